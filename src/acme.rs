@@ -0,0 +1,122 @@
+//! ACME DNS-01 challenges, which prove control of a domain to a certificate authority with TXT records.
+
+use crate::{AliyunDns, RecordSpec, Result, UpsertOutcome, MAX_PAGE_SIZE};
+
+/// The label under which the TXT records of DNS-01 challenges are published.
+const ACME_CHALLENGE_LABEL: &str = "_acme-challenge";
+
+/// Returns the name of the challenge records of a domain name, such as "_acme-challenge.www.example.com".
+///
+/// A wildcard name is validated on its base name, and a name already starting with the label is kept.
+fn challenge_name(name: &str) -> String {
+    let name = name.trim_end_matches('.');
+    let name = name.strip_prefix("*.").unwrap_or(name);
+    if name.starts_with(&format!("{}.", ACME_CHALLENGE_LABEL)) {
+        name.to_string()
+    } else {
+        format!("{}.{}", ACME_CHALLENGE_LABEL, name)
+    }
+}
+
+/// Removes the quotes around a TXT value, which the API stores without them.
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+impl AliyunDns {
+    /// Publishes the TXT record of an ACME DNS-01 challenge for a domain name.
+    ///
+    /// The record is added under `_acme-challenge`, next to the values already published, so that
+    /// the challenges of a name and of its wildcard can be validated together. A value that is already
+    /// published is left alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `fqdn` - The domain name being validated (e.g., "www.example.com" or "*.example.com").
+    /// * `token_value` - The key authorization digest to publish, quoted or not.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `UpsertOutcome`, either `Created` or `Unchanged`, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, UpsertOutcome};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<UpsertOutcome, _> = aliyun_dns
+    ///     .set_acme_challenge("*.example.com", "gfj9Xq...Rg85nM")
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn set_acme_challenge(&self, fqdn: &str, token_value: &str) -> Result<UpsertOutcome> {
+        let token_value = unquote(token_value);
+        let name = challenge_name(fqdn);
+        let (rr, domain_name) = self.split_fqdn(&name).await?;
+        let response = self
+            .query_subdomain_records_paged(&name, Some("TXT"), 1, MAX_PAGE_SIZE)
+            .await?;
+        let existing = response.domain_records.records.into_iter().find(|record| {
+            record.rr == rr && record.record_type == "TXT" && unquote(&record.value) == token_value
+        });
+        if let Some(record) = existing {
+            return Ok(UpsertOutcome::Unchanged(record.record_id));
+        }
+        let response = self
+            .add_record(&domain_name, &RecordSpec::txt(&rr, token_value))
+            .await?;
+        Ok(UpsertOutcome::Created(response.record_id))
+    }
+
+    /// Removes every TXT record of the ACME DNS-01 challenges of a domain name, once validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `fqdn` - The domain name that was validated (e.g., "www.example.com" or "*.example.com").
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of records deleted, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<u32, _> = aliyun_dns.clear_acme_challenge("*.example.com").await;
+    /// # }
+    /// ```
+    pub async fn clear_acme_challenge(&self, fqdn: &str) -> Result<u32> {
+        let (rr, domain_name) = self.split_fqdn(&challenge_name(fqdn)).await?;
+        self.delete_record_by_value(&domain_name, &rr, "TXT", None)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_challenge_name() {
+        assert_eq!(challenge_name("example.com"), "_acme-challenge.example.com");
+        assert_eq!(
+            challenge_name("*.example.com."),
+            "_acme-challenge.example.com"
+        );
+        assert_eq!(
+            challenge_name("_acme-challenge.www.example.com"),
+            "_acme-challenge.www.example.com"
+        );
+        assert_eq!(unquote("\"token\""), "token");
+        assert_eq!(unquote(" token "), "token");
+    }
+}
@@ -0,0 +1,484 @@
+//! Batch APIs, used to add or delete many domains and records in a single task.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::{AliyunDns, AliyunDnsError, Line, Result, Ttl};
+
+/// The kind of operation performed by a batch task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchTaskType {
+    /// Adds domain records.
+    RrAdd,
+    /// Deletes domain records.
+    RrDel,
+    /// Adds domains.
+    DomainAdd,
+    /// Deletes domains.
+    DomainDel,
+}
+
+impl BatchTaskType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            BatchTaskType::RrAdd => "RR_ADD",
+            BatchTaskType::RrDel => "RR_DEL",
+            BatchTaskType::DomainAdd => "DOMAIN_ADD",
+            BatchTaskType::DomainDel => "DOMAIN_DEL",
+        }
+    }
+}
+
+/// A single entry of a batch task.
+///
+/// Domain tasks only use the domain name, while record tasks also use the record settings.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::BatchEntry;
+///
+/// let entry = BatchEntry::new("example.com")
+///     .rr("www")
+///     .record_type("A")
+///     .value("192.0.2.1")
+///     .ttl(600);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchEntry {
+    domain: String,
+    rr: Option<String>,
+    record_type: Option<String>,
+    value: Option<String>,
+    ttl: Option<u32>,
+    priority: Option<u32>,
+    line: Option<Line>,
+}
+
+impl BatchEntry {
+    /// Creates a new entry for the given domain name.
+    pub fn new(domain: &str) -> Self {
+        BatchEntry {
+            domain: domain.to_string(),
+            rr: None,
+            record_type: None,
+            value: None,
+            ttl: None,
+            priority: None,
+            line: None,
+        }
+    }
+
+    /// Sets the subdomain of the record.
+    pub fn rr(mut self, rr: &str) -> Self {
+        self.rr = Some(rr.to_string());
+        self
+    }
+
+    /// Sets the type of the record (e.g., "A", "CNAME", "MX", etc.).
+    pub fn record_type(mut self, record_type: &str) -> Self {
+        self.record_type = Some(record_type.to_string());
+        self
+    }
+
+    /// Sets the value of the record (e.g., an IP address or a hostname).
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// Sets the TTL of the record, in seconds, between `Ttl::MIN` and `Ttl::MAX`.
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Sets the priority of an MX record (1 to 50).
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Sets the resolution line of the record (e.g., `Line::Default`, `Line::Telecom` or a line code).
+    pub fn line(mut self, line: impl Into<Line>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    fn to_params(&self, index: usize) -> Vec<(String, String)> {
+        let prefix = format!("DomainRecordInfo.{}", index);
+        let mut params = vec![(format!("{}.Domain", prefix), self.domain.clone())];
+        if let Some(rr) = &self.rr {
+            params.push((format!("{}.Rr", prefix), rr.clone()));
+        }
+        if let Some(record_type) = &self.record_type {
+            params.push((format!("{}.Type", prefix), record_type.clone()));
+        }
+        if let Some(value) = &self.value {
+            params.push((format!("{}.Value", prefix), value.clone()));
+        }
+        if let Some(ttl) = self.ttl {
+            params.push((format!("{}.Ttl", prefix), ttl.to_string()));
+        }
+        if let Some(priority) = self.priority {
+            params.push((format!("{}.Priority", prefix), priority.to_string()));
+        }
+        if let Some(line) = &self.line {
+            params.push((format!("{}.Line", prefix), line.as_str().to_string()));
+        }
+        params
+    }
+}
+
+/// A struct representing the response for submitting a batch task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchTaskResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId", deserialize_with = "crate::de::number")]
+    pub task_id: i64,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for querying the progress of a batch task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchResultCountResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(
+        rename = "TaskId",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub task_id: Option<i64>,
+    #[serde(rename = "BatchType", default)]
+    pub batch_type: Option<String>,
+    /// The status of the task: `0` while it is running, `1` once it has finished and `-1` if it does not exist.
+    #[serde(rename = "Status", deserialize_with = "crate::de::number")]
+    pub status: i32,
+    #[serde(rename = "TotalCount", default, deserialize_with = "crate::de::number")]
+    pub total_count: u32,
+    #[serde(
+        rename = "SuccessCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub success_count: u32,
+    #[serde(
+        rename = "FailedCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub failed_count: u32,
+    #[serde(rename = "Reason", default)]
+    pub reason: Option<String>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the result of a single entry of a batch task.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BatchResultDetail {
+    #[serde(rename = "Domain")]
+    pub domain: String,
+    #[serde(rename = "Rr", default)]
+    pub rr: Option<String>,
+    #[serde(rename = "Type", default)]
+    pub record_type: Option<String>,
+    #[serde(rename = "Value", default)]
+    pub value: Option<String>,
+    #[serde(rename = "Line", default)]
+    pub line: Option<String>,
+    #[serde(rename = "Ttl", default, deserialize_with = "crate::de::option_number")]
+    pub ttl: Option<u32>,
+    #[serde(
+        rename = "Priority",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub priority: Option<u32>,
+    #[serde(rename = "RecordId", default)]
+    pub record_id: Option<String>,
+    /// Whether the entry was processed successfully.
+    #[serde(rename = "Status")]
+    pub status: bool,
+    /// The reason the entry failed, if it did.
+    #[serde(rename = "Reason", default)]
+    pub reason: Option<String>,
+    #[serde(rename = "OperateDateStr", default)]
+    pub operate_date_str: Option<String>,
+}
+
+/// A struct containing the batch task entry results returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BatchResultDetails {
+    #[serde(rename = "BatchResultDetail")]
+    pub batch_result_details: Vec<BatchResultDetail>,
+}
+
+/// A struct representing the response for querying the entry results of a batch task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchResultDetailResponse {
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
+    pub total_count: u32,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "BatchResultDetails")]
+    pub batch_result_details: BatchResultDetails,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// The outcome of a finished batch task, as returned by `AliyunDns::wait_for_batch`.
+#[derive(Debug)]
+pub struct BatchResult {
+    /// The final progress counters of the task.
+    pub count: BatchResultCountResponse,
+    /// The entries of the task that failed.
+    pub failures: Vec<BatchResultDetail>,
+}
+
+/// The interval at which `AliyunDns::wait_for_batch` polls the progress of a batch task.
+const BATCH_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The number of times `AliyunDns::wait_for_batch` polls a batch task before giving up, ten minutes.
+const MAX_BATCH_POLLS: u32 = 300;
+
+/// The largest page size accepted by `DescribeBatchResultDetail`.
+const MAX_BATCH_DETAIL_PAGE_SIZE: u32 = 100;
+
+impl_response_meta!(
+    BatchTaskResponse,
+    BatchResultCountResponse,
+    BatchResultDetailResponse
+);
+
+impl_timestamps! {
+    BatchResultDetail { operated_at => operate_date_str }
+}
+
+impl AliyunDns {
+    /// Submits a batch task that adds or deletes many domains or records at once.
+    ///
+    /// The task runs asynchronously on the server; use the returned task ID to track its progress.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_type` - The kind of operation performed by the task.
+    /// * `entries` - The domains or records the task operates on (up to 1000 entries).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BatchTaskResponse` if the task is submitted successfully, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BatchEntry, BatchTaskResponse, BatchTaskType};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let entries = vec![
+    ///     BatchEntry::new("example.com").rr("www").record_type("A").value("192.0.2.1"),
+    ///     BatchEntry::new("example.com").rr("api").record_type("A").value("192.0.2.2"),
+    /// ];
+    /// let result: Result<BatchTaskResponse, _> = aliyun_dns.operate_batch_domain(BatchTaskType::RrAdd, &entries).await;
+    /// # }
+    /// ```
+    pub async fn operate_batch_domain(
+        &self,
+        task_type: BatchTaskType,
+        entries: &[BatchEntry],
+    ) -> Result<BatchTaskResponse> {
+        for entry in entries {
+            if let Some(ttl) = entry.ttl {
+                Ttl::new(ttl)?;
+            }
+        }
+
+        let action = "OperateBatchDomain";
+        let entry_params: Vec<(String, String)> = entries
+            .iter()
+            .enumerate()
+            .flat_map(|(index, entry)| entry.to_params(index + 1))
+            .collect();
+        let mut params = HashMap::new();
+        params.insert("Type", task_type.as_str().to_string());
+        for (key, value) in &entry_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Queries the progress of a batch task.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the batch task.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BatchResultCountResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BatchResultCountResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BatchResultCountResponse, _> = aliyun_dns.query_batch_result_count(123456).await;
+    /// # }
+    /// ```
+    pub async fn query_batch_result_count(&self, task_id: i64) -> Result<BatchResultCountResponse> {
+        let action = "DescribeBatchResultCount";
+        let mut params = HashMap::new();
+        params.insert("TaskId", task_id.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of the entry results of a batch task.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the batch task.
+    /// * `success` - `Some(true)` or `Some(false)` to only return the succeeded or failed entries, or `None` for all entries.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of entries per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BatchResultDetailResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BatchResultDetailResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BatchResultDetailResponse, _> = aliyun_dns.query_batch_result_detail(123456, Some(false), 1, 100).await;
+    /// # }
+    /// ```
+    pub async fn query_batch_result_detail(
+        &self,
+        task_id: i64,
+        success: Option<bool>,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<BatchResultDetailResponse> {
+        let action = "DescribeBatchResultDetail";
+        let mut params = HashMap::new();
+        params.insert("TaskId", task_id.to_string());
+        if let Some(success) = success {
+            params.insert(
+                "Status",
+                if success { "SUCCESS" } else { "FAIL" }.to_string(),
+            );
+        }
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Waits for a batch task to finish, polling its progress every two seconds for up to ten minutes.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the batch task.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BatchResult` with the final counters and every failed entry once the task has finished, or an error if the task does not exist, is still running after ten minutes, or a request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BatchEntry, BatchTaskType};
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let entries = vec![BatchEntry::new("example.com").rr("www").record_type("A").value("192.0.2.1")];
+    /// let task = aliyun_dns.operate_batch_domain(BatchTaskType::RrAdd, &entries).await?;
+    /// let result = aliyun_dns.wait_for_batch(task.task_id).await?;
+    /// for failure in result.failures {
+    ///     println!("{:?}: {:?}", failure.rr, failure.reason);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_batch(&self, task_id: i64) -> Result<BatchResult> {
+        let mut polls = 1;
+        let count = loop {
+            let count = self.query_batch_result_count(task_id).await?;
+            match count.status {
+                0 if polls < MAX_BATCH_POLLS => {
+                    tokio::time::sleep(BATCH_POLL_INTERVAL).await;
+                    polls += 1;
+                }
+                0 => {
+                    return Err(AliyunDnsError::Batch(format!(
+                        "Batch task {} is still running after {} polls",
+                        task_id, polls
+                    )))
+                }
+                -1 => {
+                    return Err(AliyunDnsError::InvalidInput(format!(
+                        "Batch task {} does not exist",
+                        task_id
+                    )))
+                }
+                _ => break count,
+            }
+        };
+
+        let mut failures = Vec::new();
+        let mut page_number = 1;
+        while failures.len() < count.failed_count as usize {
+            let response = self
+                .query_batch_result_detail(
+                    task_id,
+                    Some(false),
+                    page_number,
+                    MAX_BATCH_DETAIL_PAGE_SIZE,
+                )
+                .await?;
+            let page = response.batch_result_details.batch_result_details;
+            if page.is_empty() {
+                break;
+            }
+            failures.extend(page);
+            page_number += 1;
+        }
+
+        Ok(BatchResult { count, failures })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_entry_params() {
+        let entry = BatchEntry::new("example.com")
+            .rr("mail")
+            .record_type("MX")
+            .value("mx.example.com")
+            .priority(10);
+        let params: HashMap<String, String> = entry.to_params(3).into_iter().collect();
+
+        assert_eq!(params.len(), 5);
+        assert_eq!(params["DomainRecordInfo.3.Domain"], "example.com");
+        assert_eq!(params["DomainRecordInfo.3.Rr"], "mail");
+        assert_eq!(params["DomainRecordInfo.3.Type"], "MX");
+        assert_eq!(params["DomainRecordInfo.3.Value"], "mx.example.com");
+        assert_eq!(params["DomainRecordInfo.3.Priority"], "10");
+    }
+}
@@ -0,0 +1,309 @@
+//! Builder-style construction of the `AliyunDns` client.
+
+use reqwest::{Client, Proxy};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::{
+    endpoint, AliyunDns, AliyunDnsError, Credentials, CredentialsProvider, Lang, RequestMethod,
+    Result, RetryPolicy,
+};
+
+/// A builder for `AliyunDns` clients, used when the defaults of `AliyunDns::new` do not fit.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{AliyunDns, Lang};
+/// use std::time::Duration;
+///
+/// let aliyun_dns = AliyunDns::builder()
+///     .credentials("your_access_key_id", "your_access_key_secret")
+///     .timeout(Duration::from_secs(10))
+///     .lang(Lang::En)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AliyunDnsBuilder {
+    credentials: Option<Arc<dyn CredentialsProvider>>,
+    endpoint: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    user_agent_product: Option<String>,
+    proxy: Option<String>,
+    proxy_auth: Option<(String, String)>,
+    http_client: Option<Client>,
+    retry_policy: Option<RetryPolicy>,
+    request_method: Option<RequestMethod>,
+    lang: Option<Lang>,
+    dry_run: bool,
+    concurrency: Option<usize>,
+}
+
+impl AliyunDnsBuilder {
+    /// Creates a builder with every setting at its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the access key ID and secret used to sign the requests.
+    pub fn credentials(mut self, access_key_id: &str, access_key_secret: &str) -> Self {
+        self.credentials = Some(Arc::new(Credentials::new(access_key_id, access_key_secret)));
+        self
+    }
+
+    /// Sets the credentials used to sign the requests, such as temporary STS credentials with a security token.
+    pub fn static_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(Arc::new(credentials));
+        self
+    }
+
+    /// Sets the provider resolving the credentials before each request.
+    pub fn credentials_provider(mut self, provider: impl CredentialsProvider + 'static) -> Self {
+        self.credentials = Some(Arc::new(provider));
+        self
+    }
+
+    /// Sets the API endpoint, either as a host name such as the ones in the [`endpoint`] module or as a URL.
+    /// Defaults to [`endpoint::PUBLIC`].
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = Some(endpoint.to_string());
+        self
+    }
+
+    /// Sets the timeout of a whole request, from connecting until the response body is read.
+    ///
+    /// Each attempt of a retried request gets the full timeout. `AliyunDns::with_timeout` overrides it for some requests.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout of connecting to the endpoint, so that unreachable endpoints fail fast.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Replaces the `User-Agent` header sent with every request, which defaults to `aliyun_dns/<version>`.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Puts a product in front of the `User-Agent` header, such as `my-ddns/1.2` for `my-ddns/1.2 aliyun_dns/<version>`, so that the requests can be attributed to the service sending them.
+    pub fn user_agent_product(mut self, product: &str) -> Self {
+        self.user_agent_product = Some(product.to_string());
+        self
+    }
+
+    /// Sends the requests through a proxy, given as a URL such as `http://proxy.example.com:3128`.
+    ///
+    /// HTTP and HTTPS proxies are supported, and SOCKS5 proxies (`socks5://` or `socks5h://`) with the `socks` feature.
+    /// Without this setting, the proxies of the `HTTP_PROXY`, `HTTPS_PROXY` and `ALL_PROXY` environment variables are used.
+    pub fn proxy(mut self, proxy: &str) -> Self {
+        self.proxy = Some(proxy.to_string());
+        self
+    }
+
+    /// Sets the username and password the proxy requires.
+    pub fn proxy_auth(mut self, username: &str, password: &str) -> Self {
+        self.proxy_auth = Some((username.to_string(), password.to_string()));
+        self
+    }
+
+    /// Sets the HTTP client used to send the requests, sharing its connection pool, proxy and TLS settings.
+    ///
+    /// The connect timeout and proxy are settings of the HTTP client, so they cannot be combined with a client provided here.
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets how failed requests are retried. Defaults to `RetryPolicy::default()`; use `RetryPolicy::none()` to disable retries.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the HTTP method of the requests. Defaults to `RequestMethod::Post`, which keeps the parameters and signature out of the URLs.
+    pub fn request_method(mut self, request_method: RequestMethod) -> Self {
+        self.request_method = Some(request_method);
+        self
+    }
+
+    /// Sets the language of the API messages, errors included.
+    pub fn lang(mut self, lang: Lang) -> Self {
+        self.lang = Some(lang);
+        self
+    }
+
+    /// Puts the client in a dry run, in which the requests that change anything are recorded but not sent.
+    ///
+    /// See `AliyunDns::with_dry_run`.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the largest number of requests the bulk helpers, such as `AliyunDns::add_domain_records`, send at once.
+    /// Defaults to 8; 0 is treated as 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = Some(concurrency);
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `AliyunDns` client, or an error if the credentials are missing, the endpoint or proxy is invalid or the HTTP client cannot be created.
+    pub fn build(self) -> Result<AliyunDns> {
+        let credentials = self.credentials.ok_or_else(|| {
+            AliyunDnsError::InvalidInput("No credentials were provided".to_string())
+        })?;
+        let endpoint = endpoint::parse(self.endpoint.as_deref().unwrap_or(endpoint::PUBLIC))?;
+
+        let client = match self.http_client {
+            Some(client) => {
+                if self.connect_timeout.is_some() || self.proxy.is_some() {
+                    return Err(AliyunDnsError::InvalidInput(
+                        "The connect timeout and proxy cannot be set along with a custom HTTP client"
+                            .to_string(),
+                    ));
+                }
+                client
+            }
+            None => {
+                let mut client = Client::builder();
+                if let Some(connect_timeout) = self.connect_timeout {
+                    client = client.connect_timeout(connect_timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    let mut proxy = Proxy::all(proxy.as_str()).map_err(|error| {
+                        AliyunDnsError::InvalidInput(format!("Invalid proxy {}: {}", proxy, error))
+                    })?;
+                    if let Some((username, password)) = &self.proxy_auth {
+                        proxy = proxy.basic_auth(username, password);
+                    }
+                    client = client.proxy(proxy);
+                }
+                client.build()?
+            }
+        };
+
+        let user_agent = self
+            .user_agent
+            .unwrap_or_else(|| crate::USER_AGENT.to_string());
+        let user_agent = match self.user_agent_product {
+            Some(product) => format!("{} {}", product, user_agent),
+            None => user_agent,
+        };
+
+        Ok(AliyunDns {
+            credentials,
+            client,
+            lang: self.lang,
+            endpoint,
+            retry_policy: self.retry_policy.unwrap_or_default(),
+            request_method: self.request_method.unwrap_or_default(),
+            timeout: self.timeout,
+            user_agent,
+            dry_run: self.dry_run.then(Default::default),
+            concurrency: self
+                .concurrency
+                .unwrap_or(crate::DEFAULT_CONCURRENCY)
+                .max(1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_requires_credentials() {
+        assert!(AliyunDnsBuilder::new().build().is_err());
+        assert!(AliyunDnsBuilder::new()
+            .credentials("id", "secret")
+            .endpoint("not a url")
+            .build()
+            .is_err());
+
+        let aliyun_dns = AliyunDnsBuilder::new()
+            .credentials("id", "secret")
+            .endpoint("https://alidns.cn-hangzhou.aliyuncs.com/")
+            .build()
+            .unwrap();
+        assert_eq!(
+            aliyun_dns.endpoint.as_str(),
+            "https://alidns.cn-hangzhou.aliyuncs.com/"
+        );
+    }
+
+    #[test]
+    fn test_build_with_user_agent() {
+        let builder = AliyunDnsBuilder::new().credentials("id", "secret");
+        let aliyun_dns = builder.clone().build().unwrap();
+        assert_eq!(aliyun_dns.user_agent, crate::USER_AGENT);
+
+        let aliyun_dns = builder
+            .clone()
+            .user_agent_product("my-ddns/1.2")
+            .http_client(Client::new())
+            .build()
+            .unwrap();
+        assert_eq!(
+            aliyun_dns.user_agent,
+            format!("my-ddns/1.2 aliyun_dns/{}", env!("CARGO_PKG_VERSION"))
+        );
+
+        let aliyun_dns = builder
+            .user_agent("custom/1.0")
+            .user_agent_product("my-ddns/1.2")
+            .build()
+            .unwrap();
+        assert_eq!(aliyun_dns.user_agent, "my-ddns/1.2 custom/1.0");
+    }
+
+    #[test]
+    fn test_build_with_proxy() {
+        let builder = AliyunDnsBuilder::new().credentials("id", "secret");
+        assert!(builder
+            .clone()
+            .proxy("http://proxy.example.com:3128")
+            .proxy_auth("user", "password")
+            .build()
+            .is_ok());
+        assert!(builder
+            .clone()
+            .proxy("ftp://proxy.example.com")
+            .build()
+            .is_err());
+        assert!(builder
+            .proxy("http://proxy.example.com:3128")
+            .http_client(Client::new())
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_build_with_http_client() {
+        let builder = AliyunDnsBuilder::new()
+            .credentials("id", "secret")
+            .http_client(Client::new());
+        assert!(builder.clone().build().is_ok());
+        assert!(builder
+            .clone()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .is_ok());
+        assert!(builder
+            .connect_timeout(Duration::from_secs(5))
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,267 @@
+//! Bulk operations, which send many record requests concurrently with a bounded parallelism.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use tokio::task::JoinSet;
+
+use crate::{
+    AliyunDns, AliyunDnsError, DomainRecord, QueryDomainRecordsOptions, RecordResponse, RecordSpec,
+    Result, Ttl,
+};
+
+/// The outcome of `set_ttl_bulk`.
+#[derive(Debug, Default)]
+pub struct BulkUpdate {
+    /// The IDs of the records updated.
+    pub updated: Vec<String>,
+    /// The number of records that already had the new setting.
+    pub unchanged: usize,
+    /// The records that could not be updated, with their error.
+    pub failures: Vec<(DomainRecord, AliyunDnsError)>,
+}
+
+/// Returns the concurrency to use after a request was throttled.
+fn throttled_concurrency(concurrency: usize) -> usize {
+    (concurrency / 2).max(1)
+}
+
+impl AliyunDns {
+    /// Runs a request for every item, with at most `concurrency` requests in flight at once, and
+    /// returns the results in the order of the items.
+    ///
+    /// When a request is throttled despite the retry policy of the client, fewer requests are
+    /// sent at once, and the item is tried again once.
+    pub(crate) async fn fan_out<I, T, F, Fut>(&self, items: Vec<I>, request: F) -> Vec<Result<T>>
+    where
+        I: Clone + Send + 'static,
+        T: Send + 'static,
+        F: Fn(AliyunDns, I) -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+    {
+        let mut results: Vec<Option<Result<T>>> = items.iter().map(|_| None).collect();
+        let mut queue: VecDeque<(usize, I, bool)> = items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| (index, item, false))
+            .collect();
+
+        let mut concurrency = self.concurrency;
+        let mut tasks = JoinSet::new();
+        loop {
+            while tasks.len() < concurrency {
+                let Some((index, item, retried)) = queue.pop_front() else {
+                    break;
+                };
+                let future = request(self.clone(), item.clone());
+                tasks.spawn(async move { (index, item, retried, future.await) });
+            }
+            let Some(joined) = tasks.join_next().await else {
+                break;
+            };
+            let (index, item, retried, result) = joined.expect("bulk request task panicked");
+            match result {
+                Err(error) if error.is_throttling() && !retried => {
+                    concurrency = throttled_concurrency(concurrency);
+                    queue.push_back((index, item, true));
+                }
+                result => results[index] = Some(result),
+            }
+        }
+        results.into_iter().flatten().collect()
+    }
+
+    /// Adds many domain records concurrently, with at most `AliyunDns::concurrency` requests in flight at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the records should be added to.
+    /// * `specs` - The records to add.
+    ///
+    /// # Returns
+    ///
+    /// The `Result` of adding every record, containing its `RecordResponse` or its error, in the order of the records.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordSpec};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let specs = (1..=100).map(|n| RecordSpec::a(&format!("host{}", n), &format!("192.0.2.{}", n)));
+    /// for result in aliyun_dns.add_domain_records("example.com", specs).await {
+    ///     if let Err(error) = result {
+    ///         eprintln!("{}", error);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn add_domain_records(
+        &self,
+        domain_name: &str,
+        specs: impl IntoIterator<Item = RecordSpec>,
+    ) -> Vec<Result<RecordResponse>> {
+        let domain_name = domain_name.to_string();
+        self.fan_out(specs.into_iter().collect(), move |client, spec| {
+            let domain_name = domain_name.clone();
+            async move { client.add_record(&domain_name, &spec).await }
+        })
+        .await
+    }
+
+    /// Updates many domain records concurrently, with at most `AliyunDns::concurrency` requests in flight at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `updates` - The ID of every record to update, with its new host record, type, value and settings.
+    ///
+    /// # Returns
+    ///
+    /// The `Result` of updating every record, containing its `RecordResponse` or its error, in the order of the records.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordSpec};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let updates = vec![
+    ///     ("record_id_1".to_string(), RecordSpec::a("www", "192.0.2.1")),
+    ///     ("record_id_2".to_string(), RecordSpec::a("api", "192.0.2.2")),
+    /// ];
+    /// let results = aliyun_dns.update_domain_records(updates).await;
+    /// # }
+    /// ```
+    pub async fn update_domain_records(
+        &self,
+        updates: impl IntoIterator<Item = (String, RecordSpec)>,
+    ) -> Vec<Result<RecordResponse>> {
+        self.fan_out(
+            updates.into_iter().collect(),
+            |client, (record_id, spec)| async move { client.update_record(&record_id, &spec).await },
+        )
+        .await
+    }
+
+    /// Deletes many domain records concurrently, with at most `AliyunDns::concurrency` requests in flight at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `record_ids` - The IDs of the records to delete.
+    ///
+    /// # Returns
+    ///
+    /// The `Result` of deleting every record, containing its `RecordResponse` or its error, in the order of the IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let records = aliyun_dns.query_all_domain_records("example.com").await?;
+    /// let stale = records.iter().filter(|record| record.rr.starts_with("preview-"));
+    /// let results = aliyun_dns.delete_domain_records(stale.map(|record| record.record_id.clone())).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_domain_records(
+        &self,
+        record_ids: impl IntoIterator<Item = String>,
+    ) -> Vec<Result<RecordResponse>> {
+        self.fan_out(
+            record_ids.into_iter().collect(),
+            |client, record_id| async move { client.delete_domain_record(&record_id).await },
+        )
+        .await
+    }
+
+    /// Sets the TTL of every record of a domain matching a filter, such as before a migration.
+    ///
+    /// The matching records are queried page by page, and the records whose TTL differs are
+    /// updated with `update_domain_records`.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name whose records should be updated.
+    /// * `filter` - The records to update, such as a host record keyword or a type. `QueryDomainRecordsOptions::new()` matches every record.
+    /// * `new_ttl` - The new TTL of the records, in seconds.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `BulkUpdate` with the records updated and the records that could not be updated, or an error if the TTL is out of range or the records cannot be queried.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, QueryDomainRecordsOptions};
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let update = aliyun_dns.set_ttl_bulk("example.com", &QueryDomainRecordsOptions::new(), 60).await?;
+    /// println!("Updated {} records", update.updated.len());
+    /// for (record, error) in &update.failures {
+    ///     eprintln!("{} {}: {}", record.rr, record.record_type, error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_ttl_bulk(
+        &self,
+        domain_name: &str,
+        filter: &QueryDomainRecordsOptions,
+        new_ttl: u32,
+    ) -> Result<BulkUpdate> {
+        Ttl::new(new_ttl)?;
+        let records = self
+            .query_all_domain_records_with(domain_name, filter)
+            .await?;
+
+        let mut update = BulkUpdate::default();
+        let (unchanged, records): (Vec<DomainRecord>, Vec<DomainRecord>) = records
+            .into_iter()
+            .partition(|record| record.ttl == new_ttl);
+        update.unchanged = unchanged.len();
+
+        let updates = records.iter().map(|record| {
+            (
+                record.record_id.clone(),
+                RecordSpec::from(record).ttl(new_ttl),
+            )
+        });
+        let results = self.update_domain_records(updates).await;
+        for (record, result) in records.into_iter().zip(results) {
+            match result {
+                Ok(response) => update.updated.push(response.record_id),
+                Err(error) => update.failures.push((record, error)),
+            }
+        }
+        Ok(update)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttled_concurrency() {
+        assert_eq!(throttled_concurrency(crate::DEFAULT_CONCURRENCY), 4);
+        assert_eq!(throttled_concurrency(1), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_keeps_order() {
+        let aliyun_dns = AliyunDns::new(String::new(), String::new()).with_concurrency(3);
+        let results = aliyun_dns
+            .fan_out((0..10u64).collect(), |_, n| async move {
+                tokio::time::sleep(std::time::Duration::from_millis(10 - n)).await;
+                Ok(n)
+            })
+            .await;
+        let results: Vec<u64> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(results, (0..10).collect::<Vec<u64>>());
+    }
+}
@@ -0,0 +1,332 @@
+//! Cache-accelerated domain APIs, used to cache the answers of origin DNS servers on Aliyun DNS.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, BasicResponse, Result};
+
+/// The transport used to query the origin DNS servers of a cache domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceProtocol {
+    Tcp,
+    Udp,
+}
+
+impl SourceProtocol {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SourceProtocol::Tcp => "TCP",
+            SourceProtocol::Udp => "UDP",
+        }
+    }
+}
+
+/// A struct representing an origin DNS server of a cache domain.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceDnsServer {
+    #[serde(rename = "Host")]
+    pub host: String,
+    #[serde(rename = "Port")]
+    pub port: String,
+}
+
+impl SourceDnsServer {
+    /// Creates a new origin DNS server from its host and port.
+    pub fn new(host: &str, port: u16) -> Self {
+        SourceDnsServer {
+            host: host.to_string(),
+            port: port.to_string(),
+        }
+    }
+}
+
+/// The settings of a cache domain, used when adding or updating it.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{CacheDomainConfig, SourceDnsServer, SourceProtocol};
+///
+/// let config = CacheDomainConfig::new("your_instance_id", 30, 86400)
+///     .source_protocol(SourceProtocol::Udp)
+///     .source_dns_server(SourceDnsServer::new("192.0.2.53", 53));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CacheDomainConfig {
+    instance_id: String,
+    cache_ttl_min: u32,
+    cache_ttl_max: u32,
+    source_protocol: SourceProtocol,
+    source_edns: bool,
+    source_dns_servers: Vec<SourceDnsServer>,
+}
+
+impl CacheDomainConfig {
+    /// Creates the settings for a cache domain of the given instance, caching answers between `cache_ttl_min` and `cache_ttl_max` seconds.
+    ///
+    /// Origin DNS servers are queried over UDP without EDNS by default.
+    pub fn new(instance_id: &str, cache_ttl_min: u32, cache_ttl_max: u32) -> Self {
+        CacheDomainConfig {
+            instance_id: instance_id.to_string(),
+            cache_ttl_min,
+            cache_ttl_max,
+            source_protocol: SourceProtocol::Udp,
+            source_edns: false,
+            source_dns_servers: Vec::new(),
+        }
+    }
+
+    /// Sets the transport used to query the origin DNS servers.
+    pub fn source_protocol(mut self, source_protocol: SourceProtocol) -> Self {
+        self.source_protocol = source_protocol;
+        self
+    }
+
+    /// Sets whether the origin DNS servers support EDNS.
+    pub fn source_edns(mut self, source_edns: bool) -> Self {
+        self.source_edns = source_edns;
+        self
+    }
+
+    /// Adds an origin DNS server.
+    pub fn source_dns_server(mut self, source_dns_server: SourceDnsServer) -> Self {
+        self.source_dns_servers.push(source_dns_server);
+        self
+    }
+
+    /// Builds the parameters of the settings. Keys are owned since the server entries are indexed.
+    fn to_params(&self) -> Vec<(String, String)> {
+        let mut params = vec![
+            ("InstanceId".to_string(), self.instance_id.clone()),
+            ("CacheTtlMin".to_string(), self.cache_ttl_min.to_string()),
+            ("CacheTtlMax".to_string(), self.cache_ttl_max.to_string()),
+            (
+                "SourceProtocol".to_string(),
+                self.source_protocol.as_str().to_string(),
+            ),
+            (
+                "SourceEdns".to_string(),
+                if self.source_edns {
+                    "SUPPORT"
+                } else {
+                    "NOT_SUPPORT"
+                }
+                .to_string(),
+            ),
+        ];
+        for (index, server) in self.source_dns_servers.iter().enumerate() {
+            params.push((
+                format!("SourceDnsServer.{}.Host", index + 1),
+                server.host.clone(),
+            ));
+            params.push((
+                format!("SourceDnsServer.{}.Port", index + 1),
+                server.port.clone(),
+            ));
+        }
+        params
+    }
+}
+
+/// A struct representing a cache domain.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CacheDomain {
+    #[serde(rename = "DomainId")]
+    pub domain_id: String,
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(rename = "InstanceId", default)]
+    pub instance_id: Option<String>,
+    #[serde(
+        rename = "CacheTtlMin",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub cache_ttl_min: Option<u32>,
+    #[serde(
+        rename = "CacheTtlMax",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub cache_ttl_max: Option<u32>,
+    #[serde(rename = "SourceProtocol", default)]
+    pub source_protocol: Option<String>,
+    #[serde(rename = "SourceEdns", default)]
+    pub source_edns: Option<String>,
+    #[serde(rename = "SourceDnsServers", default)]
+    pub source_dns_servers: Vec<SourceDnsServer>,
+    #[serde(rename = "Remark", default)]
+    pub remark: Option<String>,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: Option<String>,
+    #[serde(rename = "UpdateTime", default)]
+    pub update_time: Option<String>,
+}
+
+/// A struct representing the response for querying cache domains.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheDomainsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "Domains", default)]
+    pub domains: Vec<CacheDomain>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+impl_response_meta!(CacheDomainsResponse);
+
+impl_timestamps! {
+    CacheDomain { created_at => create_time, updated_at => update_time }
+}
+
+impl AliyunDns {
+    /// Adds a cache domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be cached.
+    /// * `config` - The settings of the cache domain.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse, CacheDomainConfig, SourceDnsServer};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let config = CacheDomainConfig::new("your_instance_id", 30, 86400)
+    ///     .source_dns_server(SourceDnsServer::new("192.0.2.53", 53));
+    /// let result: Result<BasicResponse, _> = aliyun_dns.add_cache_domain("example.com", &config).await;
+    /// # }
+    /// ```
+    pub async fn add_cache_domain(
+        &self,
+        domain_name: &str,
+        config: &CacheDomainConfig,
+    ) -> Result<BasicResponse> {
+        let action = "AddCacheDomain";
+        let config_params = config.to_params();
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        for (key, value) in &config_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Updates the settings of a cache domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The cache domain name to be updated.
+    /// * `config` - The new settings of the cache domain, replacing the existing ones.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse, CacheDomainConfig, SourceDnsServer};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let config = CacheDomainConfig::new("your_instance_id", 60, 3600)
+    ///     .source_dns_server(SourceDnsServer::new("192.0.2.53", 53));
+    /// let result: Result<BasicResponse, _> = aliyun_dns.update_cache_domain("example.com", &config).await;
+    /// # }
+    /// ```
+    pub async fn update_cache_domain(
+        &self,
+        domain_name: &str,
+        config: &CacheDomainConfig,
+    ) -> Result<BasicResponse> {
+        let action = "UpdateCacheDomain";
+        let config_params = config.to_params();
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        for (key, value) in &config_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Deletes a cache domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The cache domain name to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BasicResponse, _> = aliyun_dns.delete_cache_domain("example.com").await;
+    /// # }
+    /// ```
+    pub async fn delete_cache_domain(&self, domain_name: &str) -> Result<BasicResponse> {
+        let action = "DeleteCacheDomain";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of the cache domains on the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_word` - A keyword matched against the domain names, or `None` for all cache domains.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of cache domains per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `CacheDomainsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, CacheDomainsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<CacheDomainsResponse, _> = aliyun_dns.query_cache_domains(None, 1, 20).await;
+    /// # }
+    /// ```
+    pub async fn query_cache_domains(
+        &self,
+        key_word: Option<&str>,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<CacheDomainsResponse> {
+        let action = "DescribeCacheDomains";
+        let mut params = HashMap::new();
+        if let Some(key_word) = key_word {
+            params.insert("KeyWord", key_word.to_string());
+        }
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+}
@@ -0,0 +1,133 @@
+//! Zone copies, which replicate the records of a template domain to another domain.
+
+use crate::{AliyunDns, DomainRecord, ImportMode, RecordSpec, RecordStatus, Result, ZoneImport};
+
+/// Optional settings for copying the records of a domain with `copy_zone`.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{CopyZoneOptions, ImportMode};
+///
+/// let options = CopyZoneOptions::new().mode(ImportMode::Overwrite).rr("www").rr("@");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CopyZoneOptions {
+    mode: ImportMode,
+    rrs: Vec<String>,
+    include_disabled: bool,
+}
+
+impl CopyZoneOptions {
+    /// Creates an empty set of options, which copies every enabled record and fails on conflicts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets what to do with a record when the target domain already has records of the same name
+    /// and type on the same line.
+    pub fn mode(mut self, mode: ImportMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Restricts the copy to the records of a host record (e.g., "www", "@"). May be called several
+    /// times to copy several host records.
+    pub fn rr(mut self, rr: &str) -> Self {
+        self.rrs.push(rr.to_string());
+        self
+    }
+
+    /// Sets whether the disabled records are copied, as enabled records. They are skipped by default.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = include_disabled;
+        self
+    }
+
+    /// Returns whether a record of the source domain should be copied.
+    ///
+    /// The SOA record and the NS records of the apex, which Aliyun DNS manages for every domain,
+    /// are never copied.
+    fn includes(&self, record: &DomainRecord) -> bool {
+        let record_type = record.record_type.to_uppercase();
+        if record_type == "SOA" || (record_type == "NS" && record.rr == "@") {
+            return false;
+        }
+        if record.status == RecordStatus::Disable && !self.include_disabled {
+            return false;
+        }
+        self.rrs.is_empty()
+            || self
+                .rrs
+                .iter()
+                .any(|rr| rr.eq_ignore_ascii_case(&record.rr))
+    }
+}
+
+impl AliyunDns {
+    /// Copies the records of a domain to another domain, such as a template zone to a new domain.
+    ///
+    /// The records keep their host record, type, value, TTL, priority and resolution line, and
+    /// are added with batch tasks, as in `import_zone`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_domain` - The domain name whose records should be copied.
+    /// * `target_domain` - The domain name the records should be added to.
+    /// * `options` - The conflict handling and the host records to copy.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `ZoneImport` with the number of records added, skipped and deleted and the records that could not be added, or an error if a conflict is found in `ImportMode::Fail`, or a request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, CopyZoneOptions, ImportMode};
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = CopyZoneOptions::new().mode(ImportMode::Skip);
+    /// let copy = aliyun_dns.copy_zone("template.example.com", "customer.example.com", &options).await?;
+    /// println!("Copied {} records", copy.added);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_zone(
+        &self,
+        source_domain: &str,
+        target_domain: &str,
+        options: &CopyZoneOptions,
+    ) -> Result<ZoneImport> {
+        let specs: Vec<RecordSpec> = self
+            .query_all_domain_records(source_domain)
+            .await?
+            .iter()
+            .filter(|record| options.includes(record))
+            .map(RecordSpec::from)
+            .collect();
+        self.import_specs(target_domain, specs, options.mode).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::record;
+
+    #[test]
+    fn test_includes() {
+        let options = CopyZoneOptions::new();
+        assert!(options.includes(&record("www", "A", "192.0.2.1").build()));
+        assert!(!options.includes(&record("@", "NS", "192.0.2.1").build()));
+        assert!(options.includes(&record("sub", "NS", "192.0.2.1").build()));
+        assert!(!options.includes(&record("www", "A", "192.0.2.1").status("DISABLE").build()));
+        assert!(CopyZoneOptions::new()
+            .include_disabled(true)
+            .includes(&record("www", "A", "192.0.2.1").status("DISABLE").build()));
+
+        let options = CopyZoneOptions::new().rr("WWW");
+        assert!(options.includes(&record("www", "A", "192.0.2.1").build()));
+        assert!(!options.includes(&record("api", "A", "192.0.2.1").build()));
+    }
+}
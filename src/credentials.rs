@@ -0,0 +1,750 @@
+//! Credentials used to sign the requests, and the providers that resolve them before each request.
+
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::{Mutex, OnceCell};
+
+use crate::{AliyunDnsError, OidcProvider, ProfileProvider, Result};
+
+/// The environment variable holding the access key ID.
+pub const ENV_ACCESS_KEY_ID: &str = "ALIBABA_CLOUD_ACCESS_KEY_ID";
+/// The environment variable holding the access key secret.
+pub const ENV_ACCESS_KEY_SECRET: &str = "ALIBABA_CLOUD_ACCESS_KEY_SECRET";
+/// The environment variable holding the STS security token, if the access key is temporary.
+pub const ENV_SECURITY_TOKEN: &str = "ALIBABA_CLOUD_SECURITY_TOKEN";
+/// The environment variable holding the name of the RAM role attached to the ECS instance.
+pub const ENV_ECS_METADATA: &str = "ALIBABA_CLOUD_ECS_METADATA";
+/// The environment variable which, set to `true`, keeps the default chain from querying the ECS metadata service.
+pub const ENV_ECS_METADATA_DISABLED: &str = "ALIBABA_CLOUD_ECS_METADATA_DISABLED";
+/// The environment variable which, set to `true`, forbids requests to the ECS metadata service without a session token.
+pub const ENV_IMDSV1_DISABLED: &str = "ALIBABA_CLOUD_IMDSV1_DISABLED";
+
+/// How long before their expiration cached credentials are refreshed.
+const REFRESH_MARGIN: chrono::Duration = chrono::Duration::minutes(5);
+
+/// An access key, optionally with the security token of temporary STS credentials.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{AliyunDns, Credentials};
+///
+/// let credentials = Credentials::new("your_access_key_id", "your_access_key_secret")
+///     .security_token("your_security_token");
+/// let aliyun_dns = AliyunDns::from_credentials(credentials);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Credentials {
+    access_key_id: String,
+    access_key_secret: String,
+    security_token: Option<String>,
+    expiration: Option<DateTime<Utc>>,
+}
+
+impl Credentials {
+    /// Creates credentials from an access key.
+    pub fn new(access_key_id: &str, access_key_secret: &str) -> Self {
+        Credentials {
+            access_key_id: access_key_id.to_string(),
+            access_key_secret: access_key_secret.to_string(),
+            security_token: None,
+            expiration: None,
+        }
+    }
+
+    /// Sets the security token issued by STS along with a temporary access key.
+    pub fn security_token(mut self, security_token: &str) -> Self {
+        self.security_token = Some(security_token.to_string());
+        self
+    }
+
+    /// Sets the time at which temporary credentials expire.
+    pub fn expiration(mut self, expiration: DateTime<Utc>) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// Returns the access key ID.
+    pub fn access_key_id(&self) -> &str {
+        &self.access_key_id
+    }
+
+    /// Returns the access key secret.
+    pub fn access_key_secret(&self) -> &str {
+        &self.access_key_secret
+    }
+
+    /// Returns the STS security token, if the credentials are temporary.
+    pub fn get_security_token(&self) -> Option<&str> {
+        self.security_token.as_deref()
+    }
+
+    /// Returns the time at which the credentials expire, or `None` if they do not.
+    pub fn get_expiration(&self) -> Option<DateTime<Utc>> {
+        self.expiration
+    }
+
+    /// Returns whether the credentials expire within the refresh margin.
+    fn needs_refresh(&self) -> bool {
+        self.expiration
+            .is_some_and(|expiration| expiration - REFRESH_MARGIN <= Utc::now())
+    }
+
+    /// Returns whether the credentials have expired.
+    fn is_expired(&self) -> bool {
+        self.expiration
+            .is_some_and(|expiration| expiration <= Utc::now())
+    }
+}
+
+impl fmt::Debug for Credentials {
+    /// Redacts the secret and the security token, so that credentials can be logged safely.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("access_key_secret", &"<redacted>")
+            .field(
+                "security_token",
+                &self.security_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("expiration", &self.expiration)
+            .finish()
+    }
+}
+
+/// The future returned by `CredentialsProvider::credentials`.
+pub type CredentialsFuture<'a> = Pin<Box<dyn Future<Output = Result<Credentials>> + Send + 'a>>;
+
+/// A source of credentials, resolved by the client before each request.
+///
+/// `Credentials` are a provider of themselves, for access keys that never change.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{Credentials, CredentialsFuture, CredentialsProvider};
+///
+/// #[derive(Debug)]
+/// struct VaultProvider;
+///
+/// impl CredentialsProvider for VaultProvider {
+///     fn credentials(&self) -> CredentialsFuture<'_> {
+///         Box::pin(async { Ok(Credentials::new("your_access_key_id", "your_access_key_secret")) })
+///     }
+/// }
+/// ```
+pub trait CredentialsProvider: fmt::Debug + Send + Sync {
+    /// Returns the credentials to sign the next request with.
+    fn credentials(&self) -> CredentialsFuture<'_>;
+}
+
+impl CredentialsProvider for Credentials {
+    fn credentials(&self) -> CredentialsFuture<'_> {
+        Box::pin(async move { Ok(self.clone()) })
+    }
+}
+
+impl<P: CredentialsProvider + ?Sized> CredentialsProvider for Arc<P> {
+    fn credentials(&self) -> CredentialsFuture<'_> {
+        (**self).credentials()
+    }
+}
+
+/// A provider reading the `ALIBABA_CLOUD_ACCESS_KEY_ID`, `ALIBABA_CLOUD_ACCESS_KEY_SECRET` and `ALIBABA_CLOUD_SECURITY_TOKEN` environment variables.
+///
+/// The variables are read before each request, so that rotated keys are picked up.
+#[derive(Debug, Clone, Default)]
+pub struct EnvironmentProvider;
+
+impl EnvironmentProvider {
+    /// Creates a provider reading the environment variables.
+    pub fn new() -> Self {
+        EnvironmentProvider
+    }
+
+    /// Reads the credentials from the environment variables, or returns an error if the access key is not set.
+    fn read(&self) -> Result<Credentials> {
+        let access_key_id = env_var(ENV_ACCESS_KEY_ID)?;
+        let access_key_secret = env_var(ENV_ACCESS_KEY_SECRET)?;
+        let credentials = Credentials::new(&access_key_id, &access_key_secret);
+        Ok(match std::env::var(ENV_SECURITY_TOKEN) {
+            Ok(security_token) if !security_token.is_empty() => {
+                credentials.security_token(&security_token)
+            }
+            _ => credentials,
+        })
+    }
+}
+
+impl CredentialsProvider for EnvironmentProvider {
+    fn credentials(&self) -> CredentialsFuture<'_> {
+        Box::pin(async move { self.read() })
+    }
+}
+
+/// Returns the value of a non-empty environment variable.
+pub(crate) fn env_var(name: &str) -> Result<String> {
+    match std::env::var(name) {
+        Ok(value) if !value.is_empty() => Ok(value),
+        _ => Err(AliyunDnsError::Credentials(format!(
+            "The environment variable {} is not set",
+            name
+        ))),
+    }
+}
+
+/// A provider caching the expiring credentials of another provider, such as STS tokens, and fetching new ones shortly before they expire.
+///
+/// Credentials without an expiration are cached for the lifetime of the provider. If fetching new credentials fails,
+/// the cached ones are used until they expire.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{AliyunDns, Credentials, RefreshingProvider};
+///
+/// # async fn fetch_sts_token() -> aliyun_dns::Result<Credentials> { unimplemented!() }
+/// let provider = RefreshingProvider::new(|| Box::pin(fetch_sts_token()));
+/// let aliyun_dns = AliyunDns::from_provider(provider);
+/// ```
+pub struct RefreshingProvider {
+    fetch: Box<dyn Fn() -> CredentialsFuture<'static> + Send + Sync>,
+    cache: CredentialsCache,
+}
+
+impl RefreshingProvider {
+    /// Creates a provider calling `fetch` whenever the cached credentials are missing or about to expire.
+    pub fn new<F>(fetch: F) -> Self
+    where
+        F: Fn() -> CredentialsFuture<'static> + Send + Sync + 'static,
+    {
+        RefreshingProvider {
+            fetch: Box::new(fetch),
+            cache: CredentialsCache::default(),
+        }
+    }
+}
+
+impl fmt::Debug for RefreshingProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefreshingProvider")
+            .field("cache", &self.cache)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CredentialsProvider for RefreshingProvider {
+    fn credentials(&self) -> CredentialsFuture<'_> {
+        Box::pin(self.cache.get_or_refresh(|| (self.fetch)()))
+    }
+}
+
+/// The ECS metadata service, which serves the credentials of the RAM role attached to the instance.
+const ECS_METADATA_ENDPOINT: &str = "http://100.100.100.200";
+
+/// The lifetime requested for the session tokens of the metadata service, in seconds.
+const ECS_METADATA_TOKEN_TTL: &str = "21600";
+
+/// The timeout of the requests to the metadata service, which is only reachable from ECS instances.
+///
+/// It is kept short, since the default chain falls back to the metadata service outside of ECS as well.
+const ECS_METADATA_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// A provider fetching the temporary credentials of the RAM role attached to the ECS instance from its metadata service, and refreshing them before they expire.
+///
+/// A session token is requested from the metadata service first, as required when the instance enforces the hardened (IMDSv2-style) mode.
+/// If the token cannot be obtained, the credentials are requested without it, unless `disable_imds_v1` is set.
+///
+/// # Examples
+///
+/// ```no_run
+/// use aliyun_dns::{AliyunDns, EcsRamRoleProvider};
+///
+/// let provider = EcsRamRoleProvider::new().role_name("dns-agent");
+/// let aliyun_dns = AliyunDns::from_provider(provider);
+/// ```
+#[derive(Debug)]
+pub struct EcsRamRoleProvider {
+    client: Client,
+    role_name: Option<String>,
+    imds_v1_disabled: bool,
+    cache: CredentialsCache,
+}
+
+impl Default for EcsRamRoleProvider {
+    fn default() -> Self {
+        // The metadata service is link-local, so its requests must never go through the proxies of
+        // the `HTTP_PROXY` and `HTTPS_PROXY` environment variables.
+        let client = Client::builder()
+            .no_proxy()
+            .timeout(ECS_METADATA_TIMEOUT)
+            .build()
+            .expect("Failed to build the HTTP client of the ECS metadata service");
+        EcsRamRoleProvider {
+            client,
+            role_name: None,
+            imds_v1_disabled: false,
+            cache: CredentialsCache::default(),
+        }
+    }
+}
+
+impl EcsRamRoleProvider {
+    /// Creates a provider for the RAM role attached to the instance, discovered from the metadata service.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the RAM role, which saves the request discovering it.
+    pub fn role_name(mut self, role_name: &str) -> Self {
+        self.role_name = Some(role_name.to_string());
+        self
+    }
+
+    /// Sets whether requests without a session token are forbidden, so that a failure to obtain the token is an error.
+    pub fn disable_imds_v1(mut self, disabled: bool) -> Self {
+        self.imds_v1_disabled = disabled;
+        self
+    }
+
+    /// Fetches the credentials of the RAM role from the metadata service.
+    async fn fetch(&self) -> Result<Credentials> {
+        let token = self.metadata_token().await?;
+        let role_name = match &self.role_name {
+            Some(role_name) => role_name.clone(),
+            None => {
+                let roles = self
+                    .get_metadata("ram/security-credentials/", token.as_deref())
+                    .await?;
+                roles
+                    .lines()
+                    .next()
+                    .map(str::trim)
+                    .filter(|role_name| !role_name.is_empty())
+                    .ok_or_else(|| {
+                        AliyunDnsError::Credentials(
+                            "No RAM role is attached to the ECS instance".to_string(),
+                        )
+                    })?
+                    .to_string()
+            }
+        };
+
+        let body = self
+            .get_metadata(
+                &format!("ram/security-credentials/{}", role_name),
+                token.as_deref(),
+            )
+            .await?;
+        let response: EcsCredentials = serde_json::from_str(&body)
+            .map_err(|source| AliyunDnsError::Decode { source, body })?;
+        response.into_credentials()
+    }
+
+    /// Requests a session token from the metadata service, or returns `None` if it cannot be obtained and requests without it are allowed.
+    async fn metadata_token(&self) -> Result<Option<String>> {
+        let result = self
+            .client
+            .put(format!("{}/latest/api/token", ECS_METADATA_ENDPOINT))
+            .header(
+                "X-aliyun-ecs-metadata-token-ttl-seconds",
+                ECS_METADATA_TOKEN_TTL,
+            )
+            .timeout(ECS_METADATA_TIMEOUT)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        let error = match result {
+            Ok(response) => match response.text().await {
+                Ok(token) => return Ok(Some(token)),
+                Err(error) => error,
+            },
+            Err(error) => error,
+        };
+
+        if self.imds_v1_disabled {
+            Err(AliyunDnsError::Credentials(format!(
+                "Failed to get a session token from the ECS metadata service: {}",
+                error
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads a path below `/latest/meta-data/` from the metadata service.
+    async fn get_metadata(&self, path: &str, token: Option<&str>) -> Result<String> {
+        let mut request = self
+            .client
+            .get(format!(
+                "{}/latest/meta-data/{}",
+                ECS_METADATA_ENDPOINT, path
+            ))
+            .timeout(ECS_METADATA_TIMEOUT);
+        if let Some(token) = token {
+            request = request.header("X-aliyun-ecs-metadata-token", token);
+        }
+
+        let response = request.send().await.map_err(|error| {
+            AliyunDnsError::Credentials(format!(
+                "Failed to reach the ECS metadata service: {}",
+                error
+            ))
+        })?;
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(AliyunDnsError::Credentials(format!(
+                "The ECS metadata service answered {} for {}: {}",
+                status, path, body
+            )));
+        }
+        Ok(body)
+    }
+}
+
+impl CredentialsProvider for EcsRamRoleProvider {
+    fn credentials(&self) -> CredentialsFuture<'_> {
+        Box::pin(self.cache.get_or_refresh(|| self.fetch()))
+    }
+}
+
+/// The credentials of a RAM role, as served by the ECS metadata service.
+#[derive(Debug, Deserialize)]
+struct EcsCredentials {
+    #[serde(rename = "Code")]
+    code: String,
+    #[serde(rename = "AccessKeyId", default)]
+    access_key_id: String,
+    #[serde(rename = "AccessKeySecret", default)]
+    access_key_secret: String,
+    #[serde(rename = "SecurityToken", default)]
+    security_token: String,
+    #[serde(rename = "Expiration", default)]
+    expiration: String,
+}
+
+impl EcsCredentials {
+    /// Converts the served credentials, or returns an error if the metadata service could not issue them.
+    fn into_credentials(self) -> Result<Credentials> {
+        if self.code != "Success" {
+            return Err(AliyunDnsError::Credentials(format!(
+                "The ECS metadata service failed to issue credentials: {}",
+                self.code
+            )));
+        }
+        Ok(
+            Credentials::new(&self.access_key_id, &self.access_key_secret)
+                .security_token(&self.security_token)
+                .expiration(parse_expiration(&self.expiration)?),
+        )
+    }
+}
+
+/// Parses the expiration of temporary credentials, such as `2026-10-16T08:00:00Z`.
+pub(crate) fn parse_expiration(expiration: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(expiration)
+        .map(|expiration| expiration.with_timezone(&Utc))
+        .map_err(|error| {
+            AliyunDnsError::Credentials(format!(
+                "Invalid expiration {:?} of the credentials: {}",
+                expiration, error
+            ))
+        })
+}
+
+/// The default chain of providers, mirroring the official SDKs: environment variables, the Aliyun CLI profile file, OIDC tokens (RRSA), and finally the RAM role of the ECS instance.
+///
+/// The first provider to return credentials is selected and used from then on.
+///
+/// # Examples
+///
+/// ```no_run
+/// use aliyun_dns::{AliyunDns, DefaultProviderChain};
+///
+/// let aliyun_dns = AliyunDns::builder()
+///     .credentials_provider(DefaultProviderChain::new())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct DefaultProviderChain {
+    providers: Vec<(&'static str, Box<dyn CredentialsProvider>)>,
+    selected: OnceCell<usize>,
+}
+
+impl DefaultProviderChain {
+    /// Creates the chain of providers.
+    ///
+    /// The OIDC provider is part of the chain only if its environment variables are set, and the ECS provider unless `ALIBABA_CLOUD_ECS_METADATA_DISABLED` is `true`.
+    /// The ECS provider honors the `ALIBABA_CLOUD_ECS_METADATA` and `ALIBABA_CLOUD_IMDSV1_DISABLED` environment variables.
+    pub fn new() -> Self {
+        let mut providers: Vec<(&'static str, Box<dyn CredentialsProvider>)> = vec![
+            ("environment", Box::new(EnvironmentProvider::new())),
+            ("profile", Box::new(ProfileProvider::new())),
+        ];
+        if let Ok(provider) = OidcProvider::from_env() {
+            providers.push(("OIDC", Box::new(provider)));
+        }
+        if !env_flag(ENV_ECS_METADATA_DISABLED) {
+            let mut provider =
+                EcsRamRoleProvider::new().disable_imds_v1(env_flag(ENV_IMDSV1_DISABLED));
+            if let Ok(role_name) = env_var(ENV_ECS_METADATA) {
+                provider = provider.role_name(&role_name);
+            }
+            providers.push(("ECS RAM role", Box::new(provider)));
+        }
+
+        DefaultProviderChain {
+            providers,
+            selected: OnceCell::new(),
+        }
+    }
+
+    /// Returns the credentials of the selected provider, or selects the first provider to return credentials.
+    ///
+    /// Only the selection is serialized, so that the requests resolving credentials once a provider is selected do not wait for each other.
+    async fn resolve(&self) -> Result<Credentials> {
+        if let Some(&index) = self.selected.get() {
+            return self.providers[index].1.credentials().await;
+        }
+
+        let mut resolved = None;
+        let index = *self
+            .selected
+            .get_or_try_init(|| async {
+                let mut failures = Vec::new();
+                for (index, (name, provider)) in self.providers.iter().enumerate() {
+                    match provider.credentials().await {
+                        Ok(credentials) => {
+                            resolved = Some(credentials);
+                            return Ok(index);
+                        }
+                        Err(error) => failures.push(format!("{}: {}", name, error)),
+                    }
+                }
+                Err(AliyunDnsError::Credentials(format!(
+                    "No provider of the default chain returned credentials ({})",
+                    failures.join("; ")
+                )))
+            })
+            .await?;
+        match resolved {
+            Some(credentials) => Ok(credentials),
+            None => self.providers[index].1.credentials().await,
+        }
+    }
+}
+
+impl Default for DefaultProviderChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CredentialsProvider for DefaultProviderChain {
+    fn credentials(&self) -> CredentialsFuture<'_> {
+        Box::pin(self.resolve())
+    }
+}
+
+/// Returns whether an environment variable is set to `true`.
+fn env_flag(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|value| value.eq_ignore_ascii_case("true"))
+}
+
+/// Cached credentials, shared by the providers of expiring credentials.
+#[derive(Debug, Default)]
+pub(crate) struct CredentialsCache {
+    credentials: Mutex<Option<Credentials>>,
+}
+
+impl CredentialsCache {
+    /// Returns the cached credentials, or fetches and caches new ones if they are missing or about to expire.
+    ///
+    /// Concurrent callers wait for a single refresh rather than fetching credentials of their own.
+    /// When a refresh fails, the cached credentials are returned until they actually expire, so that
+    /// a brief outage of the metadata service or STS does not fail the requests.
+    pub(crate) async fn get_or_refresh<F, Fut>(&self, fetch: F) -> Result<Credentials>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Credentials>>,
+    {
+        let mut cached = self.credentials.lock().await;
+        match cached.as_ref() {
+            Some(credentials) if !credentials.needs_refresh() => Ok(credentials.clone()),
+            _ => match fetch().await {
+                Ok(credentials) => {
+                    *cached = Some(credentials.clone());
+                    Ok(credentials)
+                }
+                Err(error) => match cached.as_ref() {
+                    Some(credentials) if !credentials.is_expired() => Ok(credentials.clone()),
+                    _ => Err(error),
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let credentials = Credentials::new("LTAI5t", "s3cr3t").security_token("t0k3n");
+        let debug = format!("{:?}", credentials);
+        assert!(debug.contains("LTAI5t"));
+        assert!(!debug.contains("s3cr3t"));
+        assert!(!debug.contains("t0k3n"));
+        assert_eq!(credentials.get_security_token(), Some("t0k3n"));
+    }
+
+    #[tokio::test]
+    async fn test_refreshing_provider() {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let counter = fetches.clone();
+        let provider = RefreshingProvider::new(move || {
+            let fetch = counter.fetch_add(1, Ordering::SeqCst);
+            // The first credentials are about to expire, the second ones are not.
+            let expiration = if fetch == 0 {
+                Utc::now() + chrono::Duration::minutes(1)
+            } else {
+                Utc::now() + chrono::Duration::hours(1)
+            };
+            Box::pin(async move {
+                Ok(Credentials::new(&format!("id-{}", fetch), "secret").expiration(expiration))
+            })
+        });
+
+        assert_eq!(
+            provider.credentials().await.unwrap().access_key_id(),
+            "id-0"
+        );
+        assert_eq!(
+            provider.credentials().await.unwrap().access_key_id(),
+            "id-1"
+        );
+        assert_eq!(
+            provider.credentials().await.unwrap().access_key_id(),
+            "id-1"
+        );
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_failure() {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let counter = fetches.clone();
+        let provider = RefreshingProvider::new(move || {
+            let fetch = counter.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                match fetch {
+                    // Credentials within the refresh margin, then credentials that have expired.
+                    0 => Ok(Credentials::new("id-0", "secret")
+                        .expiration(Utc::now() + chrono::Duration::minutes(1))),
+                    2 => Ok(Credentials::new("id-2", "secret")
+                        .expiration(Utc::now() - chrono::Duration::minutes(1))),
+                    _ => Err(AliyunDnsError::Credentials("unavailable".to_string())),
+                }
+            })
+        });
+
+        assert_eq!(
+            provider.credentials().await.unwrap().access_key_id(),
+            "id-0"
+        );
+        // The refresh fails, and the cached credentials are still valid.
+        assert_eq!(
+            provider.credentials().await.unwrap().access_key_id(),
+            "id-0"
+        );
+        assert_eq!(
+            provider.credentials().await.unwrap().access_key_id(),
+            "id-2"
+        );
+        // The refresh fails, and the cached credentials have expired.
+        assert!(provider.credentials().await.is_err());
+        assert_eq!(fetches.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_ecs_credentials() {
+        let response: EcsCredentials = serde_json::from_str(
+            r#"{
+                "AccessKeyId": "STS.id",
+                "AccessKeySecret": "secret",
+                "Expiration": "2026-10-16T08:00:00Z",
+                "SecurityToken": "token",
+                "LastUpdated": "2026-10-16T02:00:00Z",
+                "Code": "Success"
+            }"#,
+        )
+        .unwrap();
+        let credentials = response.into_credentials().unwrap();
+        assert_eq!(credentials.access_key_id(), "STS.id");
+        assert_eq!(credentials.get_security_token(), Some("token"));
+        assert_eq!(
+            credentials.get_expiration().unwrap().to_rfc3339(),
+            "2026-10-16T08:00:00+00:00"
+        );
+
+        let response: EcsCredentials = serde_json::from_str(r#"{"Code": "Failed"}"#).unwrap();
+        assert!(response.into_credentials().is_err());
+    }
+
+    /// A provider that fails, or whose calls after the first wait for each other in pairs.
+    #[derive(Debug)]
+    struct PairedProvider {
+        calls: AtomicUsize,
+        barrier: Option<tokio::sync::Barrier>,
+    }
+
+    impl CredentialsProvider for PairedProvider {
+        fn credentials(&self) -> CredentialsFuture<'_> {
+            Box::pin(async move {
+                let barrier = self
+                    .barrier
+                    .as_ref()
+                    .ok_or_else(|| AliyunDnsError::Credentials("No credentials".to_string()))?;
+                if self.calls.fetch_add(1, Ordering::SeqCst) > 0 {
+                    barrier.wait().await;
+                }
+                Ok(Credentials::new("id", "secret"))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_provider_chain_concurrency() {
+        let provider = |barrier: Option<tokio::sync::Barrier>| -> Box<dyn CredentialsProvider> {
+            Box::new(PairedProvider {
+                calls: AtomicUsize::new(0),
+                barrier,
+            })
+        };
+        let chain = DefaultProviderChain {
+            providers: vec![
+                ("failing", provider(None)),
+                ("paired", provider(Some(tokio::sync::Barrier::new(2)))),
+            ],
+            selected: OnceCell::new(),
+        };
+        assert!(chain.credentials().await.is_ok());
+        assert_eq!(chain.selected.get(), Some(&1));
+
+        // Two requests resolving credentials at once must not wait for each other.
+        let both = async { tokio::join!(chain.credentials(), chain.credentials()) };
+        let (a, b) = tokio::time::timeout(std::time::Duration::from_secs(5), both)
+            .await
+            .unwrap();
+        assert!(a.is_ok() && b.is_ok());
+    }
+}
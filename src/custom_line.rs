@@ -0,0 +1,333 @@
+//! Custom resolution line APIs, used to answer queries from specific IP ranges with dedicated records.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, BasicResponse, Result};
+
+/// A struct representing a range of IP addresses covered by a custom line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IpSegment {
+    #[serde(rename = "StartIp")]
+    pub start_ip: String,
+    #[serde(rename = "EndIp")]
+    pub end_ip: String,
+}
+
+impl IpSegment {
+    /// Creates a new IP range from its first and last addresses.
+    pub fn new(start_ip: &str, end_ip: &str) -> Self {
+        IpSegment {
+            start_ip: start_ip.to_string(),
+            end_ip: end_ip.to_string(),
+        }
+    }
+}
+
+/// A struct representing the response for adding a custom line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddCustomLineResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "LineId", deserialize_with = "crate::de::number")]
+    pub line_id: i64,
+    /// The line code to use as the `Line` of the records of this custom line.
+    #[serde(rename = "LineCode")]
+    pub line_code: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for querying a single custom line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomLineResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Id", deserialize_with = "crate::de::number")]
+    pub id: i64,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Code")]
+    pub code: String,
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: Option<String>,
+    #[serde(
+        rename = "CreateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub create_timestamp: Option<i64>,
+    #[serde(rename = "IpSegmentList", default)]
+    pub ip_segment_list: Vec<IpSegment>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing a custom line in a list of custom lines.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CustomLine {
+    #[serde(rename = "Id", deserialize_with = "crate::de::number")]
+    pub id: i64,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Code")]
+    pub code: String,
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: Option<String>,
+    #[serde(
+        rename = "CreateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub create_timestamp: Option<i64>,
+    /// The IP ranges of the line, as a comma-separated list of `start-end` pairs.
+    #[serde(rename = "IpSegments", default)]
+    pub ip_segments: Option<String>,
+}
+
+/// A struct representing the response for querying the custom lines of a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomLinesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "CustomLines", default)]
+    pub custom_lines: Vec<CustomLine>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Holds the indexed `IpSegment.N.*` parameters, which need owned keys.
+fn ip_segment_params(ip_segments: &[IpSegment]) -> Vec<(String, String)> {
+    ip_segments
+        .iter()
+        .enumerate()
+        .flat_map(|(index, segment)| {
+            vec![
+                (
+                    format!("IpSegment.{}.StartIp", index + 1),
+                    segment.start_ip.clone(),
+                ),
+                (
+                    format!("IpSegment.{}.EndIp", index + 1),
+                    segment.end_ip.clone(),
+                ),
+            ]
+        })
+        .collect()
+}
+
+impl_response_meta!(
+    AddCustomLineResponse,
+    CustomLineResponse,
+    CustomLinesResponse
+);
+
+impl_timestamps! {
+    CustomLineResponse { created_at => create_timestamp }
+    CustomLine { created_at => create_timestamp }
+}
+
+impl AliyunDns {
+    /// Adds a custom line to a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the custom line belongs to.
+    /// * `line_name` - The name of the custom line.
+    /// * `ip_segments` - The IP ranges covered by the custom line.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `AddCustomLineResponse` with the line code of the new line if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AddCustomLineResponse, AliyunDns, IpSegment};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let segments = vec![IpSegment::new("203.0.113.0", "203.0.113.255")];
+    /// let result: Result<AddCustomLineResponse, _> = aliyun_dns.add_custom_line("example.com", "office", &segments).await;
+    /// # }
+    /// ```
+    pub async fn add_custom_line(
+        &self,
+        domain_name: &str,
+        line_name: &str,
+        ip_segments: &[IpSegment],
+    ) -> Result<AddCustomLineResponse> {
+        let action = "AddCustomLine";
+        let segment_params = ip_segment_params(ip_segments);
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("LineName", line_name.to_string());
+        for (key, value) in &segment_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Updates the name and IP ranges of a custom line.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_id` - The ID of the custom line to be updated.
+    /// * `line_name` - The new name of the custom line.
+    /// * `ip_segments` - The new IP ranges covered by the custom line, replacing the existing ones.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse, IpSegment};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let segments = vec![IpSegment::new("198.51.100.0", "198.51.100.255")];
+    /// let result: Result<BasicResponse, _> = aliyun_dns.update_custom_line(123456, "office", &segments).await;
+    /// # }
+    /// ```
+    pub async fn update_custom_line(
+        &self,
+        line_id: i64,
+        line_name: &str,
+        ip_segments: &[IpSegment],
+    ) -> Result<BasicResponse> {
+        let action = "UpdateCustomLine";
+        let segment_params = ip_segment_params(ip_segments);
+        let mut params = HashMap::new();
+        params.insert("LineId", line_id.to_string());
+        params.insert("LineName", line_name.to_string());
+        for (key, value) in &segment_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Deletes one or more custom lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_ids` - The IDs of the custom lines to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BasicResponse, _> = aliyun_dns.delete_custom_lines(&[123456]).await;
+    /// # }
+    /// ```
+    pub async fn delete_custom_lines(&self, line_ids: &[i64]) -> Result<BasicResponse> {
+        let action = "DeleteCustomLines";
+        let line_ids: Vec<String> = line_ids.iter().map(|id| id.to_string()).collect();
+        let mut params = HashMap::new();
+        params.insert("LineIds", line_ids.join(","));
+        self.send_request(action, params).await
+    }
+
+    /// Queries the details of a single custom line.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_id` - The ID of the custom line to be queried.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `CustomLineResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, CustomLineResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<CustomLineResponse, _> = aliyun_dns.get_custom_line(123456).await;
+    /// # }
+    /// ```
+    pub async fn get_custom_line(&self, line_id: i64) -> Result<CustomLineResponse> {
+        let action = "DescribeCustomLine";
+        let mut params = HashMap::new();
+        params.insert("LineId", line_id.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of the custom lines of a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the custom lines should be queried.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of custom lines per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `CustomLinesResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, CustomLinesResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<CustomLinesResponse, _> = aliyun_dns.query_custom_lines("example.com", 1, 20).await;
+    /// # }
+    /// ```
+    pub async fn query_custom_lines(
+        &self,
+        domain_name: &str,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<CustomLinesResponse> {
+        let action = "DescribeCustomLines";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ip_segment_params() {
+        let segments = vec![
+            IpSegment::new("203.0.113.0", "203.0.113.255"),
+            IpSegment::new("198.51.100.0", "198.51.100.127"),
+        ];
+        let params: HashMap<String, String> = ip_segment_params(&segments).into_iter().collect();
+
+        assert_eq!(params.len(), 4);
+        assert_eq!(params["IpSegment.1.StartIp"], "203.0.113.0");
+        assert_eq!(params["IpSegment.1.EndIp"], "203.0.113.255");
+        assert_eq!(params["IpSegment.2.StartIp"], "198.51.100.0");
+        assert_eq!(params["IpSegment.2.EndIp"], "198.51.100.127");
+    }
+}
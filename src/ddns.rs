@@ -0,0 +1,315 @@
+//! Dynamic DNS, which points an A or AAAA record to the current public IP address of the machine.
+//!
+//! This module is available with the `ddns` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use aliyun_dns::AliyunDns;
+//! use aliyun_dns::ddns::IpVersion;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+//! let update = aliyun_dns
+//!     .update_to_current_ip("example.com", "home", IpVersion::V4)
+//!     .await?;
+//! if update.outcome.is_changed() {
+//!     println!("home.example.com now points to {}", update.ip);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::Client;
+use std::fmt;
+use std::future::Future;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::time::Duration;
+
+use crate::{AliyunDns, AliyunDnsError, RecordOptions, RecordSpec, Result, UpsertOutcome};
+
+/// The time allowed to each HTTP source to answer.
+const HTTP_SOURCE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The version of the IP address to discover, and the type of the record pointing to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpVersion {
+    /// An IPv4 address, in an A record.
+    V4,
+    /// An IPv6 address, in an AAAA record.
+    V6,
+}
+
+impl IpVersion {
+    /// Returns the type of the record pointing to an address of this version.
+    pub fn record_type(&self) -> &'static str {
+        match self {
+            IpVersion::V4 => "A",
+            IpVersion::V6 => "AAAA",
+        }
+    }
+
+    /// Returns whether an address is of this version.
+    pub fn matches(&self, ip: &IpAddr) -> bool {
+        match self {
+            IpVersion::V4 => ip.is_ipv4(),
+            IpVersion::V6 => ip.is_ipv6(),
+        }
+    }
+}
+
+/// The future returned by an `IpSource`.
+pub type IpFuture<'a> = Pin<Box<dyn Future<Output = Result<IpAddr>> + Send + 'a>>;
+
+/// A source of the public IP address of the machine.
+///
+/// Implement this trait to discover the address another way, such as from the WAN interface of a router.
+pub trait IpSource: fmt::Debug + Send + Sync {
+    /// Returns the public IP address of the machine, of the given version.
+    fn public_ip(&self, version: IpVersion) -> IpFuture<'_>;
+}
+
+/// An address known in advance is its own source.
+impl IpSource for IpAddr {
+    fn public_ip(&self, version: IpVersion) -> IpFuture<'_> {
+        let ip = *self;
+        Box::pin(async move {
+            if version.matches(&ip) {
+                Ok(ip)
+            } else {
+                Err(AliyunDnsError::IpDiscovery(format!(
+                    "{} is not an {:?} address",
+                    ip, version
+                )))
+            }
+        })
+    }
+}
+
+/// A web service answering with the IP address the request comes from, as plain text.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::ddns::HttpIpSource;
+///
+/// let source = HttpIpSource::new("https://api.ipify.org", "https://api6.ipify.org");
+/// ```
+#[derive(Debug, Clone)]
+pub struct HttpIpSource {
+    v4_url: String,
+    v6_url: String,
+    client: Client,
+}
+
+impl HttpIpSource {
+    /// Creates a source from the URLs answering over IPv4 and over IPv6.
+    pub fn new(v4_url: &str, v6_url: &str) -> Self {
+        HttpIpSource {
+            v4_url: v4_url.to_string(),
+            v6_url: v6_url.to_string(),
+            client: Client::new(),
+        }
+    }
+
+    /// Sets the HTTP client used to reach the service, for example to go through a proxy.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+}
+
+impl IpSource for HttpIpSource {
+    fn public_ip(&self, version: IpVersion) -> IpFuture<'_> {
+        Box::pin(async move {
+            let url = match version {
+                IpVersion::V4 => &self.v4_url,
+                IpVersion::V6 => &self.v6_url,
+            };
+            let body = self
+                .client
+                .get(url)
+                .timeout(HTTP_SOURCE_TIMEOUT)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?;
+            let ip: IpAddr = body.trim().parse().map_err(|_| {
+                AliyunDnsError::IpDiscovery(format!("{} answered {:?}", url, body.trim()))
+            })?;
+            ip.public_ip(version).await
+        })
+    }
+}
+
+/// A list of sources, tried in order until one returns an address.
+///
+/// The default list queries several public web services.
+#[derive(Debug)]
+pub struct IpDiscovery {
+    sources: Vec<Box<dyn IpSource>>,
+}
+
+impl IpDiscovery {
+    /// Creates an empty list of sources.
+    pub fn new() -> Self {
+        IpDiscovery {
+            sources: Vec::new(),
+        }
+    }
+
+    /// Appends a source to the list.
+    pub fn source(mut self, source: impl IpSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+}
+
+impl Default for IpDiscovery {
+    fn default() -> Self {
+        IpDiscovery::new()
+            .source(HttpIpSource::new(
+                "https://api.ipify.org",
+                "https://api6.ipify.org",
+            ))
+            .source(HttpIpSource::new("https://4.ipw.cn", "https://6.ipw.cn"))
+            .source(HttpIpSource::new(
+                "https://ipv4.icanhazip.com",
+                "https://ipv6.icanhazip.com",
+            ))
+    }
+}
+
+impl IpSource for IpDiscovery {
+    fn public_ip(&self, version: IpVersion) -> IpFuture<'_> {
+        Box::pin(async move {
+            let mut errors = Vec::new();
+            for source in &self.sources {
+                match source.public_ip(version).await {
+                    Ok(ip) => return Ok(ip),
+                    Err(error) => errors.push(error.to_string()),
+                }
+            }
+            Err(AliyunDnsError::IpDiscovery(if errors.is_empty() {
+                "No IP address source is configured".to_string()
+            } else {
+                errors.join("; ")
+            }))
+        })
+    }
+}
+
+/// The result of `update_to_current_ip`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DdnsUpdate {
+    /// The public IP address the record points to.
+    pub ip: IpAddr,
+    /// Whether the record was created, updated or already up to date.
+    pub outcome: UpsertOutcome,
+}
+
+impl AliyunDns {
+    /// Points an A or AAAA record to the current public IP address of the machine, discovered with
+    /// the default `IpDiscovery`, and updates the record only if the address changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the record belongs to.
+    /// * `rr` - The subdomain of the record (e.g., "home", or "@" for the domain itself).
+    /// * `version` - `IpVersion::V4` for an A record, or `IpVersion::V6` for an AAAA record.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `DdnsUpdate` with the address and the action taken, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    /// use aliyun_dns::ddns::{DdnsUpdate, IpVersion};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DdnsUpdate, _> = aliyun_dns.update_to_current_ip("example.com", "home", IpVersion::V6).await;
+    /// # }
+    /// ```
+    pub async fn update_to_current_ip(
+        &self,
+        domain_name: &str,
+        rr: &str,
+        version: IpVersion,
+    ) -> Result<DdnsUpdate> {
+        self.update_to_current_ip_with(
+            domain_name,
+            rr,
+            version,
+            &IpDiscovery::default(),
+            &RecordOptions::new(),
+        )
+        .await
+    }
+
+    /// Points an A or AAAA record to the current public IP address of the machine, discovered with
+    /// the given source, and updates the record only if the address or the settings changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the record belongs to.
+    /// * `rr` - The subdomain of the record (e.g., "home", or "@" for the domain itself).
+    /// * `version` - `IpVersion::V4` for an A record, or `IpVersion::V6` for an AAAA record.
+    /// * `source` - The source of the public IP address.
+    /// * `options` - The optional settings of the record, such as its TTL.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `DdnsUpdate` with the address and the action taken, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordOptions};
+    /// use aliyun_dns::ddns::{DdnsUpdate, HttpIpSource, IpDiscovery, IpVersion};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let source = IpDiscovery::new().source(HttpIpSource::new("https://ip.example.net/v4", "https://ip.example.net/v6"));
+    /// let options = RecordOptions::new().ttl(60);
+    /// let result: Result<DdnsUpdate, _> = aliyun_dns
+    ///     .update_to_current_ip_with("example.com", "home", IpVersion::V4, &source, &options)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn update_to_current_ip_with(
+        &self,
+        domain_name: &str,
+        rr: &str,
+        version: IpVersion,
+        source: &dyn IpSource,
+        options: &RecordOptions,
+    ) -> Result<DdnsUpdate> {
+        let ip = source.public_ip(version).await?;
+        let spec =
+            RecordSpec::new(rr, version.record_type(), &ip.to_string()).options(options.clone());
+        let outcome = self.upsert_record(domain_name, &spec).await?;
+        Ok(DdnsUpdate { ip, outcome })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_ip_discovery() {
+        let v4: IpAddr = "192.0.2.1".parse().unwrap();
+        let v6: IpAddr = "2001:db8::1".parse().unwrap();
+        let discovery = IpDiscovery::new().source(v4).source(v6);
+        assert_eq!(discovery.public_ip(IpVersion::V4).await.unwrap(), v4);
+        assert_eq!(discovery.public_ip(IpVersion::V6).await.unwrap(), v6);
+
+        let error = IpDiscovery::new().source(v4).public_ip(IpVersion::V6).await;
+        assert!(matches!(error, Err(AliyunDnsError::IpDiscovery(_))));
+    }
+}
@@ -0,0 +1,81 @@
+//! Lenient deserializers for the numbers the API sends either as JSON numbers or as strings, depending on the action.
+
+use serde::{Deserialize, Deserializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A number as sent on the wire, either as a JSON number or as a string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString<T> {
+    Number(T),
+    String(String),
+}
+
+impl<T: FromStr> NumberOrString<T>
+where
+    T::Err: Display,
+{
+    fn parse<E: serde::de::Error>(self) -> Result<T, E> {
+        match self {
+            NumberOrString::Number(number) => Ok(number),
+            NumberOrString::String(string) => string
+                .trim()
+                .parse()
+                .map_err(|error| E::custom(format!("invalid number {:?}: {}", string, error))),
+        }
+    }
+}
+
+/// Deserializes a number sent either as a JSON number or as a string, such as `600` or `"600"`.
+pub(crate) fn number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    NumberOrString::deserialize(deserializer)?.parse()
+}
+
+/// Deserializes an optional number sent either as a JSON number or as a string, where `null` and an empty string are `None`.
+pub(crate) fn option_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    match Option::<NumberOrString<T>>::deserialize(deserializer)? {
+        Some(NumberOrString::String(string)) if string.trim().is_empty() => Ok(None),
+        Some(number) => number.parse().map(Some),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Counts {
+        #[serde(deserialize_with = "number")]
+        total: u32,
+        #[serde(default, deserialize_with = "option_number")]
+        ttl: Option<u64>,
+    }
+
+    #[test]
+    fn test_number_or_string() {
+        let counts: Counts = serde_json::from_str(r#"{"total": 12, "ttl": "600"}"#).unwrap();
+        assert_eq!(counts.total, 12);
+        assert_eq!(counts.ttl, Some(600));
+
+        let counts: Counts = serde_json::from_str(r#"{"total": "12", "ttl": ""}"#).unwrap();
+        assert_eq!(counts.total, 12);
+        assert_eq!(counts.ttl, None);
+
+        let counts: Counts = serde_json::from_str(r#"{"total": "12"}"#).unwrap();
+        assert_eq!(counts.ttl, None);
+
+        assert!(serde_json::from_str::<Counts>(r#"{"total": "twelve"}"#).is_err());
+    }
+}
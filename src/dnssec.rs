@@ -0,0 +1,102 @@
+//! DNSSEC APIs, used to sign a zone and retrieve the DS record to publish at the registrar.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, BasicResponse, Result};
+
+/// A struct representing the response for querying the DNSSEC settings of a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainDnssecInfoResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    /// Whether DNSSEC is enabled, either "ON" or "OFF".
+    #[serde(rename = "Status")]
+    pub status: String,
+    /// The full DS record to publish at the registrar.
+    #[serde(rename = "DsRecord", default)]
+    pub ds_record: Option<String>,
+    #[serde(rename = "KeyTag", default)]
+    pub key_tag: Option<String>,
+    #[serde(rename = "Algorithm", default)]
+    pub algorithm: Option<String>,
+    #[serde(rename = "DigestType", default)]
+    pub digest_type: Option<String>,
+    #[serde(rename = "Digest", default)]
+    pub digest: Option<String>,
+    #[serde(rename = "Flags", default)]
+    pub flags: Option<String>,
+    #[serde(rename = "PublicKey", default)]
+    pub public_key: Option<String>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+impl_response_meta!(DomainDnssecInfoResponse);
+
+impl AliyunDns {
+    /// Enables or disables DNSSEC on a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name on which DNSSEC should be enabled or disabled.
+    /// * `enabled` - `true` to enable DNSSEC, `false` to disable it.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BasicResponse, _> = aliyun_dns.set_dnssec_status("example.com", true).await;
+    /// # }
+    /// ```
+    pub async fn set_dnssec_status(
+        &self,
+        domain_name: &str,
+        enabled: bool,
+    ) -> Result<BasicResponse> {
+        let action = "SetDNSSECStatus";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("Status", if enabled { "ON" } else { "OFF" }.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries the DNSSEC settings of a domain, including the DS record data needed at the registrar.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be queried.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainDnssecInfoResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainDnssecInfoResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainDnssecInfoResponse, _> = aliyun_dns.get_domain_dnssec_info("example.com").await;
+    /// # }
+    /// ```
+    pub async fn get_domain_dnssec_info(
+        &self,
+        domain_name: &str,
+    ) -> Result<DomainDnssecInfoResponse> {
+        let action = "DescribeDomainDnssecInfo";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        self.send_request(action, params).await
+    }
+}
@@ -0,0 +1,162 @@
+//! DNS-over-HTTPS (DoH) statistics APIs, used to chart the queries served through Alibaba Cloud's DoH endpoints.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use aliyun_dns::AliyunDns;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+//! let response = aliyun_dns
+//!     .query_doh_domain_statistics("example.com", "2023-01-01", Some("2023-01-07"))
+//!     .await?;
+//! for point in response.statistics {
+//!     println!("{}: {} HTTPS queries", point.timestamp, point.https_count);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, Result};
+
+/// A struct representing the DoH query counts of a single point in time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Statistic {
+    /// The point in time, in milliseconds since the Unix epoch.
+    #[serde(rename = "Timestamp", deserialize_with = "crate::de::number")]
+    pub timestamp: i64,
+    #[serde(rename = "TotalCount", default, deserialize_with = "crate::de::number")]
+    pub total_count: u64,
+    #[serde(rename = "HttpCount", default, deserialize_with = "crate::de::number")]
+    pub http_count: u64,
+    #[serde(rename = "HttpsCount", default, deserialize_with = "crate::de::number")]
+    pub https_count: u64,
+    #[serde(
+        rename = "V4HttpCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub v4_http_count: u64,
+    #[serde(
+        rename = "V6HttpCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub v6_http_count: u64,
+    #[serde(
+        rename = "V4HttpsCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub v4_https_count: u64,
+    #[serde(
+        rename = "V6HttpsCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub v6_https_count: u64,
+    #[serde(rename = "IpCount", default, deserialize_with = "crate::de::number")]
+    pub ip_count: u64,
+}
+
+/// A struct representing the response for querying DoH statistics.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatisticsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Statistics", default)]
+    pub statistics: Vec<Statistic>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+impl_response_meta!(StatisticsResponse);
+
+impl_timestamps! {
+    Statistic { time => timestamp }
+}
+
+impl AliyunDns {
+    /// Queries the DoH statistics of a domain as a time series.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the statistics should be queried.
+    /// * `start_date` - The first day of the time range, in the `YYYY-MM-DD` format.
+    /// * `end_date` - The last day of the time range, in the `YYYY-MM-DD` format, or `None` for today.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `doh::StatisticsResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn query_doh_domain_statistics(
+        &self,
+        domain_name: &str,
+        start_date: &str,
+        end_date: Option<&str>,
+    ) -> Result<StatisticsResponse> {
+        let action = "DescribeDohDomainStatistics";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        append_date_params(&mut params, start_date, end_date);
+        self.send_request(action, params).await
+    }
+
+    /// Queries the DoH statistics of a subdomain as a time series.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_domain` - The full subdomain name (e.g., "www.example.com").
+    /// * `start_date` - The first day of the time range, in the `YYYY-MM-DD` format.
+    /// * `end_date` - The last day of the time range, in the `YYYY-MM-DD` format, or `None` for today.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `doh::StatisticsResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn query_doh_sub_domain_statistics(
+        &self,
+        sub_domain: &str,
+        start_date: &str,
+        end_date: Option<&str>,
+    ) -> Result<StatisticsResponse> {
+        let action = "DescribeDohSubDomainStatistics";
+        let mut params = HashMap::new();
+        params.insert("SubDomain", sub_domain.to_string());
+        append_date_params(&mut params, start_date, end_date);
+        self.send_request(action, params).await
+    }
+
+    /// Queries the DoH statistics of the whole account as a time series.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` - The first day of the time range, in the `YYYY-MM-DD` format.
+    /// * `end_date` - The last day of the time range, in the `YYYY-MM-DD` format, or `None` for today.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `doh::StatisticsResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn query_doh_account_statistics(
+        &self,
+        start_date: &str,
+        end_date: Option<&str>,
+    ) -> Result<StatisticsResponse> {
+        let action = "DescribeDohAccountStatistics";
+        let mut params = HashMap::new();
+        append_date_params(&mut params, start_date, end_date);
+        self.send_request(action, params).await
+    }
+}
+
+fn append_date_params(
+    params: &mut HashMap<&str, String>,
+    start_date: &str,
+    end_date: Option<&str>,
+) {
+    params.insert("StartDate", start_date.to_string());
+    if let Some(end_date) = end_date {
+        params.insert("EndDate", end_date.to_string());
+    }
+}
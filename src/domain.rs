@@ -0,0 +1,1025 @@
+//! Domain management APIs, such as adding and deleting domains on the account.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, BasicResponse, Direction, Result, SearchMode};
+
+/// The largest page size accepted by `DescribeDomains`.
+const MAX_DOMAINS_PAGE_SIZE: u32 = 100;
+
+/// A struct containing the DNS servers assigned to a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DnsServers {
+    #[serde(rename = "DnsServer")]
+    pub dns_servers: Vec<String>,
+}
+
+/// A struct representing the response for adding a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddDomainResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "DomainId")]
+    pub domain_id: String,
+    #[serde(rename = "DomainName")]
+    #[cfg_attr(feature = "idna", serde(deserialize_with = "crate::idn::unicode"))]
+    pub domain_name: String,
+    #[serde(rename = "PunyCode", default)]
+    pub puny_code: Option<String>,
+    #[serde(rename = "GroupId", default)]
+    pub group_id: Option<String>,
+    #[serde(rename = "GroupName", default)]
+    pub group_name: Option<String>,
+    #[serde(rename = "DnsServers")]
+    pub dns_servers: DnsServers,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for deleting a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeleteDomainResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing a domain on the account.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Domain {
+    #[serde(rename = "DomainId")]
+    pub domain_id: String,
+    #[serde(rename = "DomainName")]
+    #[cfg_attr(feature = "idna", serde(deserialize_with = "crate::idn::unicode"))]
+    pub domain_name: String,
+    #[serde(rename = "PunyCode", default)]
+    pub puny_code: Option<String>,
+    #[serde(rename = "AliDomain", default)]
+    pub ali_domain: bool,
+    #[serde(
+        rename = "RecordCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub record_count: u64,
+    #[serde(rename = "GroupId", default)]
+    pub group_id: Option<String>,
+    #[serde(rename = "GroupName", default)]
+    pub group_name: Option<String>,
+    #[serde(rename = "InstanceId", default)]
+    pub instance_id: Option<String>,
+    #[serde(rename = "VersionCode", default)]
+    pub version_code: Option<String>,
+    #[serde(rename = "VersionName", default)]
+    pub version_name: Option<String>,
+    #[serde(rename = "ResourceGroupId", default)]
+    pub resource_group_id: Option<String>,
+    #[serde(rename = "Remark", default)]
+    pub remark: Option<String>,
+    /// Whether the domain is starred in the console.
+    #[serde(rename = "Starmark", default)]
+    pub starmark: bool,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: Option<String>,
+    #[serde(rename = "DnsServers", default)]
+    pub dns_servers: Option<DnsServers>,
+}
+
+/// A struct containing the domains returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Domains {
+    #[serde(rename = "Domain")]
+    pub domains: Vec<Domain>,
+}
+
+/// A struct representing the response for querying the domains on the account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainsResponse {
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
+    pub total_count: u32,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "Domains")]
+    pub domains: Domains,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing a resolution line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecordLine {
+    #[serde(rename = "LineCode")]
+    pub line_code: String,
+    #[serde(rename = "LineName")]
+    pub line_name: String,
+    #[serde(rename = "LineDisplayName", default)]
+    pub line_display_name: Option<String>,
+    #[serde(rename = "FatherCode", default)]
+    pub father_code: Option<String>,
+}
+
+/// A struct containing the resolution lines returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecordLines {
+    #[serde(rename = "RecordLine")]
+    pub record_lines: Vec<RecordLine>,
+}
+
+/// A struct representing the response for querying the resolution lines supported by a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupportLinesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "RecordLines")]
+    pub record_lines: RecordLines,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct containing the TTL values allowed for the records of a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AvailableTtls {
+    #[serde(rename = "AvailableTtl")]
+    pub available_ttls: Vec<String>,
+}
+
+/// A struct representing the response for querying the details of a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainInfoResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "DomainId")]
+    pub domain_id: String,
+    #[serde(rename = "DomainName")]
+    #[cfg_attr(feature = "idna", serde(deserialize_with = "crate::idn::unicode"))]
+    pub domain_name: String,
+    #[serde(rename = "PunyCode", default)]
+    pub puny_code: Option<String>,
+    #[serde(rename = "AliDomain", default)]
+    pub ali_domain: bool,
+    #[serde(rename = "GroupId", default)]
+    pub group_id: Option<String>,
+    #[serde(rename = "GroupName", default)]
+    pub group_name: Option<String>,
+    #[serde(rename = "InstanceId", default)]
+    pub instance_id: Option<String>,
+    #[serde(rename = "VersionCode", default)]
+    pub version_code: Option<String>,
+    #[serde(rename = "VersionName", default)]
+    pub version_name: Option<String>,
+    #[serde(
+        rename = "MinTtl",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub min_ttl: Option<u32>,
+    #[serde(rename = "LineType", default)]
+    pub line_type: Option<String>,
+    #[serde(rename = "RegionLines", default)]
+    pub region_lines: bool,
+    #[serde(rename = "ResourceGroupId", default)]
+    pub resource_group_id: Option<String>,
+    #[serde(rename = "Remark", default)]
+    pub remark: Option<String>,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: Option<String>,
+    #[serde(rename = "DnsServers", default)]
+    pub dns_servers: Option<DnsServers>,
+    /// Whether secondary DNS is enabled, serving the zone from an external master as well.
+    #[serde(rename = "SlaveDns", default)]
+    pub slave_dns: Option<bool>,
+    /// Only returned when the detail attributes are requested.
+    #[serde(rename = "AvailableTtls", default)]
+    pub available_ttls: Option<AvailableTtls>,
+    /// Only returned when the detail attributes are requested.
+    #[serde(rename = "RecordLines", default)]
+    pub record_lines: Option<RecordLines>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for switching the DNS servers of a domain registered with Alibaba Cloud.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModifyHichinaDomainDnsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "OriginalDnsServers")]
+    pub original_dns_servers: DnsServers,
+    #[serde(rename = "NewDnsServers")]
+    pub new_dns_servers: DnsServers,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for extracting the main domain name from a host name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MainDomainNameResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "DomainName")]
+    #[cfg_attr(feature = "idna", serde(deserialize_with = "crate::idn::unicode"))]
+    pub domain_name: String,
+    #[serde(rename = "RR")]
+    #[cfg_attr(feature = "idna", serde(deserialize_with = "crate::idn::unicode"))]
+    pub rr: String,
+    #[serde(rename = "DomainLevel", deserialize_with = "crate::de::number")]
+    pub domain_level: u32,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct containing the DNS servers a domain is expected to be delegated to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ExpectDnsServers {
+    #[serde(rename = "ExpectDnsServer")]
+    pub expect_dns_servers: Vec<String>,
+}
+
+/// A struct representing the response for checking the NS delegation of a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainNsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// Whether every NS record of the domain points to Aliyun DNS.
+    #[serde(rename = "AllAliDns", default)]
+    pub all_ali_dns: bool,
+    /// Whether at least one NS record of the domain points to Aliyun DNS.
+    #[serde(rename = "IncludeAliDns", default)]
+    pub include_ali_dns: bool,
+    /// The NS records currently set for the domain at the registry.
+    #[serde(rename = "DnsServers", default)]
+    pub dns_servers: Option<DnsServers>,
+    /// The NS records the domain should be delegated to.
+    #[serde(rename = "ExpectDnsServers", default)]
+    pub expect_dns_servers: Option<ExpectDnsServers>,
+    #[serde(rename = "DetectFailedReasonCode", default)]
+    pub detect_failed_reason_code: Option<String>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Options for querying the domains on the account, used to page through results and filter them server-side.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{QueryDomainsOptions, SearchMode};
+///
+/// let options = QueryDomainsOptions::new()
+///     .key_word("example")
+///     .search_mode(SearchMode::Like)
+///     .page_size(100);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QueryDomainsOptions {
+    page_number: Option<u32>,
+    page_size: Option<u32>,
+    key_word: Option<String>,
+    search_mode: Option<SearchMode>,
+    group_id: Option<String>,
+    starmark: Option<bool>,
+    resource_group_id: Option<String>,
+    order_by: Option<String>,
+    direction: Option<Direction>,
+}
+
+impl QueryDomainsOptions {
+    /// Creates an empty set of options, which matches every domain on the account.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the page number to fetch, starting from 1.
+    pub fn page_number(mut self, page_number: u32) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the number of domains per page (1 to 100).
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets a keyword matched against the domain names.
+    pub fn key_word(mut self, key_word: &str) -> Self {
+        self.key_word = Some(key_word.to_string());
+        self
+    }
+
+    /// Sets how the keyword is matched. Only `SearchMode::Like` and `SearchMode::Exact` are supported.
+    pub fn search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.search_mode = Some(search_mode);
+        self
+    }
+
+    /// Restricts the results to domains in the given domain group.
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        self.group_id = Some(group_id.to_string());
+        self
+    }
+
+    /// Restricts the results to starred (`true`) or unstarred (`false`) domains.
+    pub fn starmark(mut self, starmark: bool) -> Self {
+        self.starmark = Some(starmark);
+        self
+    }
+
+    /// Restricts the results to domains in the given resource group.
+    pub fn resource_group_id(mut self, resource_group_id: &str) -> Self {
+        self.resource_group_id = Some(resource_group_id.to_string());
+        self
+    }
+
+    /// Sets the field used to sort the results (e.g., "RecordCount", "CreateTime", etc.).
+    pub fn order_by(mut self, order_by: &str) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    /// Sets the direction in which the results are sorted.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        if let Some(page_number) = self.page_number {
+            params.insert("PageNumber", page_number.to_string());
+        }
+        if let Some(page_size) = self.page_size {
+            params.insert("PageSize", page_size.to_string());
+        }
+        if let Some(key_word) = &self.key_word {
+            params.insert("KeyWord", key_word.clone());
+        }
+        if let Some(search_mode) = self.search_mode {
+            params.insert("SearchMode", search_mode.as_str().to_string());
+        }
+        if let Some(group_id) = &self.group_id {
+            params.insert("GroupId", group_id.clone());
+        }
+        if let Some(starmark) = self.starmark {
+            params.insert("Starmark", starmark.to_string());
+        }
+        if let Some(resource_group_id) = &self.resource_group_id {
+            params.insert("ResourceGroupId", resource_group_id.clone());
+        }
+        if let Some(order_by) = &self.order_by {
+            params.insert("OrderBy", order_by.clone());
+        }
+        if let Some(direction) = self.direction {
+            params.insert("Direction", direction.as_str().to_string());
+        }
+    }
+}
+
+/// A struct representing a single entry of the domain operation logs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainLog {
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(rename = "Action")]
+    pub action: String,
+    #[serde(rename = "ActionTime")]
+    pub action_time: String,
+    #[serde(rename = "ActionTimestamp", deserialize_with = "crate::de::number")]
+    pub action_timestamp: i64,
+    #[serde(rename = "Message")]
+    pub message: String,
+    #[serde(rename = "ClientIp", default)]
+    pub client_ip: Option<String>,
+}
+
+/// A struct containing the domain operation logs returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainLogs {
+    #[serde(rename = "DomainLog")]
+    pub domain_logs: Vec<DomainLog>,
+}
+
+/// A struct representing the response for querying domain operation logs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainLogsResponse {
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
+    pub total_count: u32,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "DomainLogs")]
+    pub domain_logs: DomainLogs,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Options for querying domain operation logs, used to page through results and filter them by time range.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::QueryDomainLogsOptions;
+///
+/// let options = QueryDomainLogsOptions::new()
+///     .start_date("2023-01-01")
+///     .end_date("2023-01-31")
+///     .page_size(100);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QueryDomainLogsOptions {
+    page_number: Option<u32>,
+    page_size: Option<u32>,
+    key_word: Option<String>,
+    group_id: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+}
+
+impl QueryDomainLogsOptions {
+    /// Creates an empty set of options, which matches every log entry of the account.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the page number to fetch, starting from 1.
+    pub fn page_number(mut self, page_number: u32) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the number of log entries per page (1 to 100).
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets a keyword matched against the log entries.
+    pub fn key_word(mut self, key_word: &str) -> Self {
+        self.key_word = Some(key_word.to_string());
+        self
+    }
+
+    /// Restricts the results to domains in the given domain group.
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        self.group_id = Some(group_id.to_string());
+        self
+    }
+
+    /// Sets the first day of the time range, in the `YYYY-MM-DD` format.
+    pub fn start_date(mut self, start_date: &str) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+
+    /// Sets the last day of the time range, in the `YYYY-MM-DD` format.
+    pub fn end_date(mut self, end_date: &str) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        if let Some(page_number) = self.page_number {
+            params.insert("PageNumber", page_number.to_string());
+        }
+        if let Some(page_size) = self.page_size {
+            params.insert("PageSize", page_size.to_string());
+        }
+        if let Some(key_word) = &self.key_word {
+            params.insert("KeyWord", key_word.clone());
+        }
+        if let Some(group_id) = &self.group_id {
+            params.insert("GroupId", group_id.clone());
+        }
+        if let Some(start_date) = &self.start_date {
+            params.insert("StartDate", start_date.clone());
+        }
+        if let Some(end_date) = &self.end_date {
+            // The API spells this parameter with a lowercase initial.
+            params.insert("endDate", end_date.clone());
+        }
+    }
+}
+
+/// Optional settings for adding a domain.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::AddDomainOptions;
+///
+/// let options = AddDomainOptions::new().group_id("your_group_id");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AddDomainOptions {
+    group_id: Option<String>,
+    resource_group_id: Option<String>,
+    lang: Option<String>,
+}
+
+impl AddDomainOptions {
+    /// Creates an empty set of options, which leaves every setting at the API default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the domain group the domain is added to.
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        self.group_id = Some(group_id.to_string());
+        self
+    }
+
+    /// Sets the resource group the domain is added to.
+    pub fn resource_group_id(mut self, resource_group_id: &str) -> Self {
+        self.resource_group_id = Some(resource_group_id.to_string());
+        self
+    }
+
+    /// Sets the language of the response messages (e.g., "en", "zh").
+    pub fn lang(mut self, lang: &str) -> Self {
+        self.lang = Some(lang.to_string());
+        self
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        if let Some(group_id) = &self.group_id {
+            params.insert("GroupId", group_id.clone());
+        }
+        if let Some(resource_group_id) = &self.resource_group_id {
+            params.insert("ResourceGroupId", resource_group_id.clone());
+        }
+        if let Some(lang) = &self.lang {
+            params.insert("Lang", lang.clone());
+        }
+    }
+}
+
+impl_response_meta!(
+    AddDomainResponse,
+    DeleteDomainResponse,
+    DomainsResponse,
+    SupportLinesResponse,
+    DomainInfoResponse,
+    ModifyHichinaDomainDnsResponse,
+    MainDomainNameResponse,
+    DomainNsResponse,
+    DomainLogsResponse
+);
+
+impl_timestamps! {
+    Domain { created_at => create_time }
+    DomainInfoResponse { created_at => create_time }
+    DomainLog { action_at => action_timestamp }
+}
+
+impl AliyunDns {
+    /// Adds a domain to the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be added.
+    /// * `options` - The optional settings of the domain.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `AddDomainResponse` with the assigned DNS servers if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AddDomainOptions, AddDomainResponse, AliyunDns};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<AddDomainResponse, _> = aliyun_dns.add_domain("example.com", &AddDomainOptions::new()).await;
+    /// # }
+    /// ```
+    pub async fn add_domain(
+        &self,
+        domain_name: &str,
+        options: &AddDomainOptions,
+    ) -> Result<AddDomainResponse> {
+        let action = "AddDomain";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+
+    /// Deletes a domain and all of its records from the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DeleteDomainResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DeleteDomainResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DeleteDomainResponse, _> = aliyun_dns.delete_domain("example.com").await;
+    /// # }
+    /// ```
+    pub async fn delete_domain(&self, domain_name: &str) -> Result<DeleteDomainResponse> {
+        let action = "DeleteDomain";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries the domains on the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The paging and filter options applied to the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainsResponse, QueryDomainsOptions};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = QueryDomainsOptions::new().key_word("example");
+    /// let result: Result<DomainsResponse, _> = aliyun_dns.query_domains(&options).await;
+    /// # }
+    /// ```
+    pub async fn query_domains(&self, options: &QueryDomainsOptions) -> Result<DomainsResponse> {
+        let action = "DescribeDomains";
+        let mut params = HashMap::new();
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+
+    /// Queries the details of a specific domain, such as its DNS servers, plan and minimum TTL.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be queried.
+    /// * `need_detail_attributes` - Whether to also return the available TTLs and resolution lines of the domain.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainInfoResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainInfoResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainInfoResponse, _> = aliyun_dns.get_domain_info("example.com", true).await;
+    /// # }
+    /// ```
+    pub async fn get_domain_info(
+        &self,
+        domain_name: &str,
+        need_detail_attributes: bool,
+    ) -> Result<DomainInfoResponse> {
+        let action = "DescribeDomainInfo";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("NeedDetailAttributes", need_detail_attributes.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries the resolution lines supported by the plan of a domain.
+    ///
+    /// Child lines reference their parent through `RecordLine::father_code`.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the lines should be queried.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `SupportLinesResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, SupportLinesResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<SupportLinesResponse, _> = aliyun_dns.query_support_lines("example.com").await;
+    /// # }
+    /// ```
+    pub async fn query_support_lines(&self, domain_name: &str) -> Result<SupportLinesResponse> {
+        let action = "DescribeSupportLines";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries the actual and expected NS records of a domain, to check whether it is delegated to Aliyun DNS.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be checked.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainNsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainNsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainNsResponse, _> = aliyun_dns.query_domain_ns("example.com").await;
+    /// # }
+    /// ```
+    pub async fn query_domain_ns(&self, domain_name: &str) -> Result<DomainNsResponse> {
+        let action = "DescribeDomainNs";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Switches the DNS servers of a domain registered with Alibaba Cloud (wanwang) to Aliyun DNS.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name whose DNS servers should be switched.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `ModifyHichinaDomainDnsResponse` with the original and new DNS servers if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, ModifyHichinaDomainDnsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<ModifyHichinaDomainDnsResponse, _> = aliyun_dns.modify_hichina_domain_dns("example.com").await;
+    /// # }
+    /// ```
+    pub async fn modify_hichina_domain_dns(
+        &self,
+        domain_name: &str,
+    ) -> Result<ModifyHichinaDomainDnsResponse> {
+        let action = "ModifyHichinaDomainDNS";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Extracts the main (registered) domain name from a host name.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The host name to be parsed (e.g., "a.b.example.com").
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `MainDomainNameResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, MainDomainNameResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<MainDomainNameResponse, _> = aliyun_dns.get_main_domain_name("a.b.example.com").await;
+    /// # }
+    /// ```
+    pub async fn get_main_domain_name(&self, input: &str) -> Result<MainDomainNameResponse> {
+        let action = "GetMainDomainName";
+        let mut params = HashMap::new();
+        params.insert("InputString", input.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Splits a fully qualified domain name into its RR and main domain name.
+    ///
+    /// The apex of the domain is returned as the "@" RR, and a trailing dot on `fqdn` is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `fqdn` - The fully qualified domain name to be split (e.g., "a.b.example.com").
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `(rr, domain_name)` pair (e.g., `("a.b", "example.com")`) if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let (rr, domain_name) = aliyun_dns.split_fqdn("_acme-challenge.www.example.com").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn split_fqdn(&self, fqdn: &str) -> Result<(String, String)> {
+        let fqdn = fqdn.trim_end_matches('.');
+        let response = self.get_main_domain_name(fqdn).await?;
+        let rr = if response.rr.is_empty() {
+            "@".to_string()
+        } else {
+            response.rr
+        };
+        Ok((rr, response.domain_name))
+    }
+
+    /// Sets the remark of a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name whose remark should be set.
+    /// * `remark` - The new remark, or an empty string to clear it.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BasicResponse, _> = aliyun_dns.update_domain_remark("example.com", "owner: platform-team").await;
+    /// # }
+    /// ```
+    pub async fn update_domain_remark(
+        &self,
+        domain_name: &str,
+        remark: &str,
+    ) -> Result<BasicResponse> {
+        let action = "UpdateDomainRemark";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("Remark", remark.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Moves a domain to another resource group.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be moved.
+    /// * `resource_group_id` - The ID of the target resource group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BasicResponse, _> = aliyun_dns.move_domain_resource_group("example.com", "rg-aekzxxxxxxxx").await;
+    /// # }
+    /// ```
+    pub async fn move_domain_resource_group(
+        &self,
+        domain_name: &str,
+        resource_group_id: &str,
+    ) -> Result<BasicResponse> {
+        let action = "MoveDomainResourceGroup";
+        let mut params = HashMap::new();
+        params.insert("ResourceId", domain_name.to_string());
+        params.insert("NewResourceGroupId", resource_group_id.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries the operation logs of the domains on the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The paging and time range options applied to the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainLogsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainLogsResponse, QueryDomainLogsOptions};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = QueryDomainLogsOptions::new().start_date("2023-01-01").end_date("2023-01-31");
+    /// let result: Result<DomainLogsResponse, _> = aliyun_dns.query_domain_logs(&options).await;
+    /// # }
+    /// ```
+    pub async fn query_domain_logs(
+        &self,
+        options: &QueryDomainLogsOptions,
+    ) -> Result<DomainLogsResponse> {
+        let action = "DescribeDomainLogs";
+        let mut params = HashMap::new();
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+
+    /// Queries all domains on the account, fetching every page.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing every `Domain` on the account if the operation is successful, or an error if any page request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, Domain};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<Vec<Domain>, _> = aliyun_dns.query_all_domains().await;
+    /// # }
+    /// ```
+    pub async fn query_all_domains(&self) -> Result<Vec<Domain>> {
+        let mut domains = Vec::new();
+        let mut page_number = 1;
+        loop {
+            let options = QueryDomainsOptions::new()
+                .page_number(page_number)
+                .page_size(MAX_DOMAINS_PAGE_SIZE);
+            let response = self.query_domains(&options).await?;
+            let page_len = response.domains.domains.len();
+            domains.extend(response.domains.domains);
+            if page_len == 0 || domains.len() >= response.total_count as usize {
+                return Ok(domains);
+            }
+            page_number += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_domains_response() {
+        let json = r#"{
+            "RequestId": "536E9CAD-DB30-4647-AC87-AA5CC38C5382",
+            "TotalCount": 1,
+            "PageNumber": 1,
+            "PageSize": 20,
+            "Domains": {
+                "Domain": [
+                    {
+                        "DomainId": "00efd71a-770e-4255-b54e-6fe5659baffe",
+                        "DomainName": "example.com",
+                        "AliDomain": true,
+                        "RecordCount": 3,
+                        "Starmark": true,
+                        "VersionCode": "mianfei",
+                        "DnsServers": {
+                            "DnsServer": ["dns1.hichina.com", "dns2.hichina.com"]
+                        }
+                    }
+                ]
+            }
+        }"#;
+        let response: DomainsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.total_count, 1);
+        let domain = &response.domains.domains[0];
+        assert_eq!(domain.domain_name, "example.com");
+        assert!(domain.ali_domain);
+        assert_eq!(domain.record_count, 3);
+        assert!(domain.starmark);
+        assert_eq!(domain.group_id, None);
+        assert_eq!(
+            domain.dns_servers.as_ref().unwrap().dns_servers,
+            vec!["dns1.hichina.com", "dns2.hichina.com"]
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Dry runs, in which the mutating requests are recorded and answered locally instead of being sent.
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use crate::{AliyunDns, AliyunDnsError, BatchResult, BatchResultCountResponse, Result};
+
+/// The request ID, record ID and other IDs of the responses synthesized in a dry run.
+const DRY_RUN_ID: &str = "dry-run";
+
+/// The prefixes of the actions that only read, which are sent even in a dry run.
+const READ_ACTION_PREFIXES: &[&str] = &[
+    "Describe", "Get", "List", "Check", "Query", "Preview", "Validate",
+];
+
+/// The requests recorded by a client in a dry run, shared by its copies.
+pub(crate) type DryRunLog = Arc<Mutex<Vec<DryRunRequest>>>;
+
+/// A mutating request recorded instead of being sent, in a dry run.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DryRunRequest {
+    /// The action, such as "AddDomainRecord".
+    pub action: String,
+    /// The parameters of the action, without the signature and the common parameters.
+    pub params: BTreeMap<String, String>,
+}
+
+/// Returns whether an action changes anything, judging by its name.
+fn is_mutating(action: &str) -> bool {
+    !READ_ACTION_PREFIXES
+        .iter()
+        .any(|prefix| action.starts_with(prefix))
+}
+
+/// Returns the response of a mutating action, with the fields its response type requires.
+///
+/// The IDs given in the parameters are echoed, and the IDs the API would have assigned are
+/// `"dry-run"` or 0.
+fn synthesize_response(action: &str, params: &HashMap<&str, String>) -> Value {
+    let param = |name: &str| {
+        params
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| DRY_RUN_ID.to_string())
+    };
+    let no_servers = json!({ "DnsServer": [] });
+    let fields = match action {
+        "AddDomainRecord" | "UpdateDomainRecord" | "DeleteDomainRecord" | "UpdateDNSSLBWeight" => {
+            json!({ "RecordId": param("RecordId") })
+        }
+        "SetDomainRecordStatus" => {
+            json!({ "RecordId": param("RecordId"), "Status": param("Status") })
+        }
+        "DeleteSubDomainRecords" => json!({ "RR": param("RR"), "TotalCount": 0 }),
+        "OperateBatchDomain" | "TransferDomain" => json!({ "TaskId": 0 }),
+        "AddDomain" => json!({
+            "DomainId": DRY_RUN_ID,
+            "DomainName": param("DomainName"),
+            "DnsServers": no_servers,
+        }),
+        "DeleteDomain" => json!({ "DomainName": param("DomainName") }),
+        "ModifyHichinaDomainDNS" => json!({
+            "OriginalDnsServers": no_servers,
+            "NewDnsServers": no_servers,
+        }),
+        "AddDomainGroup" | "UpdateDomainGroup" | "ChangeDomainGroup" => {
+            json!({ "GroupId": param("GroupId"), "GroupName": param("GroupName") })
+        }
+        "DeleteDomainGroup" => json!({ "GroupName": param("GroupName") }),
+        "AddDnsGtmAddressPool" => json!({ "AddrPoolId": DRY_RUN_ID }),
+        "AddDnsGtmMonitor" => json!({ "MonitorConfigId": DRY_RUN_ID }),
+        "AddGtmRecoveryPlan" => json!({ "RecoveryPlanId": 0 }),
+        "AddCustomLine" => json!({ "LineId": 0, "LineCode": DRY_RUN_ID }),
+        _ => json!({}),
+    };
+    let mut response = match fields {
+        Value::Object(fields) => fields,
+        _ => Map::new(),
+    };
+    response.insert("RequestId".to_string(), json!(DRY_RUN_ID));
+    Value::Object(response)
+}
+
+/// Returns the result of a batch task submitted in a dry run, in which every entry succeeded.
+///
+/// The task was answered locally, so its progress cannot be queried from the API.
+pub(crate) fn batch_result(task_id: i64, entries: usize) -> BatchResult {
+    let entries = entries as u32;
+    BatchResult {
+        count: BatchResultCountResponse {
+            request_id: DRY_RUN_ID.to_string(),
+            task_id: Some(task_id),
+            batch_type: None,
+            status: 1,
+            total_count: entries,
+            success_count: entries,
+            failed_count: 0,
+            reason: None,
+            extras: HashMap::new(),
+        },
+        failures: Vec::new(),
+    }
+}
+
+impl AliyunDns {
+    /// Records a mutating request and answers it locally if the client is in a dry run, or returns
+    /// `None` if the request should be sent.
+    pub(crate) fn intercept_dry_run<T: DeserializeOwned>(
+        &self,
+        action: &str,
+        params: &HashMap<&str, String>,
+    ) -> Option<Result<T>> {
+        let log = self.dry_run.as_ref()?;
+        if !is_mutating(action) {
+            return None;
+        }
+        log.lock().unwrap().push(DryRunRequest {
+            action: action.to_string(),
+            params: params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+        });
+        let response = synthesize_response(action, params);
+        Some(
+            serde_json::from_value(response.clone()).map_err(|source| AliyunDnsError::Decode {
+                source,
+                body: response.to_string(),
+            }),
+        )
+    }
+
+    /// Returns a copy of the client in a dry run, or out of it.
+    ///
+    /// In a dry run, the requests that change anything are validated and recorded, but not sent,
+    /// and answered with a response synthesized locally, whose IDs are `"dry-run"` or 0. The
+    /// requests that only read, such as queries, are still sent, so that plans and upserts are
+    /// computed against the live records. The copy starts a new record of requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the copy is in a dry run.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordSpec};
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string())
+    ///     .with_dry_run(true);
+    /// aliyun_dns.add_record("example.com", &RecordSpec::a("www", "192.0.2.1")).await?;
+    /// for request in aliyun_dns.dry_run_requests() {
+    ///     println!("Would send {} {:?}", request.action, request.params);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_dry_run(&self, enabled: bool) -> Self {
+        AliyunDns {
+            dry_run: enabled.then(DryRunLog::default),
+            ..self.clone()
+        }
+    }
+
+    /// Returns whether the client is in a dry run.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run.is_some()
+    }
+
+    /// Returns the requests recorded instead of being sent in a dry run, in order.
+    pub fn dry_run_requests(&self) -> Vec<DryRunRequest> {
+        self.dry_run
+            .as_ref()
+            .map(|log| log.lock().unwrap().clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{dry_run_client, record};
+    use crate::{
+        CopyZoneOptions, ImportMode, RecordResponse, RecordSpec, RecordStatus,
+        SetDomainRecordStatusResponse,
+    };
+
+    #[tokio::test]
+    async fn test_dry_run() {
+        let (aliyun_dns, _) = dry_run_client(Vec::new()).await;
+
+        let spec = RecordSpec::a("www", "192.0.2.1");
+        let response = aliyun_dns.add_record("example.com", &spec).await.unwrap();
+        assert_eq!(response.record_id, "dry-run");
+        let response: RecordResponse = aliyun_dns.delete_domain_record("12345").await.unwrap();
+        assert_eq!(response.record_id, "12345");
+        let response: SetDomainRecordStatusResponse = aliyun_dns
+            .set_domain_record_status("12345", false)
+            .await
+            .unwrap();
+        assert_eq!(response.status, RecordStatus::Disable);
+
+        let requests = aliyun_dns.dry_run_requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].action, "AddDomainRecord");
+        assert_eq!(requests[0].params["RR"], "www");
+        assert!(!is_mutating("DescribeDomainRecords"));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_import() {
+        let (aliyun_dns, actions) = dry_run_client(vec![record("www", "A", "192.0.2.1")]).await;
+
+        let zone = "www IN A 192.0.2.2\napi IN A 192.0.2.3";
+        let import = aliyun_dns
+            .import_zone("example.com", zone, ImportMode::Overwrite)
+            .await
+            .unwrap();
+        assert_eq!((import.added, import.deleted), (2, 1));
+        let options = CopyZoneOptions::new().mode(ImportMode::Skip);
+        let copy = aliyun_dns
+            .copy_zone("template.example.com", "example.com", &options)
+            .await
+            .unwrap();
+        assert_eq!((copy.added, copy.skipped), (0, 1));
+
+        assert!(actions
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|action| action == "DescribeDomainRecords"));
+        let requests = aliyun_dns.dry_run_requests();
+        assert!(requests
+            .iter()
+            .any(|request| request.action == "OperateBatchDomain"));
+    }
+}
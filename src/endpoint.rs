@@ -0,0 +1,93 @@
+//! API endpoints, used to target a regional or VPC endpoint instead of the global one.
+//!
+//! # Examples
+//!
+//! ```
+//! use aliyun_dns::{endpoint, AliyunDns};
+//!
+//! let aliyun_dns = AliyunDns::builder()
+//!     .credentials("your_access_key_id", "your_access_key_secret")
+//!     .endpoint(endpoint::VPC_CN_HANGZHOU)
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use url::Url;
+
+use crate::{AliyunDnsError, Result};
+
+/// The global public endpoint, used by default.
+pub const PUBLIC: &str = "alidns.aliyuncs.com";
+
+/// The public endpoint of the China (Hangzhou) region.
+pub const CN_HANGZHOU: &str = "alidns.cn-hangzhou.aliyuncs.com";
+
+/// The public endpoint of the Singapore region, used by international accounts.
+pub const AP_SOUTHEAST_1: &str = "alidns.ap-southeast-1.aliyuncs.com";
+
+/// The VPC endpoint of the China (Hangzhou) region, reachable from inside Alibaba Cloud VPCs without Internet access.
+pub const VPC_CN_HANGZHOU: &str = "alidns-vpc.cn-hangzhou.aliyuncs.com";
+
+/// Returns the public endpoint of a region.
+///
+/// # Arguments
+///
+/// * `region_id` - The ID of the region (e.g., "cn-shanghai").
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::endpoint;
+///
+/// assert_eq!(endpoint::region("cn-shanghai"), "alidns.cn-shanghai.aliyuncs.com");
+/// ```
+pub fn region(region_id: &str) -> String {
+    format!("alidns.{}.aliyuncs.com", region_id)
+}
+
+/// Returns the VPC endpoint of a region.
+///
+/// # Arguments
+///
+/// * `region_id` - The ID of the region (e.g., "cn-shanghai").
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::endpoint;
+///
+/// assert_eq!(endpoint::vpc("cn-shanghai"), "alidns-vpc.cn-shanghai.aliyuncs.com");
+/// ```
+pub fn vpc(region_id: &str) -> String {
+    format!("alidns-vpc.{}.aliyuncs.com", region_id)
+}
+
+/// Parses an endpoint given either as a host name or as a URL. Host names are reached over HTTPS.
+pub(crate) fn parse(endpoint: &str) -> Result<Url> {
+    let url = if endpoint.contains("://") {
+        Url::parse(endpoint)
+    } else {
+        Url::parse(&format!("https://{}/", endpoint))
+    };
+    url.map_err(|error| {
+        AliyunDnsError::InvalidInput(format!("Invalid endpoint {}: {}", endpoint, error))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(CN_HANGZHOU).unwrap().as_str(),
+            "https://alidns.cn-hangzhou.aliyuncs.com/"
+        );
+        assert_eq!(
+            parse("http://127.0.0.1:8080").unwrap().as_str(),
+            "http://127.0.0.1:8080/"
+        );
+        assert!(parse("not a host").is_err());
+    }
+}
@@ -0,0 +1,323 @@
+//! The error type returned by the client, used to tell API rejections apart from transport and decoding failures.
+
+use std::fmt;
+
+use crate::DomainRecord;
+
+/// The result type returned by the client.
+pub type Result<T, E = AliyunDnsError> = std::result::Result<T, E>;
+
+/// An error returned by the client.
+///
+/// # Examples
+///
+/// ```no_run
+/// use aliyun_dns::{AliyunDns, AliyunDnsError, RecordSpec};
+///
+/// # async fn run() {
+/// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+/// match aliyun_dns.add_record("example.com", &RecordSpec::a("www", "192.0.2.1")).await {
+///     Ok(response) => println!("Record ID: {}", response.record_id),
+///     Err(AliyunDnsError::Api { code, .. }) if code == "DomainRecordDuplicate" => println!("Already there"),
+///     Err(error) => eprintln!("Error: {}", error),
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AliyunDnsError {
+    /// The API rejected the request.
+    Api {
+        /// The error code, such as `DomainRecordDuplicate` or `Throttling.User`.
+        code: String,
+        message: String,
+        request_id: String,
+        /// The HTTP status of the response.
+        status: u16,
+    },
+    /// The request could not be sent, or its response could not be read.
+    Http(reqwest::Error),
+    /// The API answered with an unsuccessful HTTP status and a body that is not an API error.
+    Status { status: u16, body: String },
+    /// The response body could not be decoded.
+    Decode {
+        source: serde_json::Error,
+        body: String,
+    },
+    /// The request could not be signed.
+    Signing(String),
+    /// A setting or argument is invalid.
+    InvalidInput(String),
+    /// The credentials could not be resolved by their provider.
+    Credentials(String),
+    /// The public IP address of the machine could not be discovered.
+    IpDiscovery(String),
+    /// A record was not served by the authoritative nameservers of its zone in time.
+    Propagation(String),
+    /// A batch task did not finish in time.
+    Batch(String),
+    /// Records deleted to be replaced could not be added back after their replacements failed.
+    Restore {
+        /// The error that stopped the operation, or `None` if only some replacements failed.
+        source: Option<Box<AliyunDnsError>>,
+        /// The records that could not be added back, with the error of each.
+        failures: Vec<(DomainRecord, AliyunDnsError)>,
+    },
+}
+
+impl fmt::Display for AliyunDnsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AliyunDnsError::Api {
+                code,
+                message,
+                request_id,
+                ..
+            } => write!(
+                f,
+                "API error: Request ID: {}, Code: {}, Message: {}",
+                request_id, code, message
+            ),
+            AliyunDnsError::Http(error) => write!(f, "HTTP error: {}", error),
+            AliyunDnsError::Status { status, body } => {
+                write!(f, "Request failed with status {}: {}", status, body)
+            }
+            AliyunDnsError::Decode { source, body } => {
+                write!(f, "Failed to parse JSON response ({}): {}", source, body)
+            }
+            AliyunDnsError::Signing(message) => {
+                write!(f, "Failed to sign the request: {}", message)
+            }
+            AliyunDnsError::InvalidInput(message) => write!(f, "Invalid input: {}", message),
+            AliyunDnsError::Credentials(message) => {
+                write!(f, "Failed to resolve the credentials: {}", message)
+            }
+            AliyunDnsError::IpDiscovery(message) => {
+                write!(f, "Failed to discover the public IP address: {}", message)
+            }
+            AliyunDnsError::Propagation(message) => {
+                write!(f, "Failed to wait for the record to propagate: {}", message)
+            }
+            AliyunDnsError::Batch(message) => {
+                write!(f, "Failed to wait for the batch task: {}", message)
+            }
+            AliyunDnsError::Restore { source, failures } => {
+                write!(f, "Failed to add back the deleted records")?;
+                for (record, error) in failures {
+                    write!(
+                        f,
+                        "; {} {} {}: {}",
+                        record.rr, record.record_type, record.value, error
+                    )?;
+                }
+                match source {
+                    Some(source) => write!(f, " (after: {})", source),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for AliyunDnsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AliyunDnsError::Http(error) => Some(error),
+            AliyunDnsError::Decode { source, .. } => Some(source),
+            AliyunDnsError::Restore {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl AliyunDnsError {
+    /// Returns the ID of the request, or `None` if the error did not come from the API.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            AliyunDnsError::Api { request_id, .. } => Some(request_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the API error code, or `None` if the error did not come from the API.
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            AliyunDnsError::Api { code, .. } => Some(ErrorCode::from(code.as_str())),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the request was rejected because too many requests were sent.
+    pub fn is_throttling(&self) -> bool {
+        self.error_code().is_some_and(|code| code.is_throttling())
+    }
+
+    /// Returns whether the request referred to a domain or record that does not exist on the account.
+    pub fn is_not_found(&self) -> bool {
+        self.error_code().is_some_and(|code| code.is_not_found())
+    }
+
+    /// Returns whether the request would have created a record that already exists.
+    pub fn is_duplicate(&self) -> bool {
+        self.error_code().is_some_and(|code| code.is_duplicate())
+    }
+
+    /// Returns whether the request was rejected because a quota of the account was reached.
+    pub fn is_quota_exceeded(&self) -> bool {
+        self.error_code()
+            .is_some_and(|code| code.is_quota_exceeded())
+    }
+
+    /// Returns whether the request was rejected because of the credentials it was signed with.
+    pub fn is_auth_error(&self) -> bool {
+        self.error_code().is_some_and(|code| code.is_auth_error())
+    }
+}
+
+/// A well-known API error code.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::ErrorCode;
+///
+/// let code = ErrorCode::from("Throttling.User");
+/// assert_eq!(code, ErrorCode::ThrottlingUser);
+/// assert!(code.is_throttling());
+/// assert_eq!(code.as_str(), "Throttling.User");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `DomainRecordDuplicate`: a record with the same RR, type, line and value already exists.
+    DomainRecordDuplicate,
+    /// `DomainRecordNotBelongToUser`: the record does not exist on the account.
+    DomainRecordNotBelongToUser,
+    /// `IncorrectDomainUser`: the domain does not exist on the account.
+    IncorrectDomainUser,
+    /// `InvalidDomainName.NoExist`: the domain does not exist.
+    InvalidDomainNameNoExist,
+    /// `Throttling.User`: the account sent too many requests.
+    ThrottlingUser,
+    /// Any other `Throttling.*` code.
+    Throttling(String),
+    /// `InvalidAccessKeyId.NotFound`: the access key ID does not exist.
+    InvalidAccessKeyIdNotFound,
+    /// `SignatureDoesNotMatch`: the access key secret does not match the access key ID.
+    SignatureDoesNotMatch,
+    /// Any `QuotaExceeded.*` code.
+    QuotaExceeded(String),
+    /// Any other code.
+    Other(String),
+}
+
+impl ErrorCode {
+    /// Returns the code as sent by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorCode::DomainRecordDuplicate => "DomainRecordDuplicate",
+            ErrorCode::DomainRecordNotBelongToUser => "DomainRecordNotBelongToUser",
+            ErrorCode::IncorrectDomainUser => "IncorrectDomainUser",
+            ErrorCode::InvalidDomainNameNoExist => "InvalidDomainName.NoExist",
+            ErrorCode::ThrottlingUser => "Throttling.User",
+            ErrorCode::InvalidAccessKeyIdNotFound => "InvalidAccessKeyId.NotFound",
+            ErrorCode::SignatureDoesNotMatch => "SignatureDoesNotMatch",
+            ErrorCode::Throttling(code)
+            | ErrorCode::QuotaExceeded(code)
+            | ErrorCode::Other(code) => code,
+        }
+    }
+
+    /// Returns whether the code means that too many requests were sent.
+    pub fn is_throttling(&self) -> bool {
+        matches!(self, ErrorCode::ThrottlingUser | ErrorCode::Throttling(_))
+    }
+
+    /// Returns whether the code means that a domain or record does not exist on the account.
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::DomainRecordNotBelongToUser
+                | ErrorCode::IncorrectDomainUser
+                | ErrorCode::InvalidDomainNameNoExist
+        )
+    }
+
+    /// Returns whether the code means that a record already exists.
+    pub fn is_duplicate(&self) -> bool {
+        matches!(self, ErrorCode::DomainRecordDuplicate)
+    }
+
+    /// Returns whether the code means that a quota of the account was reached.
+    pub fn is_quota_exceeded(&self) -> bool {
+        matches!(self, ErrorCode::QuotaExceeded(_))
+    }
+
+    /// Returns whether the code means that the credentials are invalid.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::InvalidAccessKeyIdNotFound | ErrorCode::SignatureDoesNotMatch
+        )
+    }
+}
+
+impl From<&str> for ErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "DomainRecordDuplicate" => ErrorCode::DomainRecordDuplicate,
+            "DomainRecordNotBelongToUser" => ErrorCode::DomainRecordNotBelongToUser,
+            "IncorrectDomainUser" => ErrorCode::IncorrectDomainUser,
+            "InvalidDomainName.NoExist" => ErrorCode::InvalidDomainNameNoExist,
+            "Throttling.User" => ErrorCode::ThrottlingUser,
+            "InvalidAccessKeyId.NotFound" => ErrorCode::InvalidAccessKeyIdNotFound,
+            "SignatureDoesNotMatch" => ErrorCode::SignatureDoesNotMatch,
+            _ if code.starts_with("Throttling") => ErrorCode::Throttling(code.to_string()),
+            _ if code.starts_with("QuotaExceeded") => ErrorCode::QuotaExceeded(code.to_string()),
+            _ => ErrorCode::Other(code.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<reqwest::Error> for AliyunDnsError {
+    fn from(error: reqwest::Error) -> Self {
+        AliyunDnsError::Http(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_predicates() {
+        let error = AliyunDnsError::Api {
+            code: "QuotaExceeded.Record".to_string(),
+            message: String::new(),
+            request_id: "request_id".to_string(),
+            status: 400,
+        };
+
+        assert_eq!(
+            error.error_code(),
+            Some(ErrorCode::QuotaExceeded("QuotaExceeded.Record".to_string()))
+        );
+        assert!(error.is_quota_exceeded());
+        assert!(!error.is_throttling());
+        assert!(ErrorCode::from("Throttling.Api").is_throttling());
+        assert!(ErrorCode::from("IncorrectDomainUser").is_not_found());
+        assert_eq!(
+            ErrorCode::from("InvalidRR"),
+            ErrorCode::Other("InvalidRR".to_string())
+        );
+    }
+}
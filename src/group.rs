@@ -0,0 +1,280 @@
+//! Domain group APIs, used to organize the domains on the account.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, Result};
+
+/// A struct representing a domain group.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainGroup {
+    #[serde(rename = "GroupId")]
+    pub group_id: String,
+    #[serde(rename = "GroupName")]
+    pub group_name: String,
+    #[serde(
+        rename = "DomainCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub domain_count: u64,
+}
+
+/// A struct containing the domain groups returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainGroups {
+    #[serde(rename = "DomainGroup")]
+    pub domain_groups: Vec<DomainGroup>,
+}
+
+/// A struct representing the response for querying domain groups.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainGroupsResponse {
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
+    pub total_count: u32,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "DomainGroups")]
+    pub domain_groups: DomainGroups,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for adding, updating or changing a domain group.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainGroupResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "GroupId")]
+    pub group_id: String,
+    #[serde(rename = "GroupName")]
+    pub group_name: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for deleting a domain group.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeleteDomainGroupResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "GroupName")]
+    pub group_name: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Options for querying domain groups, used to page through results and filter them by name.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::QueryDomainGroupsOptions;
+///
+/// let options = QueryDomainGroupsOptions::new().key_word("customers").page_size(100);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QueryDomainGroupsOptions {
+    page_number: Option<u32>,
+    page_size: Option<u32>,
+    key_word: Option<String>,
+}
+
+impl QueryDomainGroupsOptions {
+    /// Creates an empty set of options, which matches every domain group on the account.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the page number to fetch, starting from 1.
+    pub fn page_number(mut self, page_number: u32) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the number of domain groups per page (1 to 100).
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets a keyword matched against the group names.
+    pub fn key_word(mut self, key_word: &str) -> Self {
+        self.key_word = Some(key_word.to_string());
+        self
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        if let Some(page_number) = self.page_number {
+            params.insert("PageNumber", page_number.to_string());
+        }
+        if let Some(page_size) = self.page_size {
+            params.insert("PageSize", page_size.to_string());
+        }
+        if let Some(key_word) = &self.key_word {
+            params.insert("KeyWord", key_word.clone());
+        }
+    }
+}
+
+impl_response_meta!(
+    DomainGroupsResponse,
+    DomainGroupResponse,
+    DeleteDomainGroupResponse
+);
+
+impl AliyunDns {
+    /// Adds a new domain group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_name` - The name of the domain group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainGroupResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainGroupResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainGroupResponse, _> = aliyun_dns.add_domain_group("customers").await;
+    /// # }
+    /// ```
+    pub async fn add_domain_group(&self, group_name: &str) -> Result<DomainGroupResponse> {
+        let action = "AddDomainGroup";
+        let mut params = HashMap::new();
+        params.insert("GroupName", group_name.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Renames a domain group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The ID of the domain group to be updated.
+    /// * `group_name` - The new name of the domain group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainGroupResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainGroupResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainGroupResponse, _> = aliyun_dns.update_domain_group("group_id", "enterprise-customers").await;
+    /// # }
+    /// ```
+    pub async fn update_domain_group(
+        &self,
+        group_id: &str,
+        group_name: &str,
+    ) -> Result<DomainGroupResponse> {
+        let action = "UpdateDomainGroup";
+        let mut params = HashMap::new();
+        params.insert("GroupId", group_id.to_string());
+        params.insert("GroupName", group_name.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Deletes a domain group. The domains of the group are moved back to the default group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The ID of the domain group to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DeleteDomainGroupResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DeleteDomainGroupResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DeleteDomainGroupResponse, _> = aliyun_dns.delete_domain_group("group_id").await;
+    /// # }
+    /// ```
+    pub async fn delete_domain_group(&self, group_id: &str) -> Result<DeleteDomainGroupResponse> {
+        let action = "DeleteDomainGroup";
+        let mut params = HashMap::new();
+        params.insert("GroupId", group_id.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries the domain groups on the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The paging and filter options applied to the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainGroupsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainGroupsResponse, QueryDomainGroupsOptions};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainGroupsResponse, _> = aliyun_dns.query_domain_groups(&QueryDomainGroupsOptions::new()).await;
+    /// # }
+    /// ```
+    pub async fn query_domain_groups(
+        &self,
+        options: &QueryDomainGroupsOptions,
+    ) -> Result<DomainGroupsResponse> {
+        let action = "DescribeDomainGroups";
+        let mut params = HashMap::new();
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+
+    /// Moves a domain to another domain group.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be moved.
+    /// * `group_id` - The ID of the target domain group, or an empty string for the default group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainGroupResponse` describing the target group if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainGroupResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainGroupResponse, _> = aliyun_dns.change_domain_group("example.com", "group_id").await;
+    /// # }
+    /// ```
+    pub async fn change_domain_group(
+        &self,
+        domain_name: &str,
+        group_id: &str,
+    ) -> Result<DomainGroupResponse> {
+        let action = "ChangeDomainGroup";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("GroupId", group_id.to_string());
+        self.send_request(action, params).await
+    }
+}
@@ -0,0 +1,1471 @@
+//! Global Traffic Manager (GTM) APIs, used to drive GTM failover from the same client as the DNS records.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use aliyun_dns::AliyunDns;
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+//! let response = aliyun_dns.query_gtm_instances(None, 1, 20).await?;
+//! for instance in response.instances {
+//!     println!("{}: {:?}", instance.instance_id, instance.config.instance_name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, BasicResponse, Result};
+
+/// A struct representing the configuration of a GTM instance.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InstanceConfig {
+    #[serde(rename = "InstanceName", default)]
+    pub instance_name: Option<String>,
+    #[serde(rename = "Ttl", default, deserialize_with = "crate::de::option_number")]
+    pub ttl: Option<u32>,
+    /// The contact groups notified on alerts, as a JSON array string.
+    #[serde(rename = "AlertGroup", default)]
+    pub alert_group: Option<String>,
+    #[serde(rename = "CnameType", default)]
+    pub cname_type: Option<String>,
+    /// The access strategy mode, either `GEO` or `LATENCY`.
+    #[serde(rename = "StrategyMode", default)]
+    pub strategy_mode: Option<String>,
+    /// Whether the CNAME is provided by the system (`SYSTEM_ASSIGN`) or a custom domain (`CUSTOM`).
+    #[serde(rename = "PublicCnameMode", default)]
+    pub public_cname_mode: Option<String>,
+    #[serde(rename = "PublicUserDomainName", default)]
+    pub public_user_domain_name: Option<String>,
+    #[serde(rename = "PublicRr", default)]
+    pub public_rr: Option<String>,
+    #[serde(rename = "PublicZoneName", default)]
+    pub public_zone_name: Option<String>,
+}
+
+/// A struct representing the alert quotas used by a GTM instance.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UsedQuota {
+    #[serde(
+        rename = "EmailUsedCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub email_used_count: u32,
+    #[serde(
+        rename = "SmsUsedCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub sms_used_count: u32,
+    #[serde(
+        rename = "TaskUsedCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub task_used_count: u32,
+    #[serde(
+        rename = "DingtalkUsedCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub dingtalk_used_count: u32,
+}
+
+/// A struct representing a GTM instance.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Instance {
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+    #[serde(rename = "VersionCode", default)]
+    pub version_code: Option<String>,
+    #[serde(rename = "PaymentType", default)]
+    pub payment_type: Option<String>,
+    #[serde(rename = "ResourceGroupId", default)]
+    pub resource_group_id: Option<String>,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: Option<String>,
+    #[serde(
+        rename = "CreateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub create_timestamp: Option<i64>,
+    #[serde(rename = "ExpireTime", default)]
+    pub expire_time: Option<String>,
+    #[serde(
+        rename = "ExpireTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub expire_timestamp: Option<i64>,
+    #[serde(
+        rename = "SmsQuota",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub sms_quota: Option<u32>,
+    #[serde(
+        rename = "TaskQuota",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub task_quota: Option<u32>,
+    #[serde(rename = "Config")]
+    pub config: InstanceConfig,
+    #[serde(rename = "UsedQuota", default)]
+    pub used_quota: Option<UsedQuota>,
+}
+
+/// A struct representing the response for querying the GTM instances on the account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstancesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "GtmInstances", default)]
+    pub instances: Vec<Instance>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for fetching a single GTM instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstanceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(flatten)]
+    pub instance: Instance,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// The type of the addresses in an address pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddrPoolType {
+    Ipv4,
+    Ipv6,
+    Domain,
+}
+
+impl AddrPoolType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AddrPoolType::Ipv4 => "IPV4",
+            AddrPoolType::Ipv6 => "IPV6",
+            AddrPoolType::Domain => "DOMAIN",
+        }
+    }
+}
+
+/// How the answers are balanced across the addresses of an address pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LbaStrategy {
+    /// Return all addresses.
+    AllRr,
+    /// Return addresses in proportion to their weights.
+    Ratio,
+}
+
+impl LbaStrategy {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LbaStrategy::AllRr => "ALL_RR",
+            LbaStrategy::Ratio => "RATIO",
+        }
+    }
+}
+
+/// Whether an address is served according to its health check or forced online or offline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddrMode {
+    Smart,
+    Online,
+    Offline,
+}
+
+impl AddrMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AddrMode::Smart => "SMART",
+            AddrMode::Online => "ONLINE",
+            AddrMode::Offline => "OFFLINE",
+        }
+    }
+}
+
+/// An address to be put in an address pool.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::gtm::{Addr, AddrMode};
+///
+/// let addr = Addr::new("192.0.2.1", 1).mode(AddrMode::Online).remark("primary");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Addr {
+    addr: String,
+    lba_weight: u32,
+    mode: AddrMode,
+    attribute_info: Option<String>,
+    remark: Option<String>,
+}
+
+impl Addr {
+    /// Creates an address with the given weight, served according to its health check.
+    pub fn new(addr: &str, lba_weight: u32) -> Self {
+        Addr {
+            addr: addr.to_string(),
+            lba_weight,
+            mode: AddrMode::Smart,
+            attribute_info: None,
+            remark: None,
+        }
+    }
+
+    /// Sets whether the address is served according to its health check or forced online or offline.
+    pub fn mode(mut self, mode: AddrMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the source region of the address, as a JSON string.
+    pub fn attribute_info(mut self, attribute_info: &str) -> Self {
+        self.attribute_info = Some(attribute_info.to_string());
+        self
+    }
+
+    /// Sets the remark of the address.
+    pub fn remark(mut self, remark: &str) -> Self {
+        self.remark = Some(remark.to_string());
+        self
+    }
+}
+
+/// A struct representing the response for adding an address pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddAddressPoolResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "AddrPoolId")]
+    pub addr_pool_id: String,
+    #[serde(rename = "MonitorConfigId", default)]
+    pub monitor_config_id: Option<String>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing an address pool in a list of address pools.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AddressPool {
+    #[serde(rename = "AddrPoolId")]
+    pub addr_pool_id: String,
+    #[serde(rename = "Name", default)]
+    pub name: Option<String>,
+    #[serde(rename = "Type", default)]
+    pub pool_type: Option<String>,
+    #[serde(rename = "AddrCount", default, deserialize_with = "crate::de::number")]
+    pub addr_count: u32,
+    #[serde(
+        rename = "MinAvailableAddrNum",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub min_available_addr_num: Option<u32>,
+    #[serde(rename = "MonitorConfigId", default)]
+    pub monitor_config_id: Option<String>,
+    /// Whether health checks are enabled, either `OPEN` or `CLOSE`.
+    #[serde(rename = "MonitorStatus", default)]
+    pub monitor_status: Option<String>,
+    #[serde(
+        rename = "CreateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub create_timestamp: Option<i64>,
+    #[serde(
+        rename = "UpdateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub update_timestamp: Option<i64>,
+}
+
+/// A struct containing the address pools returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AddressPools {
+    #[serde(rename = "AddrPool", default)]
+    pub addr_pools: Vec<AddressPool>,
+}
+
+/// A struct representing the response for querying the address pools of a GTM instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressPoolsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "AddrPools")]
+    pub addr_pools: AddressPools,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing an address of an address pool.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AddressPoolAddr {
+    #[serde(rename = "AddrId", deserialize_with = "crate::de::number")]
+    pub addr_id: i64,
+    #[serde(rename = "Addr")]
+    pub addr: String,
+    #[serde(
+        rename = "LbaWeight",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub lba_weight: Option<u32>,
+    /// Either `SMART`, `ONLINE` or `OFFLINE`.
+    #[serde(rename = "Mode", default)]
+    pub mode: Option<String>,
+    /// The health check result, either `OK` or `ALERT`.
+    #[serde(rename = "AlertStatus", default)]
+    pub alert_status: Option<String>,
+    #[serde(rename = "AttributeInfo", default)]
+    pub attribute_info: Option<String>,
+    #[serde(rename = "Remark", default)]
+    pub remark: Option<String>,
+    #[serde(
+        rename = "CreateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub create_timestamp: Option<i64>,
+    #[serde(
+        rename = "UpdateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub update_timestamp: Option<i64>,
+}
+
+/// A struct containing the addresses returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AddressPoolAddrs {
+    #[serde(rename = "Addr", default)]
+    pub addrs: Vec<AddressPoolAddr>,
+}
+
+/// A struct representing the response for fetching a single address pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressPoolResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(flatten)]
+    pub addr_pool: AddressPool,
+    #[serde(rename = "LbaStrategy", default)]
+    pub lba_strategy: Option<String>,
+    #[serde(rename = "Addrs")]
+    pub addrs: AddressPoolAddrs,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// The protocol used to health-check the addresses of an address pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorProtocol {
+    Http,
+    Https,
+    Ping,
+    Tcp,
+}
+
+impl MonitorProtocol {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MonitorProtocol::Http => "HTTP",
+            MonitorProtocol::Https => "HTTPS",
+            MonitorProtocol::Ping => "PING",
+            MonitorProtocol::Tcp => "TCP",
+        }
+    }
+}
+
+/// A monitoring node health checks are run from, identified by its city and ISP codes.
+#[derive(Debug, Clone)]
+pub struct IspCityNode {
+    city_code: String,
+    isp_code: String,
+}
+
+impl IspCityNode {
+    /// Creates a monitoring node from the codes returned by `query_gtm_monitor_available_config`.
+    pub fn new(city_code: &str, isp_code: &str) -> Self {
+        IspCityNode {
+            city_code: city_code.to_string(),
+            isp_code: isp_code.to_string(),
+        }
+    }
+}
+
+/// The health-check settings of an address pool.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::gtm::{IspCityNode, MonitorConfig, MonitorProtocol};
+///
+/// let config = MonitorConfig::new(
+///     MonitorProtocol::Http,
+///     r#"{"host":"www.example.com","path":"/health","code":400}"#,
+/// )
+/// .interval(60)
+/// .evaluation_count(3)
+/// .isp_city_node(IspCityNode::new("503", "465"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MonitorConfig {
+    protocol: MonitorProtocol,
+    monitor_extend_info: String,
+    interval: u32,
+    evaluation_count: u32,
+    timeout: u32,
+    isp_city_nodes: Vec<IspCityNode>,
+}
+
+impl MonitorConfig {
+    /// Creates health-check settings for the given protocol.
+    ///
+    /// `monitor_extend_info` is the protocol-specific JSON configuration, such as the host, path and failing status code of HTTP checks.
+    /// Checks run every minute, fail after a single failed evaluation and time out after 3 seconds by default.
+    pub fn new(protocol: MonitorProtocol, monitor_extend_info: &str) -> Self {
+        MonitorConfig {
+            protocol,
+            monitor_extend_info: monitor_extend_info.to_string(),
+            interval: 60,
+            evaluation_count: 1,
+            timeout: 3000,
+            isp_city_nodes: Vec::new(),
+        }
+    }
+
+    /// Sets the interval between checks, in seconds.
+    pub fn interval(mut self, interval: u32) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the number of consecutive failed checks after which an address is considered unhealthy.
+    pub fn evaluation_count(mut self, evaluation_count: u32) -> Self {
+        self.evaluation_count = evaluation_count;
+        self
+    }
+
+    /// Sets the timeout of a single check, in milliseconds.
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Adds a monitoring node to run the checks from.
+    pub fn isp_city_node(mut self, isp_city_node: IspCityNode) -> Self {
+        self.isp_city_nodes.push(isp_city_node);
+        self
+    }
+
+    /// Builds the parameters of the settings. Keys are owned since the monitoring nodes are indexed.
+    fn to_params(&self) -> Vec<(String, String)> {
+        let mut params = vec![
+            (
+                "ProtocolType".to_string(),
+                self.protocol.as_str().to_string(),
+            ),
+            (
+                "MonitorExtendInfo".to_string(),
+                self.monitor_extend_info.clone(),
+            ),
+            ("Interval".to_string(), self.interval.to_string()),
+            (
+                "EvaluationCount".to_string(),
+                self.evaluation_count.to_string(),
+            ),
+            ("Timeout".to_string(), self.timeout.to_string()),
+        ];
+        for (index, node) in self.isp_city_nodes.iter().enumerate() {
+            params.push((
+                format!("IspCityNode.{}.CityCode", index + 1),
+                node.city_code.clone(),
+            ));
+            params.push((
+                format!("IspCityNode.{}.IspCode", index + 1),
+                node.isp_code.clone(),
+            ));
+        }
+        params
+    }
+}
+
+/// A struct representing the response for adding health checks to an address pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddMonitorResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "MonitorConfigId")]
+    pub monitor_config_id: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing a monitoring node returned by the API.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MonitorNode {
+    #[serde(rename = "CityCode")]
+    pub city_code: String,
+    #[serde(rename = "CityName", default)]
+    pub city_name: Option<String>,
+    #[serde(rename = "IspCode")]
+    pub isp_code: String,
+    #[serde(rename = "IspName", default)]
+    pub isp_name: Option<String>,
+    #[serde(rename = "CountryCode", default)]
+    pub country_code: Option<String>,
+    #[serde(rename = "CountryName", default)]
+    pub country_name: Option<String>,
+    #[serde(rename = "GroupType", default)]
+    pub group_type: Option<String>,
+    #[serde(rename = "GroupName", default)]
+    pub group_name: Option<String>,
+    #[serde(rename = "Mainland", default)]
+    pub mainland: Option<bool>,
+    /// Whether the node is selected by default in the console.
+    #[serde(rename = "DefaultSelected", default)]
+    pub default_selected: Option<bool>,
+}
+
+/// A struct containing the monitoring nodes returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MonitorNodes {
+    #[serde(
+        rename = "IspCityNode",
+        alias = "Ipv4IspCityNode",
+        alias = "Ipv6IspCityNode",
+        alias = "DomainIpv4IspCityNode",
+        alias = "DomainIpv6IspCityNode",
+        default
+    )]
+    pub nodes: Vec<MonitorNode>,
+}
+
+/// A struct representing the response for fetching the health-check settings of an address pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonitorConfigResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "MonitorConfigId")]
+    pub monitor_config_id: String,
+    #[serde(rename = "ProtocolType")]
+    pub protocol_type: String,
+    #[serde(rename = "Interval", deserialize_with = "crate::de::number")]
+    pub interval: u32,
+    #[serde(rename = "EvaluationCount", deserialize_with = "crate::de::number")]
+    pub evaluation_count: u32,
+    #[serde(rename = "Timeout", deserialize_with = "crate::de::number")]
+    pub timeout: u32,
+    #[serde(rename = "MonitorExtendInfo", default)]
+    pub monitor_extend_info: Option<String>,
+    #[serde(
+        rename = "CreateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub create_timestamp: Option<i64>,
+    #[serde(
+        rename = "UpdateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub update_timestamp: Option<i64>,
+    #[serde(rename = "IspCityNodes")]
+    pub isp_city_nodes: MonitorNodes,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for querying the monitoring nodes health checks can run from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonitorAvailableConfigResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// The nodes available to check IPv4 address pools.
+    #[serde(rename = "Ipv4IspCityNodes")]
+    pub ipv4_isp_city_nodes: MonitorNodes,
+    /// The nodes available to check IPv6 address pools.
+    #[serde(rename = "Ipv6IspCityNodes")]
+    pub ipv6_isp_city_nodes: MonitorNodes,
+    /// The nodes available to check domain address pools over IPv4.
+    #[serde(rename = "DomainIpv4IspCityNodes")]
+    pub domain_ipv4_isp_city_nodes: MonitorNodes,
+    /// The nodes available to check domain address pools over IPv6.
+    #[serde(rename = "DomainIpv6IspCityNodes")]
+    pub domain_ipv6_isp_city_nodes: MonitorNodes,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing a GTM operation log.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Log {
+    #[serde(rename = "Id", deserialize_with = "crate::de::number")]
+    pub id: i64,
+    #[serde(
+        rename = "OperTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub oper_timestamp: Option<i64>,
+    #[serde(rename = "OperTime", default)]
+    pub oper_time: Option<String>,
+    #[serde(rename = "OperAction", default)]
+    pub oper_action: Option<String>,
+    #[serde(rename = "EntityType", default)]
+    pub entity_type: Option<String>,
+    #[serde(rename = "EntityId", default)]
+    pub entity_id: Option<String>,
+    #[serde(rename = "EntityName", default)]
+    pub entity_name: Option<String>,
+    #[serde(rename = "Content", default)]
+    pub content: Option<String>,
+}
+
+/// A struct containing the operation logs returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Logs {
+    #[serde(rename = "Log", default)]
+    pub logs: Vec<Log>,
+}
+
+/// A struct representing the response for querying GTM operation logs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "Logs")]
+    pub logs: Logs,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// The filters used to query GTM operation logs.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::gtm::QueryLogsOptions;
+///
+/// let options = QueryLogsOptions::new()
+///     .instance_id("your_instance_id")
+///     .start_timestamp(1672531200000)
+///     .page_size(50);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QueryLogsOptions {
+    instance_id: Option<String>,
+    keyword: Option<String>,
+    start_timestamp: Option<i64>,
+    end_timestamp: Option<i64>,
+    page_number: Option<u32>,
+    page_size: Option<u32>,
+}
+
+impl QueryLogsOptions {
+    /// Creates an empty set of filters, querying the logs of all GTM instances.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only returns the logs of the given GTM instance.
+    pub fn instance_id(mut self, instance_id: &str) -> Self {
+        self.instance_id = Some(instance_id.to_string());
+        self
+    }
+
+    /// Only returns the logs matching the given keyword.
+    pub fn keyword(mut self, keyword: &str) -> Self {
+        self.keyword = Some(keyword.to_string());
+        self
+    }
+
+    /// Only returns the logs since the given time, in milliseconds since the Unix epoch.
+    pub fn start_timestamp(mut self, start_timestamp: i64) -> Self {
+        self.start_timestamp = Some(start_timestamp);
+        self
+    }
+
+    /// Only returns the logs until the given time, in milliseconds since the Unix epoch.
+    pub fn end_timestamp(mut self, end_timestamp: i64) -> Self {
+        self.end_timestamp = Some(end_timestamp);
+        self
+    }
+
+    /// Sets the page number to fetch, starting from 1.
+    pub fn page_number(mut self, page_number: u32) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the number of logs per page (1 to 100).
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        if let Some(instance_id) = &self.instance_id {
+            params.insert("InstanceId", instance_id.clone());
+        }
+        if let Some(keyword) = &self.keyword {
+            params.insert("Keyword", keyword.clone());
+        }
+        if let Some(start_timestamp) = self.start_timestamp {
+            params.insert("StartTimestamp", start_timestamp.to_string());
+        }
+        if let Some(end_timestamp) = self.end_timestamp {
+            params.insert("EndTimestamp", end_timestamp.to_string());
+        }
+        if let Some(page_number) = self.page_number {
+            params.insert("PageNumber", page_number.to_string());
+        }
+        if let Some(page_size) = self.page_size {
+            params.insert("PageSize", page_size.to_string());
+        }
+    }
+}
+
+/// A struct representing the response for adding a recovery plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddRecoveryPlanResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "RecoveryPlanId", deserialize_with = "crate::de::number")]
+    pub recovery_plan_id: i64,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing a recovery plan.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecoveryPlan {
+    #[serde(rename = "RecoveryPlanId", deserialize_with = "crate::de::number")]
+    pub recovery_plan_id: i64,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Remark", default)]
+    pub remark: Option<String>,
+    #[serde(
+        rename = "FaultAddrPoolNum",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub fault_addr_pool_num: u32,
+    /// The state of the plan, such as `UNEXECUTED`, `EXECUTED` or `ROLLED_BACK`.
+    #[serde(rename = "Status", default)]
+    pub status: Option<String>,
+    #[serde(
+        rename = "LastExecuteTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub last_execute_timestamp: Option<i64>,
+    #[serde(
+        rename = "LastRollbackTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub last_rollback_timestamp: Option<i64>,
+    #[serde(
+        rename = "CreateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub create_timestamp: Option<i64>,
+    #[serde(
+        rename = "UpdateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub update_timestamp: Option<i64>,
+}
+
+/// A struct containing the recovery plans returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecoveryPlans {
+    #[serde(rename = "RecoveryPlan", default)]
+    pub recovery_plans: Vec<RecoveryPlan>,
+}
+
+/// A struct representing the response for querying recovery plans.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecoveryPlansResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "RecoveryPlans")]
+    pub recovery_plans: RecoveryPlans,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing a change to an access strategy that executing a recovery plan would make.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SwitchInfo {
+    #[serde(rename = "StrategyName")]
+    pub strategy_name: String,
+    #[serde(rename = "Content", default)]
+    pub content: Option<String>,
+}
+
+/// A struct containing the access strategy changes returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SwitchInfos {
+    #[serde(rename = "SwitchInfo", default)]
+    pub switch_infos: Vec<SwitchInfo>,
+}
+
+/// A struct representing the changes a recovery plan would make to a GTM instance.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Preview {
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+    #[serde(rename = "Name", default)]
+    pub name: Option<String>,
+    #[serde(rename = "UserDomainName", default)]
+    pub user_domain_name: Option<String>,
+    #[serde(rename = "SwitchInfos")]
+    pub switch_infos: SwitchInfos,
+}
+
+/// A struct containing the previews returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Previews {
+    #[serde(rename = "Preview", default)]
+    pub previews: Vec<Preview>,
+}
+
+/// A struct representing the response for previewing a recovery plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreviewRecoveryPlanResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "Previews")]
+    pub previews: Previews,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+impl_response_meta!(
+    InstancesResponse,
+    InstanceResponse,
+    AddAddressPoolResponse,
+    AddressPoolsResponse,
+    AddressPoolResponse,
+    AddMonitorResponse,
+    MonitorConfigResponse,
+    MonitorAvailableConfigResponse,
+    LogsResponse,
+    AddRecoveryPlanResponse,
+    RecoveryPlansResponse,
+    PreviewRecoveryPlanResponse
+);
+
+impl_timestamps! {
+    Instance { created_at => create_timestamp, expires_at => expire_timestamp }
+    AddressPool { created_at => create_timestamp, updated_at => update_timestamp }
+    AddressPoolAddr { created_at => create_timestamp, updated_at => update_timestamp }
+    MonitorConfigResponse { created_at => create_timestamp, updated_at => update_timestamp }
+    Log { operated_at => oper_timestamp }
+    RecoveryPlan {
+        created_at => create_timestamp,
+        updated_at => update_timestamp,
+        last_executed_at => last_execute_timestamp,
+        last_rolled_back_at => last_rollback_timestamp,
+    }
+}
+
+impl AliyunDns {
+    /// Queries a single page of the GTM instances on the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `keyword` - A keyword matched against the instance IDs and names, or `None` for all instances.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of instances per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::InstancesResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn query_gtm_instances(
+        &self,
+        keyword: Option<&str>,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<InstancesResponse> {
+        let action = "DescribeDnsGtmInstances";
+        let mut params = HashMap::new();
+        if let Some(keyword) = keyword {
+            params.insert("Keyword", keyword.to_string());
+        }
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Fetches the configuration of a GTM instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_id` - The ID of the GTM instance.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::InstanceResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn get_gtm_instance(&self, instance_id: &str) -> Result<InstanceResponse> {
+        let action = "DescribeDnsGtmInstance";
+        let mut params = HashMap::new();
+        params.insert("InstanceId", instance_id.to_string());
+        self.send_request(action, params).await
+    }
+    /// Adds an address pool to a GTM instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_id` - The ID of the GTM instance.
+    /// * `name` - The name of the address pool.
+    /// * `pool_type` - The type of the addresses in the pool.
+    /// * `lba_strategy` - How the answers are balanced across the addresses.
+    /// * `addrs` - The addresses in the pool.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::AddAddressPoolResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    /// use aliyun_dns::gtm::{AddAddressPoolResponse, Addr, AddrPoolType, LbaStrategy};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let addrs = [Addr::new("192.0.2.1", 1), Addr::new("192.0.2.2", 1)];
+    /// let result: Result<AddAddressPoolResponse, _> = aliyun_dns
+    ///     .add_gtm_address_pool("your_instance_id", "origins", AddrPoolType::Ipv4, LbaStrategy::AllRr, &addrs)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn add_gtm_address_pool(
+        &self,
+        instance_id: &str,
+        name: &str,
+        pool_type: AddrPoolType,
+        lba_strategy: LbaStrategy,
+        addrs: &[Addr],
+    ) -> Result<AddAddressPoolResponse> {
+        let action = "AddDnsGtmAddressPool";
+        let addr_params = addr_params(addrs);
+        let mut params = HashMap::new();
+        params.insert("InstanceId", instance_id.to_string());
+        params.insert("Name", name.to_string());
+        params.insert("Type", pool_type.as_str().to_string());
+        params.insert("LbaStrategy", lba_strategy.as_str().to_string());
+        for (key, value) in &addr_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Updates an address pool, replacing its addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr_pool_id` - The ID of the address pool.
+    /// * `name` - The new name of the address pool, or `None` to keep the current name.
+    /// * `lba_strategy` - How the answers are balanced across the addresses.
+    /// * `addrs` - The addresses in the pool, replacing the existing ones.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn update_gtm_address_pool(
+        &self,
+        addr_pool_id: &str,
+        name: Option<&str>,
+        lba_strategy: LbaStrategy,
+        addrs: &[Addr],
+    ) -> Result<BasicResponse> {
+        let action = "UpdateDnsGtmAddressPool";
+        let addr_params = addr_params(addrs);
+        let mut params = HashMap::new();
+        params.insert("AddrPoolId", addr_pool_id.to_string());
+        if let Some(name) = name {
+            params.insert("Name", name.to_string());
+        }
+        params.insert("LbaStrategy", lba_strategy.as_str().to_string());
+        for (key, value) in &addr_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Deletes an address pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr_pool_id` - The ID of the address pool.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn delete_gtm_address_pool(&self, addr_pool_id: &str) -> Result<BasicResponse> {
+        let action = "DeleteDnsGtmAddressPool";
+        let mut params = HashMap::new();
+        params.insert("AddrPoolId", addr_pool_id.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of the address pools of a GTM instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_id` - The ID of the GTM instance.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of address pools per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::AddressPoolsResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn query_gtm_address_pools(
+        &self,
+        instance_id: &str,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<AddressPoolsResponse> {
+        let action = "DescribeDnsGtmInstanceAddressPools";
+        let mut params = HashMap::new();
+        params.insert("InstanceId", instance_id.to_string());
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Fetches an address pool along with its addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr_pool_id` - The ID of the address pool.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::AddressPoolResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn get_gtm_address_pool(&self, addr_pool_id: &str) -> Result<AddressPoolResponse> {
+        let action = "DescribeDnsGtmInstanceAddressPool";
+        let mut params = HashMap::new();
+        params.insert("AddrPoolId", addr_pool_id.to_string());
+        self.send_request(action, params).await
+    }
+    /// Enables health checks on an address pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr_pool_id` - The ID of the address pool.
+    /// * `config` - The health-check settings.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::AddMonitorResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    /// use aliyun_dns::gtm::{AddMonitorResponse, MonitorConfig, MonitorProtocol};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let config = MonitorConfig::new(
+    ///     MonitorProtocol::Ping,
+    ///     r#"{"packetNum":20,"packetLossRate":10,"failureRate":50}"#,
+    /// );
+    /// let result: Result<AddMonitorResponse, _> = aliyun_dns.add_gtm_monitor("your_addr_pool_id", &config).await;
+    /// # }
+    /// ```
+    pub async fn add_gtm_monitor(
+        &self,
+        addr_pool_id: &str,
+        config: &MonitorConfig,
+    ) -> Result<AddMonitorResponse> {
+        let action = "AddDnsGtmMonitor";
+        let config_params = config.to_params();
+        let mut params = HashMap::new();
+        params.insert("AddrPoolId", addr_pool_id.to_string());
+        for (key, value) in &config_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Updates the health-check settings of an address pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor_config_id` - The ID of the health-check settings.
+    /// * `config` - The new health-check settings, replacing the existing ones.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn update_gtm_monitor(
+        &self,
+        monitor_config_id: &str,
+        config: &MonitorConfig,
+    ) -> Result<BasicResponse> {
+        let action = "UpdateDnsGtmMonitor";
+        let config_params = config.to_params();
+        let mut params = HashMap::new();
+        params.insert("MonitorConfigId", monitor_config_id.to_string());
+        for (key, value) in &config_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Fetches the health-check settings of an address pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor_config_id` - The ID of the health-check settings.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::MonitorConfigResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn get_gtm_monitor_config(
+        &self,
+        monitor_config_id: &str,
+    ) -> Result<MonitorConfigResponse> {
+        let action = "DescribeDnsGtmMonitorConfig";
+        let mut params = HashMap::new();
+        params.insert("MonitorConfigId", monitor_config_id.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries the monitoring nodes health checks can run from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::MonitorAvailableConfigResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn query_gtm_monitor_available_config(
+        &self,
+    ) -> Result<MonitorAvailableConfigResponse> {
+        let action = "DescribeDnsGtmMonitorAvailableConfig";
+        let params = HashMap::new();
+        self.send_request(action, params).await
+    }
+    /// Queries GTM operation logs.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The filters and page of the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::LogsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    /// use aliyun_dns::gtm::{LogsResponse, QueryLogsOptions};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = QueryLogsOptions::new().instance_id("your_instance_id");
+    /// let result: Result<LogsResponse, _> = aliyun_dns.query_gtm_logs(&options).await;
+    /// # }
+    /// ```
+    pub async fn query_gtm_logs(&self, options: &QueryLogsOptions) -> Result<LogsResponse> {
+        let action = "DescribeDnsGtmLogs";
+        let mut params = HashMap::new();
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+
+    /// Adds a recovery plan, switching traffic away from the given address pools when executed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the recovery plan.
+    /// * `fault_addr_pools` - The IDs of the address pools the plan takes out of service.
+    /// * `remark` - An optional remark for the recovery plan.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::AddRecoveryPlanResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    /// use aliyun_dns::gtm::AddRecoveryPlanResponse;
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<AddRecoveryPlanResponse, _> = aliyun_dns
+    ///     .add_gtm_recovery_plan("evacuate-hangzhou", &["your_addr_pool_id"], None)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn add_gtm_recovery_plan(
+        &self,
+        name: &str,
+        fault_addr_pools: &[&str],
+        remark: Option<&str>,
+    ) -> Result<AddRecoveryPlanResponse> {
+        let action = "AddGtmRecoveryPlan";
+        let mut params = HashMap::new();
+        params.insert("Name", name.to_string());
+        params.insert(
+            "FaultAddrPool",
+            serde_json::json!(fault_addr_pools).to_string(),
+        );
+        if let Some(remark) = remark {
+            params.insert("Remark", remark.to_string());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Deletes a recovery plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `recovery_plan_id` - The ID of the recovery plan.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn delete_gtm_recovery_plan(&self, recovery_plan_id: i64) -> Result<BasicResponse> {
+        let action = "DeleteGtmRecoveryPlan";
+        let mut params = HashMap::new();
+        params.insert("RecoveryPlanId", recovery_plan_id.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of the recovery plans on the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `keyword` - A keyword matched against the plan names, or `None` for all recovery plans.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of recovery plans per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::RecoveryPlansResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn query_gtm_recovery_plans(
+        &self,
+        keyword: Option<&str>,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<RecoveryPlansResponse> {
+        let action = "DescribeGtmRecoveryPlans";
+        let mut params = HashMap::new();
+        if let Some(keyword) = keyword {
+            params.insert("Keyword", keyword.to_string());
+        }
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Previews the access strategy changes that executing a recovery plan would make.
+    ///
+    /// # Arguments
+    ///
+    /// * `recovery_plan_id` - The ID of the recovery plan.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of GTM instances per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `gtm::PreviewRecoveryPlanResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn preview_gtm_recovery_plan(
+        &self,
+        recovery_plan_id: i64,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<PreviewRecoveryPlanResponse> {
+        let action = "PreviewGtmRecoveryPlan";
+        let mut params = HashMap::new();
+        params.insert("RecoveryPlanId", recovery_plan_id.to_string());
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Executes a recovery plan, switching traffic away from its address pools.
+    ///
+    /// # Arguments
+    ///
+    /// * `recovery_plan_id` - The ID of the recovery plan.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn execute_gtm_recovery_plan(&self, recovery_plan_id: i64) -> Result<BasicResponse> {
+        let action = "ExecuteGtmRecoveryPlan";
+        let mut params = HashMap::new();
+        params.insert("RecoveryPlanId", recovery_plan_id.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Rolls back an executed recovery plan, restoring the access strategies it changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `recovery_plan_id` - The ID of the recovery plan.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    pub async fn rollback_gtm_recovery_plan(&self, recovery_plan_id: i64) -> Result<BasicResponse> {
+        let action = "RollbackGtmRecoveryPlan";
+        let mut params = HashMap::new();
+        params.insert("RecoveryPlanId", recovery_plan_id.to_string());
+        self.send_request(action, params).await
+    }
+}
+
+/// Builds the `Addr.N.*` parameters of the given addresses.
+fn addr_params(addrs: &[Addr]) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    for (index, addr) in addrs.iter().enumerate() {
+        let n = index + 1;
+        params.push((format!("Addr.{}.Addr", n), addr.addr.clone()));
+        params.push((format!("Addr.{}.LbaWeight", n), addr.lba_weight.to_string()));
+        params.push((format!("Addr.{}.Mode", n), addr.mode.as_str().to_string()));
+        if let Some(attribute_info) = &addr.attribute_info {
+            params.push((format!("Addr.{}.AttributeInfo", n), attribute_info.clone()));
+        }
+        if let Some(remark) = &addr.remark {
+            params.push((format!("Addr.{}.Remark", n), remark.clone()));
+        }
+    }
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_instance_response() {
+        let json = r#"{
+            "RequestId": "6856BCF6-11D6-4D7E-AC53-FD579933522B",
+            "InstanceId": "gtm-cn-zz11t58**",
+            "VersionCode": "ultimate",
+            "PaymentType": "Subscription",
+            "ExpireTimestamp": 1589904000000,
+            "Config": {
+                "InstanceName": "test",
+                "Ttl": 60,
+                "StrategyMode": "GEO",
+                "PublicCnameMode": "SYSTEM_ASSIGN"
+            },
+            "UsedQuota": { "SmsUsedCount": 2 }
+        }"#;
+        let response: InstanceResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.instance.instance_id, "gtm-cn-zz11t58**");
+        assert_eq!(response.instance.config.ttl, Some(60));
+        assert_eq!(
+            response.instance.config.strategy_mode.as_deref(),
+            Some("GEO")
+        );
+        assert_eq!(response.instance.used_quota.unwrap().sms_used_count, 2);
+    }
+
+    #[test]
+    fn test_addr_params() {
+        let addrs = [
+            Addr::new("192.0.2.1", 2).remark("primary"),
+            Addr::new("192.0.2.2", 1).mode(AddrMode::Offline),
+        ];
+        let params: HashMap<String, String> = addr_params(&addrs).into_iter().collect();
+
+        assert_eq!(params.len(), 7);
+        assert_eq!(params["Addr.1.Addr"], "192.0.2.1");
+        assert_eq!(params["Addr.1.LbaWeight"], "2");
+        assert_eq!(params["Addr.1.Mode"], "SMART");
+        assert_eq!(params["Addr.1.Remark"], "primary");
+        assert_eq!(params["Addr.2.Mode"], "OFFLINE");
+    }
+
+    #[test]
+    fn test_deserialize_monitor_available_config() {
+        let json = r#"{
+            "RequestId": "6856BCF6-11D6-4D7E-AC53-FD579933522B",
+            "Ipv4IspCityNodes": { "Ipv4IspCityNode": [
+                { "CityCode": "503", "CityName": "Beijing", "IspCode": "465", "IspName": "Alibaba", "DefaultSelected": true }
+            ] },
+            "Ipv6IspCityNodes": { "Ipv6IspCityNode": [] },
+            "DomainIpv4IspCityNodes": { "DomainIpv4IspCityNode": [] },
+            "DomainIpv6IspCityNodes": { "DomainIpv6IspCityNode": [] }
+        }"#;
+        let response: MonitorAvailableConfigResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.ipv4_isp_city_nodes.nodes.len(), 1);
+        assert_eq!(response.ipv4_isp_city_nodes.nodes[0].city_code, "503");
+        assert_eq!(
+            response.ipv4_isp_city_nodes.nodes[0].default_selected,
+            Some(true)
+        );
+        assert!(response.ipv6_isp_city_nodes.nodes.is_empty());
+    }
+}
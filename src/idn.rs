@@ -0,0 +1,199 @@
+//! Internationalized domain names, such as 中文域名, converted to punycode for the API and back.
+
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+
+use crate::{AliyunDnsError, Result};
+
+/// The parameters holding domain names or host records, converted to punycode before sending,
+/// matched by the last segment of their name so as to include the entries of batch tasks such as
+/// `DomainRecordInfo.1.Rr`. Their values may be lists separated by commas.
+const NAME_PARAMS: &[&str] = &[
+    "DomainName",
+    "DomainNames",
+    "Domain",
+    "Domains",
+    "NewDomain",
+    "RR",
+    "Rr",
+    "SubDomain",
+    "InputString",
+];
+
+/// The types whose values are host names, converted to punycode as well.
+const HOST_TYPES: &[&str] = &["CNAME", "NS", "MX", "PTR"];
+
+/// Converts the labels of a name that are not ASCII to punycode, keeping the others as they are,
+/// such as "@", "*" or "_dmarc".
+pub(crate) fn to_ascii(name: &str) -> Result<String> {
+    if name.is_ascii() {
+        return Ok(name.to_string());
+    }
+    let labels: Result<Vec<String>> = name
+        .split('.')
+        .map(|label| {
+            if label.is_ascii() {
+                Ok(label.to_string())
+            } else {
+                idna::domain_to_ascii(label).map_err(|error| {
+                    AliyunDnsError::InvalidInput(format!(
+                        "Invalid internationalized name {}: {}",
+                        name, error
+                    ))
+                })
+            }
+        })
+        .collect();
+    Ok(labels?.join("."))
+}
+
+/// Converts the punycode labels of a name, starting with "xn--", back to Unicode.
+pub(crate) fn to_unicode(name: &str) -> String {
+    if !name.to_ascii_lowercase().contains("xn--") {
+        return name.to_string();
+    }
+    name.split('.')
+        .map(|label| match idna::domain_to_unicode(label) {
+            (unicode, Ok(())) => unicode,
+            _ => label.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Returns whether two host name values of a record type are the same name, one of them possibly
+/// in punycode as the API lists it and the other in Unicode as it was given.
+pub(crate) fn is_same_host(record_type: &str, a: &str, b: &str) -> Option<bool> {
+    if !HOST_TYPES.contains(&record_type.to_uppercase().as_str()) {
+        return None;
+    }
+    match (to_ascii(a), to_ascii(b)) {
+        (Ok(a), Ok(b)) => Some(a.eq_ignore_ascii_case(&b)),
+        _ => Some(a == b),
+    }
+}
+
+/// Splits the name of a parameter into the prefix of its entry, such as "DomainRecordInfo.1.",
+/// and its last segment.
+fn split_param(name: &str) -> (&str, &str) {
+    match name.rfind('.') {
+        Some(index) => name.split_at(index + 1),
+        None => ("", name),
+    }
+}
+
+/// Returns whether a parameter holds names: a domain name or host record, or the value of a
+/// record whose type has host names as values.
+fn is_name_param(params: &HashMap<&str, String>, name: &str) -> bool {
+    let (prefix, last) = split_param(name);
+    if NAME_PARAMS.contains(&last) {
+        return true;
+    }
+    last == "Value"
+        && params
+            .get(format!("{}Type", prefix).as_str())
+            .is_some_and(|record_type| HOST_TYPES.contains(&record_type.to_uppercase().as_str()))
+}
+
+/// Converts the domain names, host records and host name values among the parameters of a
+/// request to punycode.
+pub(crate) fn encode_params(params: &mut HashMap<&str, String>) -> Result<()> {
+    let names: Vec<&str> = params
+        .keys()
+        .copied()
+        .filter(|name| is_name_param(params, name))
+        .collect();
+    for name in names {
+        if let Some(value) = params.get_mut(name) {
+            let names: Result<Vec<String>> = value.split(',').map(to_ascii).collect();
+            *value = names?.join(",");
+        }
+    }
+    Ok(())
+}
+
+/// Deserializes a domain name or host record, converting punycode labels back to Unicode.
+pub(crate) fn unicode<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(to_unicode(&String::deserialize(deserializer)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{dry_run_client, record};
+    use crate::ImportMode;
+
+    #[test]
+    fn test_idn_round_trip() {
+        assert_eq!(to_ascii("例子.中国").unwrap(), "xn--fsqu00a.xn--fiqs8s");
+        assert_eq!(
+            to_ascii("_acme-challenge.网站").unwrap(),
+            "_acme-challenge.xn--5tzm5g"
+        );
+        assert_eq!(to_ascii("@").unwrap(), "@");
+        assert_eq!(to_unicode("xn--fsqu00a.xn--fiqs8s"), "例子.中国");
+        assert_eq!(to_unicode("www.example.com"), "www.example.com");
+
+        let mut params = HashMap::new();
+        params.insert("DomainName", "例子.中国".to_string());
+        params.insert("Value", "例子".to_string());
+        encode_params(&mut params).unwrap();
+        assert_eq!(params["DomainName"], "xn--fsqu00a.xn--fiqs8s");
+        assert_eq!(params["Value"], "例子");
+    }
+
+    #[tokio::test]
+    async fn test_upsert_unicode_value() {
+        let (aliyun_dns, actions) = dry_run_client(vec![record(
+            "mail",
+            "CNAME",
+            "xn--5nq051n.xn--fsqu00a.xn--fiqs8s",
+        )])
+        .await;
+        let spec = crate::RecordSpec::cname("mail", "邮件.例子.中国");
+
+        let outcome = aliyun_dns
+            .upsert_record("example.com", &spec)
+            .await
+            .unwrap();
+        assert_eq!(outcome, crate::UpsertOutcome::Unchanged("1".to_string()));
+        assert!(aliyun_dns.dry_run_requests().is_empty());
+        assert_eq!(*actions.lock().unwrap(), ["DescribeSubDomainRecords"]);
+
+        let plan = aliyun_dns.plan_zone("example.com", &[spec]).await.unwrap();
+        assert!(plan.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_unicode_domain() {
+        let (aliyun_dns, _) = dry_run_client(vec![record("www", "A", "192.0.2.1")]).await;
+        let zone = "api IN A 192.0.2.2\nmail IN CNAME 邮件.例子.中国.";
+        aliyun_dns
+            .import_zone("例子.中国", zone, ImportMode::Fail)
+            .await
+            .unwrap();
+        aliyun_dns
+            .transfer_domains(&["例子.中国", "example.com"], 12345, None)
+            .await
+            .unwrap();
+
+        let requests = aliyun_dns.dry_run_requests();
+        let params = &requests[0].params;
+        assert_eq!(
+            params["DomainRecordInfo.1.Domain"],
+            "xn--fsqu00a.xn--fiqs8s"
+        );
+        assert_eq!(params["DomainRecordInfo.2.Rr"], "mail");
+        assert_eq!(
+            params["DomainRecordInfo.2.Value"],
+            "xn--5nq051n.xn--fsqu00a.xn--fiqs8s"
+        );
+        assert_eq!(
+            requests[1].params["DomainNames"],
+            "xn--fsqu00a.xn--fiqs8s,example.com"
+        );
+    }
+}
@@ -0,0 +1,376 @@
+//! Paid instance APIs, used to list Alidns instances and bind domains to them.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, Result};
+
+/// The largest page size accepted by `DescribeInstanceDomains`.
+const MAX_INSTANCE_DOMAINS_PAGE_SIZE: u32 = 100;
+
+/// A struct representing a paid Alidns instance.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DnsProduct {
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+    #[serde(rename = "VersionCode", default)]
+    pub version_code: Option<String>,
+    #[serde(rename = "VersionName", default)]
+    pub version_name: Option<String>,
+    /// The domain the instance is attached to, for single-domain instances.
+    #[serde(rename = "Domain", default)]
+    pub domain: Option<String>,
+    #[serde(rename = "StartTime", default)]
+    pub start_time: Option<String>,
+    #[serde(rename = "EndTime", default)]
+    pub end_time: Option<String>,
+    #[serde(
+        rename = "StartTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub start_timestamp: Option<i64>,
+    #[serde(
+        rename = "EndTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub end_timestamp: Option<i64>,
+    #[serde(
+        rename = "BindDomainCount",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub bind_domain_count: Option<u64>,
+    #[serde(
+        rename = "BindDomainUsedCount",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub bind_domain_used_count: Option<u64>,
+    #[serde(
+        rename = "TTLMinValue",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub ttl_min_value: Option<u64>,
+    #[serde(rename = "PaymentType", default)]
+    pub payment_type: Option<String>,
+    #[serde(rename = "AutoRenewal", default)]
+    pub auto_renewal: bool,
+}
+
+/// A struct containing the paid instances returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DnsProducts {
+    #[serde(rename = "DnsProduct")]
+    pub dns_products: Vec<DnsProduct>,
+}
+
+/// A struct representing the response for querying the paid instances on the account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DnsProductInstancesResponse {
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
+    pub total_count: u32,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "DnsProducts")]
+    pub dns_products: DnsProducts,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for binding domains to or unbinding them from a paid instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BindInstanceDomainsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(
+        rename = "SuccessCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub success_count: u32,
+    #[serde(
+        rename = "FailedCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub failed_count: u32,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for moving a paid instance to another domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeDomainOfDnsProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// The domain the instance was attached to before the change.
+    #[serde(rename = "OriginalDomain", default)]
+    pub original_domain: Option<String>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing a domain bound to a paid instance.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InstanceDomain {
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: Option<String>,
+    #[serde(
+        rename = "CreateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub create_timestamp: Option<i64>,
+}
+
+/// A struct representing the response for querying the domains bound to a paid instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstanceDomainsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "InstanceDomains", default)]
+    pub instance_domains: Vec<InstanceDomain>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+impl_response_meta!(
+    DnsProductInstancesResponse,
+    BindInstanceDomainsResponse,
+    ChangeDomainOfDnsProductResponse,
+    InstanceDomainsResponse
+);
+
+impl_timestamps! {
+    DnsProduct { starts_at => start_timestamp, ends_at => end_timestamp }
+    InstanceDomain { created_at => create_timestamp }
+}
+
+impl AliyunDns {
+    /// Queries a single page of the paid instances on the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of instances per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DnsProductInstancesResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DnsProductInstancesResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DnsProductInstancesResponse, _> = aliyun_dns.query_dns_product_instances(1, 20).await;
+    /// # }
+    /// ```
+    pub async fn query_dns_product_instances(
+        &self,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<DnsProductInstancesResponse> {
+        let action = "DescribeDnsProductInstances";
+        let mut params = HashMap::new();
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Binds domains to a paid instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_id` - The ID of the paid instance.
+    /// * `domain_names` - The domain names to be bound (up to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BindInstanceDomainsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BindInstanceDomainsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BindInstanceDomainsResponse, _> = aliyun_dns.bind_instance_domains("instance_id", &["example.com", "example.net"]).await;
+    /// # }
+    /// ```
+    pub async fn bind_instance_domains(
+        &self,
+        instance_id: &str,
+        domain_names: &[&str],
+    ) -> Result<BindInstanceDomainsResponse> {
+        let action = "BindInstanceDomains";
+        let mut params = HashMap::new();
+        params.insert("InstanceId", instance_id.to_string());
+        params.insert("DomainNames", domain_names.join(","));
+        self.send_request(action, params).await
+    }
+
+    /// Unbinds domains from a paid instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_id` - The ID of the paid instance.
+    /// * `domain_names` - The domain names to be unbound (up to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BindInstanceDomainsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BindInstanceDomainsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BindInstanceDomainsResponse, _> = aliyun_dns.unbind_instance_domains("instance_id", &["example.com"]).await;
+    /// # }
+    /// ```
+    pub async fn unbind_instance_domains(
+        &self,
+        instance_id: &str,
+        domain_names: &[&str],
+    ) -> Result<BindInstanceDomainsResponse> {
+        let action = "UnbindInstanceDomains";
+        let mut params = HashMap::new();
+        params.insert("InstanceId", instance_id.to_string());
+        params.insert("DomainNames", domain_names.join(","));
+        self.send_request(action, params).await
+    }
+
+    /// Moves a paid instance from its current domain to another domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_id` - The ID of the paid instance.
+    /// * `new_domain` - The domain name the instance should be attached to.
+    /// * `force` - Whether to detach the new domain from any paid instance it is already attached to.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `ChangeDomainOfDnsProductResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, ChangeDomainOfDnsProductResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<ChangeDomainOfDnsProductResponse, _> = aliyun_dns.change_domain_of_dns_product("instance_id", "example.net", false).await;
+    /// # }
+    /// ```
+    pub async fn change_domain_of_dns_product(
+        &self,
+        instance_id: &str,
+        new_domain: &str,
+        force: bool,
+    ) -> Result<ChangeDomainOfDnsProductResponse> {
+        let action = "ChangeDomainOfDnsProduct";
+        let mut params = HashMap::new();
+        params.insert("InstanceId", instance_id.to_string());
+        params.insert("NewDomain", new_domain.to_string());
+        params.insert("Force", force.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of the domains bound to a paid instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_id` - The ID of the paid instance.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of domains per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `InstanceDomainsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, InstanceDomainsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<InstanceDomainsResponse, _> = aliyun_dns.query_instance_domains("instance_id", 1, 20).await;
+    /// # }
+    /// ```
+    pub async fn query_instance_domains(
+        &self,
+        instance_id: &str,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<InstanceDomainsResponse> {
+        let action = "DescribeInstanceDomains";
+        let mut params = HashMap::new();
+        params.insert("InstanceId", instance_id.to_string());
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries all domains bound to a paid instance, fetching every page.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_id` - The ID of the paid instance.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing every `InstanceDomain` bound to the instance if the operation is successful, or an error if any page request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, InstanceDomain};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<Vec<InstanceDomain>, _> = aliyun_dns.query_all_instance_domains("instance_id").await;
+    /// # }
+    /// ```
+    pub async fn query_all_instance_domains(
+        &self,
+        instance_id: &str,
+    ) -> Result<Vec<InstanceDomain>> {
+        let mut domains = Vec::new();
+        let mut page_number = 1;
+        loop {
+            let response = self
+                .query_instance_domains(instance_id, page_number, MAX_INSTANCE_DOMAINS_PAGE_SIZE)
+                .await?;
+            let page_len = response.instance_domains.len();
+            domains.extend(response.instance_domains);
+            if page_len == 0 || domains.len() >= response.total_items as usize {
+                return Ok(domains);
+            }
+            page_number += 1;
+        }
+    }
+}
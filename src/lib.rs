@@ -9,11 +9,93 @@
 //!
 //! ## Features
 //!
-//! - Add a new domain record
+//! - Add a new domain record, optionally with TTL, priority and line
+//! - Forward the IP address of the end user a record operation is made for
 //! - Delete a domain record
-//! - Delete subdomain records
-//! - Update a domain record
-//! - Query domain records
+//! - Enable or disable a domain record
+//! - Set the remark of a domain record
+//! - Delete subdomain records, optionally of a single type
+//! - Delete the records of a name and type, optionally with a given value, without failing when none exist
+//! - Point A and AAAA records to the public IP address of the machine, in the [`ddns`] module with the `ddns` feature
+//! - Update a domain record, optionally with TTL, priority and line
+//! - Describe records to add or update with the `RecordSpec` builder, such as `RecordSpec::a("www", "192.0.2.1")`
+//! - Upsert a record: create it, update it in place or leave it alone, depending on what exists
+//! - Query a single domain record by its ID
+//! - Query domain records, optionally page by page
+//! - Query all domain records of a zone across pages
+//! - Export the records of a domain as a BIND zone file, and import zone files with batch tasks
+//! - Diff the records of two domains, or of a domain and a zone file snapshot, record by record and field by field
+//! - Copy the records of a template domain to a new domain, with conflict handling and host record filters
+//! - Change the TTL of every matching record of a domain at once, with concurrent, throttling-aware requests
+//! - Rename a host record, moving its records without a moment where the name stops resolving
+//! - Add, update or delete hundreds of records concurrently, with a bounded number of requests in flight
+//! - Set up mail with the MX, SPF, DKIM and DMARC records of Alibaba Mail, Google Workspace or Microsoft 365, in the [`presets`] module
+//! - Assemble and check the values of SRV and CAA records with [`Srv`] and [`Caa`]
+//! - Split TXT values longer than 255 bytes, such as DKIM keys, into character strings and join them back
+//! - Use Unicode domain names and host records, such as 中文域名, converted to punycode and back (with the `idna` feature)
+//! - Catch malformed values, such as an IPv6 address in an A record or a CNAME next to other records, before sending them
+//! - Name the apex and wildcard host records with `RecordSpec::APEX` and `RecordSpec::WILDCARD`, checked against the types they accept
+//! - Fetch the one record of a host record and type with `get_record`, without paging nor filtering
+//! - Reconcile the records of a domain with a desired set of records, as a plan of changes to review and apply, in the [`reconcile`] module
+//! - Filter domain records server-side by keyword, type, line, status or group
+//! - Sort domain records server-side
+//! - Query the records of a single subdomain
+//! - Query the operation logs of domain records
+//! - Generate TXT records for domain ownership verification
+//! - Publish and clear the TXT records of ACME DNS-01 challenges, for certificate automation
+//! - Wait for a record to be served by the authoritative nameservers, with the `propagation` feature
+//! - Add or delete a domain on the account
+//! - Query the domains on the account, optionally only the starred ones
+//! - Query the details of a domain, such as its DNS servers and minimum TTL
+//! - Split a fully qualified domain name into its RR and main domain name
+//! - Query the resolution lines supported by a domain
+//! - Check whether a domain is delegated to Aliyun DNS
+//! - Switch a domain registered with Alibaba Cloud to Aliyun DNS
+//! - Set the remark of a domain
+//! - Move a domain to another resource group
+//! - Query the operation logs of the domains on the account
+//! - Add, rename, delete and query domain groups
+//! - Move a domain to another domain group
+//! - Manage weighted round-robin (DNS SLB) on subdomains
+//! - Add or delete many domains and records in a single batch task, and track its results
+//! - List paid instances and the domains bound to them, bind domains to them and move them between domains
+//! - Manage custom resolution lines for specific IP ranges
+//! - Enable DNSSEC and retrieve DS record data
+//! - Tag domains and find domains by tag
+//! - Query the resolution statistics of domains and subdomains, and rank every domain by resolve count
+//! - Query DNS-over-HTTPS statistics, in the [`doh`] module
+//! - Manage cache-accelerated domains
+//! - Transfer domains between accounts and retrieve domains held by other accounts
+//! - Drive Global Traffic Manager instances, address pools, health checks and recovery plans, in the [`gtm`] module
+//! - Sign requests with temporary STS credentials and their security token
+//! - Resolve credentials before each request from pluggable providers, such as environment variables or refreshing STS tokens
+//! - Sign requests on ECS instances with the credentials of their RAM role, without embedded secrets
+//! - Assume RAM roles through STS, for cross-account DNS management
+//! - Exchange the OIDC tokens of Kubernetes service accounts for STS credentials (RRSA), for keyless workloads on ACK
+//! - Read credentials from the profiles of the Aliyun CLI configuration file
+//! - Discover credentials with the default chain of providers, like the official SDKs
+//! - Call any action without a dedicated method yet, with the same signing, retries and error handling
+//! - Keep the untouched JSON body of responses next to their deserialized value
+//! - Collect response fields added to the API after this crate in the `extras` of each response, instead of failing
+//! - Read counts, TTLs and other numbers as integers, whether the API sends them as numbers or as strings
+//! - Read creation, update and log timestamps as `DateTime<Utc>`, with the raw values preserved
+//! - Clone, compare and serialize the response types, which serialize back to the field names of the API
+//! - Name resolution lines with the `Line` enum rather than error-prone codes
+//! - Read and set the status of records with the `RecordStatus` enum
+//! - Reject TTLs out of range, or below the minimum TTL of the domain's plan, before sending the request
+//! - Send requests through HTTP, HTTPS or SOCKS5 proxies
+//! - Configure connect and request timeouts, and override the request timeout for some calls
+//! - Send the parameters and signature in a POST body rather than in URLs, or in GET query strings if preferred
+//! - Build clients with a custom endpoint, timeout or user agent
+//! - Run in a dry run, recording the requests that change anything instead of sending them
+//! - Identify the calling service in the `User-Agent` header, ahead of `aliyun_dns/<version>`
+//! - Target regional or VPC endpoints, listed in the [`endpoint`] module
+//! - Tell API rejections apart from network and decoding failures with the typed [`AliyunDnsError`]
+//! - Get the request ID of every call, successful or not, through [`ResponseMeta`]
+//! - Branch on well-known API error codes with [`ErrorCode`] and predicates such as `is_throttling`
+//! - Retry throttled and failed requests automatically, with exponential backoff and jitter
+//! - Share an existing `reqwest::Client` with the rest of your application
+//! - Receive API messages, errors included, in English or Chinese for the whole client or a single request
 //!
 //! ## Usage
 //!
@@ -24,6 +106,14 @@
 //! aliyun_dns = "0.1.0"
 //! ```
 //!
+//! TLS is provided by the platform library (OpenSSL on Linux) through the default `native-tls` feature.
+//! For builds without OpenSSL, such as static musl binaries, use rustls instead:
+//!
+//! ```toml
+//! [dependencies]
+//! aliyun_dns = { version = "0.1.0", default-features = false, features = ["rustls-tls"] }
+//! ```
+//!
 //! Then, in your code, create a new `AliyunDns` instance with your Aliyun API Access Key ID and Secret, and start using the provided methods.
 //!
 //! ```rust
@@ -115,15 +205,180 @@
 //! Happy coding! 🦀
 
 // Include the rest of the crate's implementation here.
-use anyhow::{Context, Result};
 use chrono::Utc;
 use hmac::{Hmac, Mac};
 use reqwest::{Client, Response};
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use sha1::Sha1;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 use url::Url;
 use base64::Engine;
+use dry_run::DryRunLog;
+use validate::validate_record;
+
+/// Implements `ResponseMeta` for response types with a `request_id` field.
+macro_rules! impl_response_meta {
+    ($($response:ty),* $(,)?) => {
+        $(
+            impl crate::ResponseMeta for $response {
+                fn request_id(&self) -> &str {
+                    &self.request_id
+                }
+            }
+        )*
+    };
+}
+
+/// Implements accessors returning the raw timestamp fields of a type as `DateTime<Utc>`.
+macro_rules! impl_timestamps {
+    ($($type:ty { $($method:ident => $field:ident),* $(,)? })*) => {
+        $(
+            impl $type {
+                $(
+                    #[doc = concat!(
+                        "Returns `", stringify!($field), "` as a UTC date and time, or `None` if it is ",
+                        "missing or cannot be parsed."
+                    )]
+                    pub fn $method(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+                        crate::time::AsDateTime::as_date_time(&self.$field)
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "ddns")]
+pub mod ddns;
+pub mod doh;
+pub mod endpoint;
+pub mod gtm;
+pub mod presets;
+pub mod reconcile;
+
+mod acme;
+mod batch;
+mod bulk;
+mod builder;
+mod cache;
+mod copy;
+mod credentials;
+mod custom_line;
+mod de;
+mod dnssec;
+mod domain;
+mod dry_run;
+mod error;
+mod group;
+#[cfg(feature = "idna")]
+mod idn;
+mod instance;
+mod line;
+mod lookup;
+mod profile;
+#[cfg(feature = "propagation")]
+mod propagation;
+mod rdata;
+mod record_spec;
+mod rename;
+mod retry;
+mod slb;
+mod statistics;
+mod sts;
+mod tag;
+#[cfg(test)]
+mod test_server;
+mod time;
+mod transfer;
+mod ttl;
+mod txt;
+mod upsert;
+mod validate;
+mod zone;
+mod zone_diff;
+
+pub use batch::{
+    BatchEntry, BatchResult, BatchResultCountResponse, BatchResultDetail, BatchResultDetailResponse,
+    BatchResultDetails, BatchTaskResponse, BatchTaskType,
+};
+pub use builder::AliyunDnsBuilder;
+pub use bulk::BulkUpdate;
+pub use cache::{
+    CacheDomain, CacheDomainConfig, CacheDomainsResponse, SourceDnsServer, SourceProtocol,
+};
+pub use credentials::{
+    Credentials, CredentialsFuture, CredentialsProvider, DefaultProviderChain, EcsRamRoleProvider,
+    EnvironmentProvider, RefreshingProvider, ENV_ACCESS_KEY_ID, ENV_ACCESS_KEY_SECRET,
+    ENV_ECS_METADATA, ENV_ECS_METADATA_DISABLED, ENV_IMDSV1_DISABLED, ENV_SECURITY_TOKEN,
+};
+pub use custom_line::{
+    AddCustomLineResponse, CustomLine, CustomLineResponse, CustomLinesResponse, IpSegment,
+};
+pub use dnssec::DomainDnssecInfoResponse;
+pub use dry_run::DryRunRequest;
+pub use error::{AliyunDnsError, ErrorCode, Result};
+pub use domain::{
+    AddDomainOptions, AddDomainResponse, AvailableTtls, DeleteDomainResponse, DnsServers, Domain,
+    DomainInfoResponse, DomainLog, DomainLogs, DomainLogsResponse, DomainNsResponse, Domains,
+    DomainsResponse, ExpectDnsServers, MainDomainNameResponse, ModifyHichinaDomainDnsResponse,
+    QueryDomainLogsOptions, QueryDomainsOptions, RecordLine, RecordLines, SupportLinesResponse,
+};
+pub use group::{
+    DeleteDomainGroupResponse, DomainGroup, DomainGroupResponse, DomainGroups,
+    DomainGroupsResponse, QueryDomainGroupsOptions,
+};
+pub use instance::{
+    BindInstanceDomainsResponse, ChangeDomainOfDnsProductResponse, DnsProduct,
+    DnsProductInstancesResponse, DnsProducts, InstanceDomain, InstanceDomainsResponse,
+};
+pub use retry::RetryPolicy;
+pub use line::Line;
+pub use lookup::GetRecordOptions;
+pub use profile::{ProfileProvider, ENV_CREDENTIALS_FILE, ENV_PROFILE};
+pub use rdata::{Caa, CaaTag, Srv};
+pub use record_spec::RecordSpec;
+pub use slb::{
+    DnsSlbSubDomainsResponse, LineAlgorithm, LineAlgorithms, SetDnsSlbStatusResponse,
+    SlbSubDomain, SlbSubDomains, UpdateDnsSlbWeightResponse,
+};
+pub use statistics::{
+    DomainResolveStatistic, DomainResolveStatisticsSummaryResponse, DomainStatistic,
+    DomainStatistics, DomainStatisticsResponse, DomainStatisticsSummaryResponse, RecordStatistic,
+    RecordStatistics, RecordStatisticsResponse, RecordStatisticsSummaryResponse, Statistic,
+    Statistics, StatisticsSummaryOptions,
+};
+pub use sts::{
+    AssumeRoleProvider, OidcProvider, ENV_OIDC_PROVIDER_ARN, ENV_OIDC_TOKEN_FILE, ENV_ROLE_ARN,
+    ENV_ROLE_SESSION_NAME,
+};
+pub use tag::{Tag, TagResource, TagResourcesResponse};
+pub use transfer::{
+    DomainTransfer, DomainTransfers, TransferDirection, TransferDomainResponse,
+    TransferDomainsResponse,
+};
+pub use ttl::Ttl;
+pub use txt::{join_txt, split_txt};
+pub use upsert::UpsertOutcome;
+pub use validate::validate_records;
+pub use copy::CopyZoneOptions;
+pub use zone::{parse_zone, ImportMode, ZoneImport};
+pub use zone_diff::{diff_zones, FieldChange, RecordChange, ZoneDiff};
+
+/// The version of the Aliyun DNS API.
+const API_VERSION: &str = "2015-01-09";
+
+/// The `User-Agent` header sent with every request, unless set otherwise.
+const USER_AGENT: &str = concat!("aliyun_dns/", env!("CARGO_PKG_VERSION"));
+
+/// The largest page size accepted by the paginated Aliyun DNS actions.
+const MAX_PAGE_SIZE: u32 = 500;
+
+/// The default number of requests the bulk helpers send at once.
+const DEFAULT_CONCURRENCY: usize = 8;
 
 /// An enum representing the API response, containing either a successful result or an error.
 ///
@@ -132,87 +387,789 @@ use base64::Engine;
 #[serde(bound(deserialize = "T: Deserialize<'de>"))]
 #[serde(untagged)] // Use untagged enum to handle different response structures
 enum ApiResponse<T> {
-    Success(T),
+    // Tried first, since a response type made only of `RequestId` would also match an error body.
     Error {
         #[serde(rename = "RequestId")]
         request_id: String,
 
-        #[serde(rename = "Code", default)]
-        error_code: Option<String>,
+        #[serde(rename = "Code")]
+        error_code: String,
 
         #[serde(rename = "Message", default)]
         error_message: Option<String>,
     },
+    Success(T),
+}
+
+/// Metadata shared by every API response.
+///
+/// # Examples
+///
+/// ```no_run
+/// use aliyun_dns::{AliyunDns, ResponseMeta};
+///
+/// # async fn run() {
+/// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+/// match aliyun_dns.delete_domain_record("record_id").await {
+///     Ok(response) => println!("Deleted in request {}", response.request_id()),
+///     Err(error) => println!("Failed in request {:?}: {}", error.request_id(), error),
+/// }
+/// # }
+/// ```
+pub trait ResponseMeta {
+    /// Returns the ID of the request, to be quoted in support tickets to Alibaba Cloud.
+    fn request_id(&self) -> &str;
+}
+
+/// A response deserialized into `T`, along with its untouched JSON body.
+///
+/// The JSON body gives access to the fields `T` does not model yet, and helps debugging deserialization mismatches.
+///
+/// # Examples
+///
+/// ```no_run
+/// use aliyun_dns::{AliyunDns, DomainRecordsResponse, Raw};
+///
+/// # async fn run() {
+/// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+/// let params = [("DomainName".to_string(), "example.com".to_string())];
+/// let result: Result<Raw<DomainRecordsResponse>, _> = aliyun_dns.call("DescribeDomainRecords", params).await;
+/// if let Ok(response) = result {
+///     println!("{} records, {}", response.total_count, response.json["DomainRecords"]);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Raw<T> {
+    /// The deserialized response.
+    pub value: T,
+    /// The JSON body of the response.
+    pub json: serde_json::Value,
+}
+
+impl<T> Raw<T> {
+    /// Returns the deserialized response, dropping the JSON body.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> std::ops::Deref for Raw<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: ResponseMeta> ResponseMeta for Raw<T> {
+    fn request_id(&self) -> &str {
+        self.value.request_id()
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Raw<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let json = serde_json::Value::deserialize(deserializer)?;
+        let value = T::deserialize(&json).map_err(serde::de::Error::custom)?;
+        Ok(Raw { value, json })
+    }
 }
 
 /// A struct representing a domain record.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct DomainRecord {
     #[serde(rename = "RR")]
+    #[cfg_attr(feature = "idna", serde(deserialize_with = "crate::idn::unicode"))]
     pub rr: String,
     #[serde(rename = "Line")]
     pub line: String,
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: RecordStatus,
     #[serde(rename = "Locked")]
     pub locked: bool,
     #[serde(rename = "Type")]
     pub record_type: String,
     #[serde(rename = "DomainName")]
+    #[cfg_attr(feature = "idna", serde(deserialize_with = "crate::idn::unicode"))]
     pub domain_name: String,
     #[serde(rename = "Value")]
     pub value: String,
     #[serde(rename = "RecordId")]
     pub record_id: String,
-    #[serde(rename = "TTL")]
+    #[serde(rename = "TTL", deserialize_with = "crate::de::number")]
     pub ttl: u32,
+    #[serde(rename = "Remark", default)]
+    pub remark: Option<String>,
+    /// The priority of an MX record.
+    #[serde(rename = "Priority", default, deserialize_with = "crate::de::option_number")]
+    pub priority: Option<u32>,
+    /// The weight of the record, when weighted round-robin is enabled for its subdomain.
+    #[serde(rename = "Weight", default, deserialize_with = "crate::de::option_number")]
+    pub weight: Option<u32>,
+    /// The creation time of the record, in milliseconds since the Unix epoch.
+    #[serde(rename = "CreateTimestamp", default, deserialize_with = "crate::de::option_number")]
+    pub create_timestamp: Option<i64>,
+    /// The last update time of the record, in milliseconds since the Unix epoch.
+    #[serde(rename = "UpdateTimestamp", default, deserialize_with = "crate::de::option_number")]
+    pub update_timestamp: Option<i64>,
 }
 
 /// A struct representing the response for querying domain records.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DomainRecordsResponse {
-    #[serde(rename = "TotalCount")]
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
     pub total_count: u32,
     #[serde(rename = "RequestId")]
     pub request_id: String,
-    #[serde(rename = "PageSize")]
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
     pub page_size: u32,
     #[serde(rename = "DomainRecords")]
     pub domain_records: DomainRecords,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
 }
 
 /// A struct containing the domain records returned in the response.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct DomainRecords {
     #[serde(rename = "Record")]
     pub records: Vec<DomainRecord>,
 }
 
+/// A struct representing the response for querying the details of a single domain record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainRecordInfoResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "DomainId", default)]
+    pub domain_id: Option<String>,
+    #[serde(rename = "GroupId", default)]
+    pub group_id: Option<String>,
+    #[serde(rename = "GroupName", default)]
+    pub group_name: Option<String>,
+    #[serde(rename = "PunyCode", default)]
+    pub puny_code: Option<String>,
+    #[serde(flatten)]
+    pub record: DomainRecord,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
 /// A struct representing the response for deleting subdomain records.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeleteSubDomainRecordsResponse {
     #[serde(rename = "RR")]
+    #[cfg_attr(feature = "idna", serde(deserialize_with = "crate::idn::unicode"))]
     pub rr: String,
-    #[serde(rename = "TotalCount")]
-    pub total_count: String,
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
+    pub total_count: u32,
     #[serde(rename = "RequestId")]
     pub request_id: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
 }
 
 /// A struct representing the response for adding, updating, or deleting a domain record.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RecordResponse {
     #[serde(rename = "RequestId")]
     pub request_id: String,
     #[serde(rename = "RecordId")]
     pub record_id: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// The status of a domain record.
+///
+/// The API spells the status with varying case (`ENABLE`, `Enable`), so it is deserialized case-insensitively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum RecordStatus {
+    /// The record is answered.
+    Enable,
+    /// The record is kept but not answered.
+    Disable,
+}
+
+impl RecordStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RecordStatus::Enable => "Enable",
+            RecordStatus::Disable => "Disable",
+        }
+    }
+}
+
+impl From<bool> for RecordStatus {
+    /// Converts `true` to `RecordStatus::Enable` and `false` to `RecordStatus::Disable`.
+    fn from(enabled: bool) -> Self {
+        if enabled {
+            RecordStatus::Enable
+        } else {
+            RecordStatus::Disable
+        }
+    }
+}
+
+impl fmt::Display for RecordStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let status = String::deserialize(deserializer)?;
+        if status.eq_ignore_ascii_case("enable") {
+            Ok(RecordStatus::Enable)
+        } else if status.eq_ignore_ascii_case("disable") {
+            Ok(RecordStatus::Disable)
+        } else {
+            Err(serde::de::Error::unknown_variant(&status, &["Enable", "Disable"]))
+        }
+    }
+}
+
+/// The search mode used by keyword filters when querying domain records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Fuzzy matching (the API default).
+    Like,
+    /// Exact matching.
+    Exact,
+    /// Advanced mode, where `RRKeyWord`, `TypeKeyWord` and `ValueKeyWord` are applied separately.
+    Advanced,
+}
+
+impl SearchMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SearchMode::Like => "LIKE",
+            SearchMode::Exact => "EXACT",
+            SearchMode::Advanced => "ADVANCED",
+        }
+    }
+}
+
+/// The sort direction used when ordering query results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Ascending order.
+    Asc,
+    /// Descending order (the API default).
+    Desc,
+}
+
+impl Direction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Direction::Asc => "ASC",
+            Direction::Desc => "DESC",
+        }
+    }
+}
+
+/// Options for querying domain records, used to page through results and filter them server-side.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{QueryDomainRecordsOptions, SearchMode};
+///
+/// let options = QueryDomainRecordsOptions::new()
+///     .rr_key_word("_acme-challenge")
+///     .type_key_word("TXT")
+///     .search_mode(SearchMode::Advanced);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QueryDomainRecordsOptions {
+    page_number: Option<u32>,
+    page_size: Option<u32>,
+    key_word: Option<String>,
+    rr_key_word: Option<String>,
+    type_key_word: Option<String>,
+    value_key_word: Option<String>,
+    search_mode: Option<SearchMode>,
+    record_type: Option<String>,
+    line: Option<Line>,
+    status: Option<RecordStatus>,
+    group_id: Option<String>,
+    order_by: Option<String>,
+    direction: Option<Direction>,
+    user_client_ip: Option<String>,
+}
+
+impl QueryDomainRecordsOptions {
+    /// Creates an empty set of options, which matches every record of the domain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the page number to fetch, starting from 1.
+    pub fn page_number(mut self, page_number: u32) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the number of records per page (1 to 500).
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets a keyword matched against both the RR and the value of the records.
+    pub fn key_word(mut self, key_word: &str) -> Self {
+        self.key_word = Some(key_word.to_string());
+        self
+    }
+
+    /// Sets a keyword matched against the RR of the records.
+    pub fn rr_key_word(mut self, rr_key_word: &str) -> Self {
+        self.rr_key_word = Some(rr_key_word.to_string());
+        self
+    }
+
+    /// Sets a keyword matched against the type of the records.
+    pub fn type_key_word(mut self, type_key_word: &str) -> Self {
+        self.type_key_word = Some(type_key_word.to_string());
+        self
+    }
+
+    /// Sets a keyword matched against the value of the records.
+    pub fn value_key_word(mut self, value_key_word: &str) -> Self {
+        self.value_key_word = Some(value_key_word.to_string());
+        self
+    }
+
+    /// Sets how the keyword filters are matched.
+    pub fn search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.search_mode = Some(search_mode);
+        self
+    }
+
+    /// Restricts the results to records of the given type (e.g., "A", "TXT", etc.).
+    pub fn record_type(mut self, record_type: &str) -> Self {
+        self.record_type = Some(record_type.to_string());
+        self
+    }
+
+    /// Restricts the results to records on the given resolution line (e.g., `Line::Default`, `Line::Telecom` or a line code).
+    pub fn line(mut self, line: impl Into<Line>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    /// Restricts the results to records with the given status.
+    pub fn status(mut self, status: RecordStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Restricts the results to domains in the given domain group.
+    pub fn group_id(mut self, group_id: &str) -> Self {
+        self.group_id = Some(group_id.to_string());
+        self
+    }
+
+    /// Sets the field used to sort the results (e.g., "gmtCreate", "rr", "type", etc.).
+    pub fn order_by(mut self, order_by: &str) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    /// Sets the direction in which the results are sorted.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the IP address of the end user on whose behalf the request is made, used for auditing.
+    pub fn user_client_ip(mut self, user_client_ip: &str) -> Self {
+        self.user_client_ip = Some(user_client_ip.to_string());
+        self
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        if let Some(page_number) = self.page_number {
+            params.insert("PageNumber", page_number.to_string());
+        }
+        if let Some(page_size) = self.page_size {
+            params.insert("PageSize", page_size.to_string());
+        }
+        if let Some(key_word) = &self.key_word {
+            params.insert("KeyWord", key_word.clone());
+        }
+        if let Some(rr_key_word) = &self.rr_key_word {
+            params.insert("RRKeyWord", rr_key_word.clone());
+        }
+        if let Some(type_key_word) = &self.type_key_word {
+            params.insert("TypeKeyWord", type_key_word.clone());
+        }
+        if let Some(value_key_word) = &self.value_key_word {
+            params.insert("ValueKeyWord", value_key_word.clone());
+        }
+        if let Some(search_mode) = self.search_mode {
+            params.insert("SearchMode", search_mode.as_str().to_string());
+        }
+        if let Some(record_type) = &self.record_type {
+            params.insert("Type", record_type.clone());
+        }
+        if let Some(line) = &self.line {
+            params.insert("Line", line.as_str().to_string());
+        }
+        if let Some(status) = self.status {
+            params.insert("Status", status.as_str().to_string());
+        }
+        if let Some(group_id) = &self.group_id {
+            params.insert("GroupId", group_id.clone());
+        }
+        if let Some(order_by) = &self.order_by {
+            params.insert("OrderBy", order_by.clone());
+        }
+        if let Some(direction) = self.direction {
+            params.insert("Direction", direction.as_str().to_string());
+        }
+        if let Some(user_client_ip) = &self.user_client_ip {
+            params.insert("UserClientIp", user_client_ip.clone());
+        }
+    }
+}
+
+/// A struct representing a response that only carries the request ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BasicResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for enabling or disabling a domain record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetDomainRecordStatusResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "RecordId")]
+    pub record_id: String,
+    #[serde(rename = "Status")]
+    pub status: RecordStatus,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Optional settings for adding or updating a domain record.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::RecordOptions;
+///
+/// let options = RecordOptions::new().ttl(600).priority(10).line("telecom");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RecordOptions {
+    ttl: Option<u32>,
+    priority: Option<u32>,
+    line: Option<Line>,
+    lang: Option<Lang>,
+    user_client_ip: Option<String>,
+}
+
+impl RecordOptions {
+    /// Creates an empty set of options, which leaves every setting at the API default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the TTL of the record, in seconds, between `Ttl::MIN` and `Ttl::MAX`.
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Sets the priority of an MX record (1 to 50).
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Sets the resolution line of the record (e.g., `Line::Default`, `Line::Telecom` or a line code).
+    pub fn line(mut self, line: impl Into<Line>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    /// Sets the language of the response messages, taking precedence over the language of the client.
+    pub fn lang(mut self, lang: Lang) -> Self {
+        self.lang = Some(lang);
+        self
+    }
+
+    /// Sets the IP address of the end user on whose behalf the request is made, used for auditing.
+    pub fn user_client_ip(mut self, user_client_ip: &str) -> Self {
+        self.user_client_ip = Some(user_client_ip.to_string());
+        self
+    }
+
+    /// Rejects the settings the API would reject, such as a TTL out of range.
+    fn validate(&self) -> Result<()> {
+        if let Some(ttl) = self.ttl {
+            Ttl::new(ttl)?;
+        }
+        Ok(())
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        if let Some(ttl) = self.ttl {
+            params.insert("TTL", ttl.to_string());
+        }
+        if let Some(priority) = self.priority {
+            params.insert("Priority", priority.to_string());
+        }
+        if let Some(line) = &self.line {
+            params.insert("Line", line.as_str().to_string());
+        }
+        if let Some(lang) = self.lang {
+            params.insert("Lang", lang.as_str().to_string());
+        }
+        if let Some(user_client_ip) = &self.user_client_ip {
+            params.insert("UserClientIp", user_client_ip.clone());
+        }
+    }
+}
+
+/// A struct representing a single entry of the record operation logs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecordLog {
+    #[serde(rename = "Action")]
+    pub action: String,
+    #[serde(rename = "ActionTime")]
+    pub action_time: String,
+    #[serde(rename = "ActionTimestamp", deserialize_with = "crate::de::number")]
+    pub action_timestamp: i64,
+    #[serde(rename = "Message")]
+    pub message: String,
+    #[serde(rename = "ClientIp", default)]
+    pub client_ip: Option<String>,
+}
+
+/// A struct containing the record operation logs returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecordLogs {
+    #[serde(rename = "RecordLog")]
+    pub record_logs: Vec<RecordLog>,
+}
+
+/// A struct representing the response for querying record operation logs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordLogsResponse {
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
+    pub total_count: u32,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "RecordLogs")]
+    pub record_logs: RecordLogs,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Options for querying record operation logs, used to page through results and filter them by time range.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::QueryRecordLogsOptions;
+///
+/// let options = QueryRecordLogsOptions::new()
+///     .start_date("2023-01-01")
+///     .end_date("2023-01-31")
+///     .page_size(100);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QueryRecordLogsOptions {
+    page_number: Option<u32>,
+    page_size: Option<u32>,
+    key_word: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    user_client_ip: Option<String>,
+}
+
+impl QueryRecordLogsOptions {
+    /// Creates an empty set of options, which matches every log entry of the domain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the page number to fetch, starting from 1.
+    pub fn page_number(mut self, page_number: u32) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the number of log entries per page (1 to 100).
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets a keyword matched against the log entries.
+    pub fn key_word(mut self, key_word: &str) -> Self {
+        self.key_word = Some(key_word.to_string());
+        self
+    }
+
+    /// Sets the first day of the time range, in the `YYYY-MM-DD` format.
+    pub fn start_date(mut self, start_date: &str) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+
+    /// Sets the last day of the time range, in the `YYYY-MM-DD` format.
+    pub fn end_date(mut self, end_date: &str) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    /// Sets the IP address of the end user on whose behalf the request is made, used for auditing.
+    pub fn user_client_ip(mut self, user_client_ip: &str) -> Self {
+        self.user_client_ip = Some(user_client_ip.to_string());
+        self
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        if let Some(page_number) = self.page_number {
+            params.insert("PageNumber", page_number.to_string());
+        }
+        if let Some(page_size) = self.page_size {
+            params.insert("PageSize", page_size.to_string());
+        }
+        if let Some(key_word) = &self.key_word {
+            params.insert("KeyWord", key_word.clone());
+        }
+        if let Some(start_date) = &self.start_date {
+            params.insert("StartDate", start_date.clone());
+        }
+        if let Some(end_date) = &self.end_date {
+            // The API spells this parameter with a lowercase initial.
+            params.insert("endDate", end_date.clone());
+        }
+        if let Some(user_client_ip) = &self.user_client_ip {
+            params.insert("UserClientIp", user_client_ip.clone());
+        }
+    }
+}
+
+/// The purpose of a TXT verification record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxtVerifyType {
+    /// Verifies ownership when adding a subdomain as a separate domain.
+    AddSubDomain,
+    /// Verifies ownership when retrieving a domain added by another account.
+    Retrieval,
+}
+
+impl TxtVerifyType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TxtVerifyType::AddSubDomain => "ADD_SUB_DOMAIN",
+            TxtVerifyType::Retrieval => "RETRIEVAL",
+        }
+    }
+}
+
+/// A struct representing the response for generating a TXT verification record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxtRecordForVerifyResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(rename = "RR")]
+    pub rr: String,
+    #[serde(rename = "Value")]
+    pub value: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+impl_response_meta!(
+    DomainRecordsResponse,
+    DomainRecordInfoResponse,
+    DeleteSubDomainRecordsResponse,
+    RecordResponse,
+    BasicResponse,
+    SetDomainRecordStatusResponse,
+    RecordLogsResponse,
+    TxtRecordForVerifyResponse,
+);
+
+impl_timestamps! {
+    DomainRecord { created_at => create_timestamp, updated_at => update_timestamp }
+    RecordLog { action_at => action_timestamp }
+}
+
+/// The language of the messages returned by the API, errors included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    /// English.
+    En,
+    /// Simplified Chinese.
+    Zh,
+}
+
+impl Lang {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Zh => "zh",
+        }
+    }
+}
+
+/// The HTTP method of the API requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequestMethod {
+    /// Parameters and signature in the query string of a GET request.
+    Get,
+    /// Parameters and signature in the `application/x-www-form-urlencoded` body of a POST request, kept out of the URLs logged by proxies and servers.
+    #[default]
+    Post,
+}
+
+impl RequestMethod {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RequestMethod::Get => "GET",
+            RequestMethod::Post => "POST",
+        }
+    }
 }
 
 /// A struct representing the AliyunDns API client.
+#[derive(Clone)]
 pub struct AliyunDns {
-    access_key_id: String,
-    access_key_secret: String,
+    credentials: Arc<dyn CredentialsProvider>,
     client: Client,
+    lang: Option<Lang>,
+    endpoint: Url,
+    retry_policy: RetryPolicy,
+    request_method: RequestMethod,
+    timeout: Option<Duration>,
+    user_agent: String,
+    dry_run: Option<DryRunLog>,
+    concurrency: usize,
 }
 
 // Implement methods for AliyunDns struct
@@ -229,37 +1186,223 @@ impl AliyunDns {
     /// ```
     /// use aliyun_dns::AliyunDns;
     ///
-    /// let aliyun_dns = AliyunDns::new("your_access_key_id", "your_access_key_secret");
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
     /// ```
     pub fn new(access_key_id: String, access_key_secret: String) -> Self {
-        let client = Client::new();
-        AliyunDns {
-            access_key_id,
-            access_key_secret,
-            client,
-        }
+        Self::with_client(access_key_id, access_key_secret, Client::new())
     }
 
-    /// Adds a new domain record.
+    /// Creates a new `AliyunDns` client that sends its requests through an existing HTTP client.
     ///
     /// # Arguments
     ///
-    /// * `domain_name` - The domain name for which the record should be added.
-    /// * `sub_domain` - The subdomain of the domain.
-    /// * `record_type` - The type of the record (e.g., "A", "CNAME", "MX", etc.).
-    /// * `record_value` - The value of the record (e.g., an IP address or a hostname).
+    /// * `access_key_id` - The access key ID for the Aliyun API.
+    /// * `access_key_secret` - The access key secret for the Aliyun API.
+    /// * `client` - The HTTP client to share, along with its connection pool, proxy and TLS settings.
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// A `Result` containing a `RecordResponse` if the operation is successful, or an error if the operation fails.
+    /// ```
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// let client = reqwest::Client::new();
+    /// let aliyun_dns = AliyunDns::with_client("your_access_key_id".to_string(), "your_access_key_secret".to_string(), client.clone());
+    /// ```
+    pub fn with_client(access_key_id: String, access_key_secret: String, client: Client) -> Self {
+        AliyunDns {
+            credentials: Arc::new(Credentials::new(&access_key_id, &access_key_secret)),
+            client,
+            lang: None,
+            endpoint: endpoint::parse(endpoint::PUBLIC).unwrap(),
+            retry_policy: RetryPolicy::default(),
+            request_method: RequestMethod::default(),
+            timeout: None,
+            user_agent: USER_AGENT.to_string(),
+            dry_run: None,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Creates a new `AliyunDns` client from credentials, such as temporary STS credentials with a security token.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - The credentials used to sign the requests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aliyun_dns::{AliyunDns, Credentials};
+    ///
+    /// let credentials = Credentials::new("your_access_key_id", "your_access_key_secret")
+    ///     .security_token("your_security_token");
+    /// let aliyun_dns = AliyunDns::from_credentials(credentials);
+    /// ```
+    pub fn from_credentials(credentials: Credentials) -> Self {
+        Self::from_provider(credentials)
+    }
+
+    /// Creates a new `AliyunDns` client resolving its credentials from a provider before each request.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider of the credentials used to sign the requests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aliyun_dns::{AliyunDns, EnvironmentProvider};
+    ///
+    /// let aliyun_dns = AliyunDns::from_provider(EnvironmentProvider::new());
+    /// ```
+    pub fn from_provider(provider: impl CredentialsProvider + 'static) -> Self {
+        AliyunDns {
+            credentials: Arc::new(provider),
+            ..Self::new(String::new(), String::new())
+        }
+    }
+
+    /// Creates a new `AliyunDns` client resolving its credentials from the default chain of providers: environment variables, the Aliyun CLI profile file, OIDC tokens (RRSA), and the RAM role of the ECS instance.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// let aliyun_dns = AliyunDns::from_default_providers();
+    /// ```
+    pub fn from_default_providers() -> Self {
+        Self::from_provider(DefaultProviderChain::new())
+    }
+
+    /// Creates a builder for a client with settings beyond the access key, such as the endpoint or a timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// let aliyun_dns = AliyunDns::builder()
+    ///     .credentials("your_access_key_id", "your_access_key_secret")
+    ///     .user_agent("my-ddns-agent/1.0")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> AliyunDnsBuilder {
+        AliyunDnsBuilder::new()
+    }
+
+    /// Returns a copy of the client that asks for the API messages in the given language.
+    ///
+    /// The copy shares the underlying HTTP connection pool, so it is cheap enough to create for a single request.
+    /// A language set in the options of a request takes precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the API messages.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, Lang, RecordResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string())
+    ///     .with_lang(Lang::En);
+    /// let result: Result<RecordResponse, _> = aliyun_dns.with_lang(Lang::Zh).delete_domain_record("record_id").await;
+    /// # }
+    /// ```
+    pub fn with_lang(&self, lang: Lang) -> Self {
+        AliyunDns {
+            lang: Some(lang),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of the client whose requests time out after the given duration, overriding the timeout of the builder.
+    ///
+    /// The copy shares the underlying HTTP connection pool, so it is cheap enough to create for a single request.
+    /// Each attempt of a retried request gets the full timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The timeout of a whole request, from connecting until the response body is read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordResponse, RecordSpec};
+    /// use std::time::Duration;
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<RecordResponse, _> = aliyun_dns
+    ///     .with_timeout(Duration::from_secs(3))
+    ///     .update_record("record_id", &RecordSpec::a("www", "192.0.2.1"))
+    ///     .await;
+    /// # }
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        AliyunDns {
+            timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of the client whose bulk helpers, such as `add_domain_records`, send at most the given number of requests at once.
+    ///
+    /// The copy shares the underlying HTTP connection pool, so it is cheap enough to create for a single call.
+    /// A concurrency of 0 is treated as 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `concurrency` - The largest number of requests in flight at once. Defaults to 8.
     ///
     /// # Examples
     ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let ids = vec!["record_id_1".to_string(), "record_id_2".to_string()];
+    /// let results = aliyun_dns.with_concurrency(2).delete_domain_records(ids).await;
+    /// # }
     /// ```
+    pub fn with_concurrency(&self, concurrency: usize) -> Self {
+        AliyunDns {
+            concurrency: concurrency.max(1),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the largest number of requests the bulk helpers send at once.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Adds a new domain record.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the record should be added.
+    /// * `sub_domain` - The subdomain of the domain.
+    /// * `record_type` - The type of the record (e.g., "A", "CNAME", "MX", etc.).
+    /// * `record_value` - The value of the record (e.g., an IP address or a hostname).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `RecordResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
     /// use aliyun_dns::{AliyunDns, RecordResponse};
     ///
-    /// let aliyun_dns = AliyunDns::new("your_access_key_id", "your_access_key_secret");
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
     /// let result: Result<RecordResponse, _> = aliyun_dns.add_domain_record("example.com", "www", "A", "192.0.2.1").await;
+    /// # }
     /// ```
     pub async fn add_domain_record(
         &self,
@@ -268,16 +1411,63 @@ impl AliyunDns {
         record_type: &str,
         record_value: &str
     ) -> Result<RecordResponse> {
+        validate_record(sub_domain, record_type, record_value)?;
         let action = "AddDomainRecord";
         let mut params = HashMap::new();
-        params.insert("DomainName", domain_name);
-        params.insert("RR", sub_domain);
-        params.insert("Type", record_type);
-        params.insert("Value", record_value);
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("RR", sub_domain.to_string());
+        params.insert("Type", record_type.to_string());
+        params.insert("Value", record_value.to_string());
         
         self.send_request(action, params).await
     }
 
+    /// Adds a new domain record with additional settings such as TTL, priority and line.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the record should be added.
+    /// * `sub_domain` - The subdomain of the domain.
+    /// * `record_type` - The type of the record (e.g., "A", "CNAME", "MX", etc.).
+    /// * `record_value` - The value of the record (e.g., an IP address or a hostname).
+    /// * `options` - The optional settings of the record.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `RecordResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordOptions, RecordResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = RecordOptions::new().ttl(600).priority(10);
+    /// let result: Result<RecordResponse, _> = aliyun_dns.add_domain_record_with("example.com", "@", "MX", "mail.example.com", &options).await;
+    /// # }
+    /// ```
+    pub async fn add_domain_record_with(
+        &self,
+        domain_name: &str,
+        sub_domain: &str,
+        record_type: &str,
+        record_value: &str,
+        options: &RecordOptions,
+    ) -> Result<RecordResponse> {
+        validate_record(sub_domain, record_type, record_value)?;
+        options.validate()?;
+        let action = "AddDomainRecord";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("RR", sub_domain.to_string());
+        params.insert("Type", record_type.to_string());
+        params.insert("Value", record_value.to_string());
+        options.append_params(&mut params);
+
+        self.send_request(action, params).await
+    }
+
     /// Deletes all subdomain records.
     ///
     /// # Arguments
@@ -291,21 +1481,57 @@ impl AliyunDns {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use aliyun_dns::{AliyunDns, DeleteSubDomainRecordsResponse};
     ///
-    /// let aliyun_dns = AliyunDns::new("your_access_key_id", "your_access_key_secret");
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
     /// let result: Result<DeleteSubDomainRecordsResponse, _> = aliyun_dns.delete_subdomain_records("example.com", "www").await;
+    /// # }
     /// ```
     pub async fn delete_subdomain_records(
         &self,
         domain_name: &str,
         rr: &str,
+    ) -> Result<DeleteSubDomainRecordsResponse> {
+        self.delete_subdomain_records_with_type(domain_name, rr, None).await
+    }
+
+    /// Deletes the subdomain records of a specific type, or of all types.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the subdomain records should be deleted.
+    /// * `rr` - The subdomain prefix (e.g., "www" for "www.example.com").
+    /// * `record_type` - The type of the records to delete (e.g., "TXT"), or `None` for all types.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DeleteSubDomainRecordsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DeleteSubDomainRecordsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DeleteSubDomainRecordsResponse, _> = aliyun_dns.delete_subdomain_records_with_type("example.com", "_acme-challenge", Some("TXT")).await;
+    /// # }
+    /// ```
+    pub async fn delete_subdomain_records_with_type(
+        &self,
+        domain_name: &str,
+        rr: &str,
+        record_type: Option<&str>,
     ) -> Result<DeleteSubDomainRecordsResponse> {
         let action = "DeleteSubDomainRecords";
         let mut params = HashMap::new();
-        params.insert("DomainName", domain_name);
-        params.insert("RR", rr);
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("RR", rr.to_string());
+        if let Some(record_type) = record_type {
+            params.insert("Type", record_type.to_string());
+        }
         
         self.send_request(action, params).await
     }
@@ -322,11 +1548,13 @@ impl AliyunDns {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use aliyun_dns::{AliyunDns, RecordResponse};
     ///
-    /// let aliyun_dns = AliyunDns::new("your_access_key_id", "your_access_key_secret");
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
     /// let result: Result<RecordResponse, _> = aliyun_dns.delete_domain_record("record_id").await;
+    /// # }
     /// ```
     pub async fn delete_domain_record(
         &self,
@@ -334,7 +1562,7 @@ impl AliyunDns {
     ) -> Result<RecordResponse> {
         let action = "DeleteDomainRecord";
         let mut params = HashMap::new();
-        params.insert("RecordId", record_id);
+        params.insert("RecordId", record_id.to_string());
         
         self.send_request(action, params).await
     }
@@ -354,11 +1582,13 @@ impl AliyunDns {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use aliyun_dns::{AliyunDns, RecordResponse};
     ///
-    /// let aliyun_dns = AliyunDns::new("your_access_key_id", "your_access_key_secret");
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
     /// let result: Result<RecordResponse, _> = aliyun_dns.update_domain_record("record_id", "www", "A", "192.0.2.1").await;
+    /// # }
     /// ```
     pub async fn update_domain_record(
         &self,
@@ -367,16 +1597,156 @@ impl AliyunDns {
         record_type: &str,
         value: &str,
     ) -> Result<RecordResponse> {
+        validate_record(sub_domain, record_type, value)?;
         let action = "UpdateDomainRecord";
         let mut params = HashMap::new();
-        params.insert("RecordId", record_id);
-        params.insert("RR", sub_domain);
-        params.insert("Type", record_type);
-        params.insert("Value", value);
+        params.insert("RecordId", record_id.to_string());
+        params.insert("RR", sub_domain.to_string());
+        params.insert("Type", record_type.to_string());
+        params.insert("Value", value.to_string());
         
         self.send_request(action, params).await
     }
 
+    /// Updates a domain record with new values and additional settings such as TTL, priority and line.
+    ///
+    /// # Arguments
+    ///
+    /// * `record_id` - The ID of the domain record to be updated.
+    /// * `sub_domain` - The updated subdomain of the domain.
+    /// * `record_type` - The updated type of the record (e.g., "A", "CNAME", "MX", etc.).
+    /// * `value` - The updated value of the record (e.g., an IP address or a hostname).
+    /// * `options` - The updated optional settings of the record.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `RecordResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordOptions, RecordResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = RecordOptions::new().ttl(60);
+    /// let result: Result<RecordResponse, _> = aliyun_dns.update_domain_record_with("record_id", "www", "A", "192.0.2.1", &options).await;
+    /// # }
+    /// ```
+    pub async fn update_domain_record_with(
+        &self,
+        record_id: &str,
+        sub_domain: &str,
+        record_type: &str,
+        value: &str,
+        options: &RecordOptions,
+    ) -> Result<RecordResponse> {
+        validate_record(sub_domain, record_type, value)?;
+        options.validate()?;
+        let action = "UpdateDomainRecord";
+        let mut params = HashMap::new();
+        params.insert("RecordId", record_id.to_string());
+        params.insert("RR", sub_domain.to_string());
+        params.insert("Type", record_type.to_string());
+        params.insert("Value", value.to_string());
+        options.append_params(&mut params);
+
+        self.send_request(action, params).await
+    }
+
+    /// Enables or disables a specific domain record by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `record_id` - The ID of the domain record to be enabled or disabled.
+    /// * `status` - The new status of the record, either a `RecordStatus` or `true` to enable the record and `false` to disable it.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `SetDomainRecordStatusResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordStatus, SetDomainRecordStatusResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<SetDomainRecordStatusResponse, _> = aliyun_dns.set_domain_record_status("record_id", RecordStatus::Disable).await;
+    /// # }
+    /// ```
+    pub async fn set_domain_record_status(
+        &self,
+        record_id: &str,
+        status: impl Into<RecordStatus>,
+    ) -> Result<SetDomainRecordStatusResponse> {
+        let action = "SetDomainRecordStatus";
+        let mut params = HashMap::new();
+        params.insert("RecordId", record_id.to_string());
+        params.insert("Status", status.into().as_str().to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Sets the remark of a specific domain record by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `record_id` - The ID of the domain record to be updated.
+    /// * `remark` - The new remark of the record, or an empty string to clear it.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BasicResponse, _> = aliyun_dns.update_domain_record_remark("record_id", "billing-service").await;
+    /// # }
+    /// ```
+    pub async fn update_domain_record_remark(
+        &self,
+        record_id: &str,
+        remark: &str,
+    ) -> Result<BasicResponse> {
+        let action = "UpdateDomainRecordRemark";
+        let mut params = HashMap::new();
+        params.insert("RecordId", record_id.to_string());
+        params.insert("Remark", remark.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries the details of a specific domain record by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `record_id` - The ID of the domain record to be queried.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainRecordInfoResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainRecordInfoResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainRecordInfoResponse, _> = aliyun_dns.get_domain_record("record_id").await;
+    /// # }
+    /// ```
+    pub async fn get_domain_record(&self, record_id: &str) -> Result<DomainRecordInfoResponse> {
+        let action = "DescribeDomainRecordInfo";
+        let mut params = HashMap::new();
+        params.insert("RecordId", record_id.to_string());
+        self.send_request(action, params).await
+    }
+
     /// Queries the domain records for a specific domain name.
     ///
     /// # Arguments
@@ -389,16 +1759,340 @@ impl AliyunDns {
     ///
     /// # Examples
     ///
-    /// ```
-    /// use my_crate::{AliyunDns, DomainRecordsResponse};
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainRecordsResponse};
     ///
-    /// let aliyun_dns = AliyunDns::new("your_access_key_id", "your_access_key_secret");
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
     /// let result: Result<DomainRecordsResponse, _> = aliyun_dns.query_domain_records("example.com").await;
+    /// # }
     /// ```
     pub async fn query_domain_records(&self, domain_name: &str) -> Result<DomainRecordsResponse> {
         let action = "DescribeDomainRecords";
         let mut params = HashMap::new();
-        params.insert("DomainName", domain_name);
+        params.insert("DomainName", domain_name.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of domain records for a specific domain name.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the records should be queried.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of records per page (1 to 500).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainRecordsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainRecordsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainRecordsResponse, _> = aliyun_dns.query_domain_records_paged("example.com", 2, 100).await;
+    /// # }
+    /// ```
+    pub async fn query_domain_records_paged(
+        &self,
+        domain_name: &str,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<DomainRecordsResponse> {
+        let options = QueryDomainRecordsOptions::new()
+            .page_number(page_number)
+            .page_size(page_size);
+        self.query_domain_records_with(domain_name, &options).await
+    }
+
+    /// Queries the domain records for a specific domain name, using the given paging and filter options.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the records should be queried.
+    /// * `options` - The paging and filter options applied to the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainRecordsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainRecordsResponse, QueryDomainRecordsOptions, SearchMode};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = QueryDomainRecordsOptions::new()
+    ///     .rr_key_word("_acme-challenge")
+    ///     .type_key_word("TXT")
+    ///     .search_mode(SearchMode::Advanced);
+    /// let result: Result<DomainRecordsResponse, _> = aliyun_dns.query_domain_records_with("example.com", &options).await;
+    /// # }
+    /// ```
+    pub async fn query_domain_records_with(
+        &self,
+        domain_name: &str,
+        options: &QueryDomainRecordsOptions,
+    ) -> Result<DomainRecordsResponse> {
+        let action = "DescribeDomainRecords";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+
+    /// Queries the records of a specific subdomain.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_domain` - The full subdomain name (e.g., "www.example.com").
+    /// * `record_type` - The type of the records to return, or `None` for all types.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainRecordsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainRecordsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainRecordsResponse, _> = aliyun_dns.query_subdomain_records("home.example.com", Some("A")).await;
+    /// # }
+    /// ```
+    pub async fn query_subdomain_records(
+        &self,
+        sub_domain: &str,
+        record_type: Option<&str>,
+    ) -> Result<DomainRecordsResponse> {
+        let action = "DescribeSubDomainRecords";
+        let mut params = HashMap::new();
+        params.insert("SubDomain", sub_domain.to_string());
+        if let Some(record_type) = record_type {
+            params.insert("Type", record_type.to_string());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of the records of a specific subdomain.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_domain` - The full subdomain name (e.g., "www.example.com").
+    /// * `record_type` - The type of the records to return, or `None` for all types.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of records per page (1 to 500).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainRecordsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainRecordsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainRecordsResponse, _> = aliyun_dns.query_subdomain_records_paged("home.example.com", None, 1, 100).await;
+    /// # }
+    /// ```
+    pub async fn query_subdomain_records_paged(
+        &self,
+        sub_domain: &str,
+        record_type: Option<&str>,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<DomainRecordsResponse> {
+        let action = "DescribeSubDomainRecords";
+        let mut params = HashMap::new();
+        params.insert("SubDomain", sub_domain.to_string());
+        if let Some(record_type) = record_type {
+            params.insert("Type", record_type.to_string());
+        }
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries all domain records for a specific domain name, fetching every page.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the records should be queried.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing every `DomainRecord` of the domain if the operation is successful, or an error if any page request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainRecord};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<Vec<DomainRecord>, _> = aliyun_dns.query_all_domain_records("example.com").await;
+    /// # }
+    /// ```
+    pub async fn query_all_domain_records(&self, domain_name: &str) -> Result<Vec<DomainRecord>> {
+        self.query_all_domain_records_with(domain_name, &QueryDomainRecordsOptions::new())
+            .await
+    }
+
+    /// Queries all domain records matching the given filter options, fetching every page.
+    ///
+    /// Any paging set on `options` is ignored, since every page is fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the records should be queried.
+    /// * `options` - The filter options applied to the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing every matching `DomainRecord` if the operation is successful, or an error if any page request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainRecord, QueryDomainRecordsOptions};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = QueryDomainRecordsOptions::new().key_word("www");
+    /// let result: Result<Vec<DomainRecord>, _> = aliyun_dns.query_all_domain_records_with("example.com", &options).await;
+    /// # }
+    /// ```
+    pub async fn query_all_domain_records_with(
+        &self,
+        domain_name: &str,
+        options: &QueryDomainRecordsOptions,
+    ) -> Result<Vec<DomainRecord>> {
+        let mut records = Vec::new();
+        let mut page_number = 1;
+        loop {
+            let page_options = options
+                .clone()
+                .page_number(page_number)
+                .page_size(MAX_PAGE_SIZE);
+            let response = self
+                .query_domain_records_with(domain_name, &page_options)
+                .await?;
+            let page_len = response.domain_records.records.len();
+            records.extend(response.domain_records.records);
+            if page_len == 0 || records.len() >= response.total_count as usize {
+                return Ok(records);
+            }
+            page_number += 1;
+        }
+    }
+
+    /// Queries the operation logs of the records of a specific domain name.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the logs should be queried.
+    /// * `options` - The paging and time range options applied to the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `RecordLogsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, QueryRecordLogsOptions, RecordLogsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = QueryRecordLogsOptions::new().start_date("2023-01-01").end_date("2023-01-31");
+    /// let result: Result<RecordLogsResponse, _> = aliyun_dns.query_record_logs("example.com", &options).await;
+    /// # }
+    /// ```
+    pub async fn query_record_logs(
+        &self,
+        domain_name: &str,
+        options: &QueryRecordLogsOptions,
+    ) -> Result<RecordLogsResponse> {
+        let action = "DescribeRecordLogs";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+
+    /// Generates the TXT record needed to verify ownership of a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be verified.
+    /// * `verify_type` - The purpose of the verification.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `TxtRecordForVerifyResponse` with the RR and value of the TXT record to create if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, TxtRecordForVerifyResponse, TxtVerifyType};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<TxtRecordForVerifyResponse, _> = aliyun_dns.get_txt_record_for_verify("example.com", TxtVerifyType::Retrieval).await;
+    /// # }
+    /// ```
+    pub async fn get_txt_record_for_verify(
+        &self,
+        domain_name: &str,
+        verify_type: TxtVerifyType,
+    ) -> Result<TxtRecordForVerifyResponse> {
+        let action = "GetTxtRecordForVerify";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("Type", verify_type.as_str().to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Calls any Aliyun DNS action, including those without a dedicated method yet, with the same signing, retries and error handling.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - The name of the action (e.g., "DescribeDomainNs").
+    /// * `params` - The parameters of the action, without the common parameters such as `Action`, `Version` or the signature.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the response deserialized into `T`, which can be `serde_json::Value` or a struct of your own, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let params = [("DomainName".to_string(), "example.com".to_string())];
+    /// let result: Result<serde_json::Value, _> = aliyun_dns.call("DescribeDomainNs", params).await;
+    /// # }
+    /// ```
+    pub async fn call<T: DeserializeOwned>(
+        &self,
+        action: &str,
+        params: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<T> {
+        let params: Vec<(String, String)> = params.into_iter().collect();
+        let params = params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.clone()))
+            .collect();
         self.send_request(action, params).await
     }
 
@@ -417,68 +2111,136 @@ impl AliyunDns {
     async fn send_request<T: for<'de> Deserialize<'de>>(
         &self,
         action: &str,
-        mut params: HashMap<&str, &str>,
+        params: HashMap<&str, String>,
+    ) -> Result<T> {
+        self.send_versioned_request(API_VERSION, action, params).await
+    }
+
+    /// Sends an API request to a product with the specified API version, such as STS, retrying it as the retry policy allows.
+    ///
+    /// This function is used internally by the `aliyun_dns` crate and is not part of the public API.
+    pub(crate) async fn send_versioned_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        version: &str,
+        action: &str,
+        params: HashMap<&str, String>,
+    ) -> Result<T> {
+        #[cfg(feature = "idna")]
+        let params = {
+            let mut params = params;
+            idn::encode_params(&mut params)?;
+            params
+        };
+        if version == API_VERSION {
+            if let Some(result) = self.intercept_dry_run(action, &params) {
+                return result;
+            }
+        }
+        let mut attempt = 1;
+        loop {
+            match self.send_request_once(version, action, params.clone()).await {
+                Err(error) if self.retry_policy.should_retry(action, attempt, &error) => {
+                    tokio::time::sleep(self.retry_policy.delay(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Signs and sends a single attempt of an API request, with a fresh nonce and timestamp.
+    ///
+    /// This function is used internally by the `aliyun_dns` crate and is not part of the public API.
+    async fn send_request_once<T: for<'de> Deserialize<'de>>(
+        &self,
+        version: &str,
+        action: &str,
+        mut params: HashMap<&str, String>,
     ) -> Result<T> {
-        let url = "https://alidns.aliyuncs.com/";
         let nonce = format!("{}", rand::random::<u64>());
         let now = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
 
-        params.insert("AccessKeyId", &self.access_key_id);
-        params.insert("Action", action);
-        params.insert("Format", "JSON");
-        params.insert("Version", "2015-01-09");
-        params.insert("SignatureMethod", "HMAC-SHA1");
-        params.insert("SignatureVersion", "1.0");
-        params.insert("SignatureNonce", &nonce);
-        params.insert("Timestamp", &now);
+        let credentials = self.credentials.credentials().await?;
+        params.insert("AccessKeyId", credentials.access_key_id().to_string());
+        if let Some(security_token) = credentials.get_security_token() {
+            params.insert("SecurityToken", security_token.to_string());
+        }
+        params.insert("Action", action.to_string());
+        params.insert("Format", "JSON".to_string());
+        params.insert("Version", version.to_string());
+        params.insert("SignatureMethod", "HMAC-SHA1".to_string());
+        params.insert("SignatureVersion", "1.0".to_string());
+        params.insert("SignatureNonce", nonce);
+        params.insert("Timestamp", now);
+        if let Some(lang) = self.lang {
+            params.entry("Lang").or_insert_with(|| lang.as_str().to_string());
+        }
+
+        let signature = self.sign_request(credentials.access_key_secret(), &params)?;
+        params.insert("Signature", signature);
+        let query = canonical_query_string(&params);
+        let request = match self.request_method {
+            RequestMethod::Get => {
+                let mut url = self.endpoint.clone();
+                url.set_query(Some(&query));
+                self.client.get(url)
+            }
+            RequestMethod::Post => self
+                .client
+                .post(self.endpoint.clone())
+                .header(
+                    reqwest::header::CONTENT_TYPE,
+                    "application/x-www-form-urlencoded",
+                )
+                .body(query),
+        };
 
-        let signature = self.sign_request(&params);
-        let mut url = Url::parse(url).unwrap();
-        url.query_pairs_mut().extend_pairs(params.into_iter());
-        url.query_pairs_mut().append_pair("Signature", &signature);
+        let request = request.header(reqwest::header::USER_AGENT, &self.user_agent);
+        let request = match self.timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        };
 
-        let response = self.client.get(url).send().await?;
-        self.handle_response(response).await
+        let response = request.send().await?;
+        Self::handle_response(response).await
     }
 
     /// Signs the API request with the specified parameters.
     ///
     /// # Arguments
     ///
+    /// * `access_key_secret` - The secret of the access key the request is sent with.
     /// * `params` - A map containing the API parameters for the request.
     ///
     /// # Returns
     ///
-    /// A `String` containing the signed request.
+    /// A `Result` containing the signature of the request, or an error if the request cannot be signed.
     ///
     /// This function is used internally by the `aliyun_dns` crate and is not part of the public API.
-    fn sign_request(&self, params: &HashMap<&str, &str>) -> String {
-        let mut keys: Vec<&str> = params.keys().map(AsRef::as_ref).collect();
-        keys.sort();
-        let canonical_query_string = keys
-            .iter()
-            .map(|key| {
-                format!(
-                    "{}={}",
-                    percent_encode(key),
-                    percent_encode(params.get(key).unwrap())
-                )
-            })
-            .collect::<Vec<String>>()
-            .join("&");
-
-        let string_to_sign = format!(
-            "GET&{}&{}",
-            percent_encode("/"),
-            percent_encode(&canonical_query_string)
-        );
-        let signature_key = format!("{}&", self.access_key_secret);
-        let mut mac = Hmac::<Sha1>::new_from_slice(signature_key.as_bytes()).unwrap();
+    fn sign_request(
+        &self,
+        access_key_secret: &str,
+        params: &HashMap<&str, String>,
+    ) -> Result<String> {
+        let string_to_sign = self.string_to_sign(params);
+        let signature_key = format!("{}&", access_key_secret);
+        let mut mac = Hmac::<Sha1>::new_from_slice(signature_key.as_bytes())
+            .map_err(|error| AliyunDnsError::Signing(error.to_string()))?;
         mac.update(string_to_sign.as_bytes());
         let result = mac.finalize();
-        let signature = base64::engine::general_purpose::STANDARD.encode(result.into_bytes());
-    
-        signature
+        Ok(base64::engine::general_purpose::STANDARD.encode(result.into_bytes()))
+    }
+
+    /// Returns the string the signature of a request with the specified parameters is computed over.
+    ///
+    /// This function is used internally by the `aliyun_dns` crate and is not part of the public API.
+    fn string_to_sign(&self, params: &HashMap<&str, String>) -> String {
+        format!(
+            "{}&{}&{}",
+            self.request_method.as_str(),
+            percent_encode("/"),
+            percent_encode(&canonical_query_string(params))
+        )
     }
 
     /// Handles the API response and returns the deserialized result or an error.
@@ -492,44 +2254,65 @@ impl AliyunDns {
     /// A `Result` containing the deserialized response if the operation is successful, or an error if the operation fails.
     ///
     /// This function is used internally by the `aliyun_dns` crate and is not part of the public API.
-    async fn handle_response<T: for<'de> Deserialize<'de>>(
-        &self,
+    pub(crate) async fn handle_response<T: for<'de> Deserialize<'de>>(
         response: Response,
     ) -> Result<T> {
-        // let status = response.status();
-        // if !status.is_success() {
-        //     return Err(anyhow::anyhow!("Request failed with status: {}", status));
-        // }
-    
+        let status = response.status();
         let response_text = response.text().await?;
-        let response_data: ApiResponse<T> = serde_json::from_str(&response_text)
-            .context(format!("Failed to parse JSON response: {}", response_text))?;
-    
+        let response_data: ApiResponse<T> = match serde_json::from_str(&response_text) {
+            Ok(response_data) => response_data,
+            Err(_) if !status.is_success() => {
+                return Err(AliyunDnsError::Status {
+                    status: status.as_u16(),
+                    body: response_text,
+                })
+            }
+            Err(source) => {
+                return Err(AliyunDnsError::Decode {
+                    source,
+                    body: response_text,
+                })
+            }
+        };
+
         match response_data {
             ApiResponse::Success(result) => Ok(result),
             ApiResponse::Error {
                 request_id,
                 error_code,
                 error_message,
-            } => Err(anyhow::anyhow!(
-                "API error: Request ID: {}, Code: {}, Message: {}",
+            } => Err(AliyunDnsError::Api {
+                code: error_code,
+                message: error_message.unwrap_or_default(),
                 request_id,
-                error_code.unwrap_or_default(),
-                error_message.unwrap_or_default()
-            )),
+                status: status.as_u16(),
+            }),
         }
     }
 
 }
 
+/// Returns the parameters sorted by name and joined into a query string, as they are signed and sent.
+fn canonical_query_string(params: &HashMap<&str, String>) -> String {
+    let mut keys: Vec<&str> = params.keys().map(AsRef::as_ref).collect();
+    keys.sort();
+    keys.iter()
+        .map(|key| format!("{}={}", percent_encode(key), percent_encode(&params[key])))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Encodes the input as RFC 3986 requires, leaving only the unreserved characters unescaped.
+///
+/// Unlike form encoding, a space becomes `%20` and a `~` is kept as is, which the signature of the API expects.
 fn percent_encode(input: &str) -> String {
     let mut encoded = String::new();
     for byte in input.as_bytes() {
-        if *byte == b'*' {
-            encoded.push_str("%2A");
-        } else {
-            let temp = url::form_urlencoded::byte_serialize(&[*byte]).collect::<String>();
-            encoded.push_str(&temp);
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(*byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
         }
     }
     encoded
@@ -544,12 +2327,158 @@ mod tests {
         assert_eq!(percent_encode("hello"), "hello".to_string());
         assert_eq!(percent_encode("a/b"), "a%2Fb".to_string());
         assert_eq!(percent_encode("a+b"), "a%2Bb".to_string());
-        assert_eq!(percent_encode("a b"), "a+b".to_string());
+        assert_eq!(percent_encode("a b"), "a%20b".to_string());
         assert_eq!(percent_encode("*"), "%2A".to_string());
+        assert_eq!(percent_encode("~all"), "~all".to_string());
+        assert_eq!(percent_encode("a-b_c.d"), "a-b_c.d".to_string());
         assert_eq!(percent_encode("%"), "%25".to_string());
         assert_eq!(
             percent_encode("你好"),
             "%E4%BD%A0%E5%A5%BD".to_string()
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_sign_request() {
+        // The example request from the signature documentation of the API.
+        let aliyun_dns = AliyunDns::builder()
+            .credentials("testid", "testsecret")
+            .request_method(RequestMethod::Get)
+            .build()
+            .unwrap();
+        let params = HashMap::from([
+            ("Timestamp", "2016-02-23T12:46:24Z".to_string()),
+            ("Format", "XML".to_string()),
+            ("AccessKeyId", "testid".to_string()),
+            ("Action", "DescribeRegions".to_string()),
+            ("SignatureMethod", "HMAC-SHA1".to_string()),
+            (
+                "SignatureNonce",
+                "3ee8c1b8-83d3-44af-a94f-4e0ad82fd6cf".to_string(),
+            ),
+            ("Version", "2014-05-26".to_string()),
+            ("SignatureVersion", "1.0".to_string()),
+        ]);
+
+        assert_eq!(
+            aliyun_dns.string_to_sign(&params),
+            "GET&%2F&AccessKeyId%3Dtestid%26Action%3DDescribeRegions%26Format%3DXML\
+             %26SignatureMethod%3DHMAC-SHA1%26SignatureNonce%3D3ee8c1b8-83d3-44af-a94f-4e0ad82fd6cf\
+             %26SignatureVersion%3D1.0%26Timestamp%3D2016-02-23T12%253A46%253A24Z%26Version%3D2014-05-26"
+        );
+        assert_eq!(
+            aliyun_dns.sign_request("testsecret", &params).unwrap(),
+            "OLeaidS1JvxuMvnyHOwuJ+uX5qY="
+        );
+    }
+
+    #[test]
+    fn test_canonical_query_string() {
+        let params = HashMap::from([
+            ("Value", "v=spf1 ~all".to_string()),
+            ("RR", "@".to_string()),
+        ]);
+
+        assert_eq!(
+            canonical_query_string(&params),
+            "RR=%40&Value=v%3Dspf1%20~all"
+        );
+    }
+
+    #[test]
+    fn test_query_domain_records_options_params() {
+        let options = QueryDomainRecordsOptions::new()
+            .page_number(2)
+            .rr_key_word("_acme-challenge")
+            .search_mode(SearchMode::Advanced)
+            .user_client_ip("198.51.100.7");
+        let mut params = HashMap::new();
+        options.append_params(&mut params);
+
+        assert_eq!(params.len(), 4);
+        assert_eq!(params["PageNumber"], "2");
+        assert_eq!(params["RRKeyWord"], "_acme-challenge");
+        assert_eq!(params["SearchMode"], "ADVANCED");
+        assert_eq!(params["UserClientIp"], "198.51.100.7");
+    }
+
+    #[test]
+    fn test_deserialize_domain_record_info() {
+        let json = r#"{
+            "RequestId": "B3E5D4F6-0D1C-4C52-8E6B-3B0E6C2E5D31",
+            "DomainId": "00efd71a-770e-4255-b54e-6fe5659baffe",
+            "DomainName": "example.com",
+            "GroupId": "2223",
+            "GroupName": "group",
+            "RecordId": "9999985",
+            "RR": "www",
+            "Type": "MX",
+            "Value": "mail.example.com",
+            "TTL": 600,
+            "Priority": 10,
+            "Line": "default",
+            "Status": "ENABLE",
+            "Locked": false,
+            "Remark": "owned by mail team",
+            "NewField": "not modeled yet"
+        }"#;
+        let response: DomainRecordInfoResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.request_id, "B3E5D4F6-0D1C-4C52-8E6B-3B0E6C2E5D31");
+        assert_eq!(response.record.priority, Some(10));
+        assert_eq!(response.record.remark.as_deref(), Some("owned by mail team"));
+        assert_eq!(response.record.record_id, "9999985");
+        assert_eq!(response.record.record_type, "MX");
+        assert_eq!(response.record.ttl, 600);
+        assert_eq!(response.record.status, RecordStatus::Enable);
+        assert_eq!(response.extras.len(), 1);
+        assert_eq!(response.extras["NewField"], "not modeled yet");
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let json = r#"{
+            "RequestId": "B3E5D4F6-0D1C-4C52-8E6B-3B0E6C2E5D31",
+            "RecordId": "9999985"
+        }"#;
+        let response: RecordResponse = serde_json::from_str(json).unwrap();
+        let serialized = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(serialized["RecordId"], "9999985");
+        assert_eq!(serde_json::from_value::<RecordResponse>(serialized).unwrap(), response);
+    }
+
+    #[test]
+    fn test_deserialize_raw() {
+        let json = r#"{
+            "RequestId": "B3E5D4F6-0D1C-4C52-8E6B-3B0E6C2E5D31",
+            "RecordId": "9999985",
+            "NewField": "not modeled yet"
+        }"#;
+        let response: ApiResponse<Raw<RecordResponse>> = serde_json::from_str(json).unwrap();
+
+        match response {
+            ApiResponse::Success(response) => {
+                assert_eq!(response.record_id, "9999985");
+                assert_eq!(response.request_id(), "B3E5D4F6-0D1C-4C52-8E6B-3B0E6C2E5D31");
+                assert_eq!(response.json["NewField"], "not modeled yet");
+            }
+            ApiResponse::Error { .. } => panic!("success body parsed as an error"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_api_error() {
+        let json = r#"{
+            "RequestId": "9F5BCDB3-2B25-4D4B-BB31-2A5C6E1A8F0D",
+            "Code": "DomainRecordDuplicate",
+            "Message": "The DNS record already exists."
+        }"#;
+        let response: ApiResponse<BasicResponse> = serde_json::from_str(json).unwrap();
+
+        match response {
+            ApiResponse::Error { error_code, .. } => assert_eq!(error_code, "DomainRecordDuplicate"),
+            ApiResponse::Success(_) => panic!("error body parsed as a success"),
+        }
+    }
+}
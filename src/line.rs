@@ -0,0 +1,159 @@
+//! Resolution lines, which answer queries with different records depending on the network or region of the resolver.
+
+use std::fmt;
+
+/// A resolution line of a record.
+///
+/// Lines not listed here, such as the lines of other regions or custom lines, are given by their code with `Line::Other`,
+/// or converted from a string. The lines available to a domain are returned by `query_support_lines`.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{Line, RecordOptions};
+///
+/// let options = RecordOptions::new().line(Line::Telecom);
+/// let options = RecordOptions::new().line("cn_telecom_beijing");
+/// assert_eq!(Line::from("unicom"), Line::Unicom);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Line {
+    /// The default line, answering the queries no other line matches.
+    Default,
+    /// China Telecom.
+    Telecom,
+    /// China Unicom.
+    Unicom,
+    /// China Mobile.
+    Mobile,
+    /// China Education and Research Network.
+    Edu,
+    /// Resolvers outside mainland China.
+    Oversea,
+    /// Dr. Peng.
+    Drpeng,
+    /// China Broadcasting Network.
+    Btvn,
+    /// All search engines.
+    Search,
+    /// The Google crawler.
+    Google,
+    /// The Baidu crawler.
+    Baidu,
+    /// The Bing crawler.
+    Biying,
+    /// The Youdao crawler.
+    Youdao,
+    /// The Yahoo crawler.
+    Yahoo,
+    /// North China.
+    RegionHuabei,
+    /// Northeast China.
+    RegionDongbei,
+    /// East China.
+    RegionHuadong,
+    /// Central China.
+    RegionHuazhong,
+    /// South China.
+    RegionHuanan,
+    /// Northwest China.
+    RegionXibei,
+    /// Southwest China.
+    RegionXinan,
+    /// Any other line, by its code.
+    Other(String),
+}
+
+impl Line {
+    /// Returns the code of the line, as sent to the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Line::Default => "default",
+            Line::Telecom => "telecom",
+            Line::Unicom => "unicom",
+            Line::Mobile => "mobile",
+            Line::Edu => "edu",
+            Line::Oversea => "oversea",
+            Line::Drpeng => "drpeng",
+            Line::Btvn => "btvn",
+            Line::Search => "search",
+            Line::Google => "google",
+            Line::Baidu => "baidu",
+            Line::Biying => "biying",
+            Line::Youdao => "youdao",
+            Line::Yahoo => "yahoo",
+            Line::RegionHuabei => "cn_region_huabei",
+            Line::RegionDongbei => "cn_region_dongbei",
+            Line::RegionHuadong => "cn_region_huadong",
+            Line::RegionHuazhong => "cn_region_huazhong",
+            Line::RegionHuanan => "cn_region_huanan",
+            Line::RegionXibei => "cn_region_xibei",
+            Line::RegionXinan => "cn_region_xinan",
+            Line::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for Line {
+    fn from(code: &str) -> Self {
+        match code {
+            "default" => Line::Default,
+            "telecom" => Line::Telecom,
+            "unicom" => Line::Unicom,
+            "mobile" => Line::Mobile,
+            "edu" => Line::Edu,
+            "oversea" => Line::Oversea,
+            "drpeng" => Line::Drpeng,
+            "btvn" => Line::Btvn,
+            "search" => Line::Search,
+            "google" => Line::Google,
+            "baidu" => Line::Baidu,
+            "biying" => Line::Biying,
+            "youdao" => Line::Youdao,
+            "yahoo" => Line::Yahoo,
+            "cn_region_huabei" => Line::RegionHuabei,
+            "cn_region_dongbei" => Line::RegionDongbei,
+            "cn_region_huadong" => Line::RegionHuadong,
+            "cn_region_huazhong" => Line::RegionHuazhong,
+            "cn_region_huanan" => Line::RegionHuanan,
+            "cn_region_xibei" => Line::RegionXibei,
+            "cn_region_xinan" => Line::RegionXinan,
+            _ => Line::Other(code.to_string()),
+        }
+    }
+}
+
+impl From<String> for Line {
+    fn from(code: String) -> Self {
+        Line::from(code.as_str())
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_codes() {
+        for line in [
+            Line::Default,
+            Line::Oversea,
+            Line::Biying,
+            Line::RegionHuadong,
+        ] {
+            assert_eq!(Line::from(line.as_str()), line);
+        }
+        assert_eq!(
+            Line::from("cn_telecom_beijing"),
+            Line::Other("cn_telecom_beijing".to_string())
+        );
+        assert_eq!(Line::Other("1234".to_string()).as_str(), "1234");
+    }
+}
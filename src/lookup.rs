@@ -0,0 +1,177 @@
+//! Single-record lookups, which find the one record of a name and type.
+
+use crate::record_spec::fqdn;
+use crate::upsert::is_same_record;
+use crate::{AliyunDns, AliyunDnsError, DomainRecord, Line, Result, MAX_PAGE_SIZE};
+
+/// Optional settings for finding a record with `get_record_with`.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{GetRecordOptions, Line};
+///
+/// let options = GetRecordOptions::new().line(Line::Telecom).allow_multiple(true);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GetRecordOptions {
+    line: Option<Line>,
+    allow_multiple: bool,
+}
+
+impl GetRecordOptions {
+    /// Creates an empty set of options, which matches every line and fails if several records match.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the lookup to the records on a resolution line (e.g., `Line::Default`, `Line::Telecom` or a line code).
+    pub fn line(mut self, line: impl Into<Line>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    /// Sets whether the first record is returned when several records match, rather than an error.
+    pub fn allow_multiple(mut self, allow_multiple: bool) -> Self {
+        self.allow_multiple = allow_multiple;
+        self
+    }
+}
+
+/// Picks the record of a name and type among records, or fails if several match and this is not allowed.
+fn pick_record(
+    records: Vec<DomainRecord>,
+    rr: &str,
+    record_type: &str,
+    options: &GetRecordOptions,
+) -> Result<Option<DomainRecord>> {
+    let mut records: Vec<DomainRecord> = records
+        .into_iter()
+        .filter(|record| is_same_record(record, rr, record_type, None))
+        .filter(|record| {
+            options
+                .line
+                .as_ref()
+                .is_none_or(|line| record.line == line.as_str())
+        })
+        .collect();
+    if records.len() > 1 && !options.allow_multiple {
+        return Err(AliyunDnsError::InvalidInput(format!(
+            "{} {} records exist for {}, expected at most one",
+            records.len(),
+            record_type,
+            rr
+        )));
+    }
+    Ok((!records.is_empty()).then(|| records.remove(0)))
+}
+
+impl AliyunDns {
+    /// Finds the record of a host record and type, such as the A record of "www".
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the record belongs to.
+    /// * `rr` - The host record (e.g., "www", or "@" for the domain itself).
+    /// * `record_type` - The type of the record (e.g., "A", "CNAME", "TXT", etc.).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the record, or `None` if there is none, or an error if several records match or the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// if let Some(record) = aliyun_dns.get_record("example.com", "www", "A").await? {
+    ///     println!("www points to {}", record.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_record(
+        &self,
+        domain_name: &str,
+        rr: &str,
+        record_type: &str,
+    ) -> Result<Option<DomainRecord>> {
+        self.get_record_with(domain_name, rr, record_type, &GetRecordOptions::new())
+            .await
+    }
+
+    /// Finds the record of a host record and type, on a line or among several records.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the record belongs to.
+    /// * `rr` - The host record (e.g., "www", or "@" for the domain itself).
+    /// * `record_type` - The type of the record (e.g., "A", "CNAME", "TXT", etc.).
+    /// * `options` - The line of the record, and whether several matching records are allowed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the record, or `None` if there is none, or an error if several records match without `allow_multiple`, or the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainRecord, GetRecordOptions, Line};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = GetRecordOptions::new().line(Line::Telecom);
+    /// let result: Result<Option<DomainRecord>, _> = aliyun_dns.get_record_with("example.com", "www", "A", &options).await;
+    /// # }
+    /// ```
+    pub async fn get_record_with(
+        &self,
+        domain_name: &str,
+        rr: &str,
+        record_type: &str,
+        options: &GetRecordOptions,
+    ) -> Result<Option<DomainRecord>> {
+        let response = self
+            .query_subdomain_records_paged(
+                &fqdn(rr, domain_name),
+                Some(record_type),
+                1,
+                MAX_PAGE_SIZE,
+            )
+            .await?;
+        pick_record(response.domain_records.records, rr, record_type, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::record;
+
+    #[test]
+    fn test_pick_record() {
+        let records = || {
+            vec![
+                record("www", "A", "192.0.2.1").line("default").build(),
+                record("www", "A", "192.0.2.1").line("telecom").build(),
+                record("api", "A", "192.0.2.1").line("default").build(),
+            ]
+        };
+        let options = GetRecordOptions::new();
+        assert!(pick_record(records(), "www", "A", &options).is_err());
+        assert!(pick_record(records(), "ftp", "A", &options)
+            .unwrap()
+            .is_none());
+        let record = pick_record(records(), "api", "a", &options).unwrap();
+        assert_eq!(record.unwrap().rr, "api");
+
+        let options = GetRecordOptions::new().line(Line::Telecom);
+        let record = pick_record(records(), "www", "A", &options).unwrap();
+        assert_eq!(record.unwrap().line, "telecom");
+        let options = GetRecordOptions::new().allow_multiple(true);
+        let record = pick_record(records(), "www", "A", &options).unwrap();
+        assert_eq!(record.unwrap().line, "default");
+    }
+}
@@ -0,0 +1,581 @@
+//! Record presets for common mail setups, with builders for SPF, DKIM and DMARC TXT values.
+//!
+//! A `MailPreset` generates the MX, SPF and, optionally, DKIM and DMARC records of a mail
+//! provider. `AliyunDns::apply_mail_preset` brings the domain to these records, replacing the MX
+//! records and the SPF, DKIM and DMARC values already set, and leaving the other records alone.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use aliyun_dns::AliyunDns;
+//! use aliyun_dns::presets::{Dmarc, DmarcPolicy, MailPreset, MailProvider};
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+//! let preset = MailPreset::new(MailProvider::GoogleWorkspace)
+//!     .dkim("google", "MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA...")
+//!     .dmarc(Dmarc::new(DmarcPolicy::Quarantine).rua("dmarc@example.com"));
+//! for result in aliyun_dns.apply_mail_preset("example.com", &preset).await? {
+//!     println!("{}: {:?}", result.change, result.result);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::reconcile::{diff, ChangeResult, Plan};
+use crate::{join_txt, AliyunDns, AliyunDnsError, DomainRecord, RecordSpec, Result};
+
+/// The largest number of DNS lookups an SPF record may cause, as set by RFC 7208.
+const MAX_SPF_LOOKUPS: usize = 10;
+
+/// What an SPF record says about the senders it does not list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SpfAll {
+    /// `+all`: every sender passes.
+    Pass,
+    /// `?all`: nothing is said about the other senders.
+    Neutral,
+    /// `~all`: the other senders are probably not allowed.
+    #[default]
+    SoftFail,
+    /// `-all`: the other senders are not allowed.
+    Fail,
+}
+
+impl SpfAll {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SpfAll::Pass => "+all",
+            SpfAll::Neutral => "?all",
+            SpfAll::SoftFail => "~all",
+            SpfAll::Fail => "-all",
+        }
+    }
+}
+
+/// A builder for the value of an SPF TXT record, such as `v=spf1 include:_spf.google.com ~all`.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::presets::{Spf, SpfAll};
+///
+/// let spf = Spf::new().include("_spf.google.com").ip4("192.0.2.0/24").all(SpfAll::Fail);
+/// assert_eq!(spf.to_string(), "v=spf1 include:_spf.google.com ip4:192.0.2.0/24 -all");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Spf {
+    mechanisms: Vec<String>,
+    all: SpfAll,
+}
+
+impl Spf {
+    /// Creates an SPF record listing no sender, which soft-fails every sender.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows the senders allowed by the SPF record of another domain (e.g., "_spf.google.com").
+    pub fn include(mut self, domain: &str) -> Self {
+        self.mechanisms.push(format!("include:{}", domain));
+        self
+    }
+
+    /// Allows an IPv4 address or network (e.g., "192.0.2.1" or "192.0.2.0/24").
+    pub fn ip4(mut self, network: &str) -> Self {
+        self.mechanisms.push(format!("ip4:{}", network));
+        self
+    }
+
+    /// Allows an IPv6 address or network (e.g., "2001:db8::/32").
+    pub fn ip6(mut self, network: &str) -> Self {
+        self.mechanisms.push(format!("ip6:{}", network));
+        self
+    }
+
+    /// Allows the addresses of the A and AAAA records of the domain.
+    pub fn a(mut self) -> Self {
+        self.mechanisms.push("a".to_string());
+        self
+    }
+
+    /// Allows the addresses of the mail servers of the MX records of the domain.
+    pub fn mx(mut self) -> Self {
+        self.mechanisms.push("mx".to_string());
+        self
+    }
+
+    /// Sets what the record says about the senders it does not list. Defaults to `SpfAll::SoftFail`.
+    pub fn all(mut self, all: SpfAll) -> Self {
+        self.all = all;
+        self
+    }
+
+    /// Rejects the addresses that are not valid and the records causing more than 10 DNS lookups,
+    /// which receivers treat as a permanent error.
+    pub fn validate(&self) -> Result<()> {
+        let mut lookups = 0;
+        for mechanism in &self.mechanisms {
+            let (name, argument) = mechanism.split_once(':').unwrap_or((mechanism, ""));
+            let address = argument
+                .split_once('/')
+                .map_or(argument, |(address, _)| address);
+            let valid = match name {
+                "ip4" => address.parse::<Ipv4Addr>().is_ok(),
+                "ip6" => address.parse::<Ipv6Addr>().is_ok(),
+                _ => {
+                    lookups += 1;
+                    true
+                }
+            };
+            if !valid {
+                return Err(AliyunDnsError::InvalidInput(format!(
+                    "Invalid SPF mechanism {}",
+                    mechanism
+                )));
+            }
+        }
+        if lookups > MAX_SPF_LOOKUPS {
+            return Err(AliyunDnsError::InvalidInput(format!(
+                "The SPF record causes {} DNS lookups, more than the {} allowed",
+                lookups, MAX_SPF_LOOKUPS
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Spf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v=spf1")?;
+        for mechanism in &self.mechanisms {
+            write!(f, " {}", mechanism)?;
+        }
+        write!(f, " {}", self.all.as_str())
+    }
+}
+
+/// Returns the value of a DKIM TXT record publishing an RSA public key.
+///
+/// The key may be given in the PEM format, whose header, footer and line breaks are removed.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::presets::dkim_value;
+///
+/// let pem = "-----BEGIN PUBLIC KEY-----\nMIIBIjAN\nBgkqhkiG\n-----END PUBLIC KEY-----\n";
+/// assert_eq!(dkim_value(pem), "v=DKIM1; k=rsa; p=MIIBIjANBgkqhkiG");
+/// ```
+pub fn dkim_value(public_key: &str) -> String {
+    let key: String = public_key
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .flat_map(|line| line.split_whitespace())
+        .collect();
+    format!("v=DKIM1; k=rsa; p={}", key)
+}
+
+/// What receivers should do with the mail failing the DMARC checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DmarcPolicy {
+    /// Deliver the mail, and only report it.
+    None,
+    /// Treat the mail as suspicious, such as by delivering it to the spam folder.
+    Quarantine,
+    /// Reject the mail.
+    Reject,
+}
+
+impl DmarcPolicy {
+    fn as_str(&self) -> &'static str {
+        match self {
+            DmarcPolicy::None => "none",
+            DmarcPolicy::Quarantine => "quarantine",
+            DmarcPolicy::Reject => "reject",
+        }
+    }
+}
+
+/// A builder for the value of a DMARC TXT record, published on the `_dmarc` host record.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::presets::{Dmarc, DmarcPolicy};
+///
+/// let dmarc = Dmarc::new(DmarcPolicy::Reject).pct(50).rua("dmarc@example.com");
+/// assert_eq!(dmarc.to_string(), "v=DMARC1; p=reject; pct=50; rua=mailto:dmarc@example.com");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Dmarc {
+    policy: DmarcPolicy,
+    subdomain_policy: Option<DmarcPolicy>,
+    pct: Option<u8>,
+    rua: Vec<String>,
+    ruf: Vec<String>,
+}
+
+/// Returns a reporting address as a URI, adding `mailto:` to plain mail addresses.
+fn mailto(address: &str) -> String {
+    if address.contains(':') {
+        address.to_string()
+    } else {
+        format!("mailto:{}", address)
+    }
+}
+
+impl Dmarc {
+    /// Creates a DMARC record with a policy.
+    pub fn new(policy: DmarcPolicy) -> Self {
+        Dmarc {
+            policy,
+            subdomain_policy: None,
+            pct: None,
+            rua: Vec::new(),
+            ruf: Vec::new(),
+        }
+    }
+
+    /// Sets the policy of the subdomains, which defaults to the policy of the domain.
+    pub fn subdomain_policy(mut self, policy: DmarcPolicy) -> Self {
+        self.subdomain_policy = Some(policy);
+        self
+    }
+
+    /// Sets the percentage of the failing mail the policy applies to (0 to 100).
+    pub fn pct(mut self, pct: u8) -> Self {
+        self.pct = Some(pct.min(100));
+        self
+    }
+
+    /// Adds an address the aggregate reports are sent to (e.g., "dmarc@example.com").
+    pub fn rua(mut self, address: &str) -> Self {
+        self.rua.push(mailto(address));
+        self
+    }
+
+    /// Adds an address the failure reports are sent to.
+    pub fn ruf(mut self, address: &str) -> Self {
+        self.ruf.push(mailto(address));
+        self
+    }
+}
+
+impl fmt::Display for Dmarc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v=DMARC1; p={}", self.policy.as_str())?;
+        if let Some(policy) = self.subdomain_policy {
+            write!(f, "; sp={}", policy.as_str())?;
+        }
+        if let Some(pct) = self.pct {
+            write!(f, "; pct={}", pct)?;
+        }
+        if !self.rua.is_empty() {
+            write!(f, "; rua={}", self.rua.join(","))?;
+        }
+        if !self.ruf.is_empty() {
+            write!(f, "; ruf={}", self.ruf.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+/// A mail provider whose MX and SPF records are known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MailProvider {
+    /// Alibaba Mail, formerly Aliyun Enterprise Mail.
+    AliyunEnterpriseMail,
+    /// Google Workspace.
+    GoogleWorkspace,
+    /// Microsoft 365 (Exchange Online).
+    Microsoft365,
+}
+
+impl MailProvider {
+    /// Returns the SPF record allowing the servers of the provider, which may be extended with
+    /// other senders before being passed to `MailPreset::spf`.
+    pub fn spf(&self) -> Spf {
+        match self {
+            MailProvider::AliyunEnterpriseMail => {
+                Spf::new().include("spf.qiye.aliyun.com").all(SpfAll::Fail)
+            }
+            MailProvider::GoogleWorkspace => Spf::new().include("_spf.google.com"),
+            MailProvider::Microsoft365 => Spf::new()
+                .include("spf.protection.outlook.com")
+                .all(SpfAll::Fail),
+        }
+    }
+
+    /// Returns the MX records of the provider for a domain, and the other records it needs.
+    fn records(&self, domain_name: &str) -> Vec<RecordSpec> {
+        match self {
+            MailProvider::AliyunEnterpriseMail => vec![
+                RecordSpec::mx("@", "mx1.qiye.aliyun.com", 5),
+                RecordSpec::mx("@", "mx2.qiye.aliyun.com", 10),
+                RecordSpec::mx("@", "mx3.qiye.aliyun.com", 15),
+            ],
+            MailProvider::GoogleWorkspace => vec![RecordSpec::mx("@", "smtp.google.com", 1)],
+            MailProvider::Microsoft365 => vec![
+                RecordSpec::mx(
+                    "@",
+                    &format!(
+                        "{}.mail.protection.outlook.com",
+                        domain_name.replace('.', "-")
+                    ),
+                    1,
+                ),
+                RecordSpec::cname("autodiscover", "autodiscover.outlook.com"),
+            ],
+        }
+    }
+}
+
+/// The records of a mail setup: the MX and SPF records of a provider, and optionally DKIM and DMARC.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::presets::{MailPreset, MailProvider};
+///
+/// let spf = MailProvider::GoogleWorkspace.spf().ip4("192.0.2.1");
+/// let preset = MailPreset::new(MailProvider::GoogleWorkspace).spf(spf);
+/// let records = preset.records("example.com").unwrap();
+/// assert_eq!(records[1].value(), "v=spf1 include:_spf.google.com ip4:192.0.2.1 ~all");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MailPreset {
+    provider: MailProvider,
+    spf: Spf,
+    dkim: Option<(String, String)>,
+    dmarc: Option<Dmarc>,
+}
+
+impl MailPreset {
+    /// Creates the preset of a provider, with its SPF record and without DKIM nor DMARC.
+    pub fn new(provider: MailProvider) -> Self {
+        MailPreset {
+            provider,
+            spf: provider.spf(),
+            dkim: None,
+            dmarc: None,
+        }
+    }
+
+    /// Replaces the SPF record of the provider, such as to allow other senders.
+    pub fn spf(mut self, spf: Spf) -> Self {
+        self.spf = spf;
+        self
+    }
+
+    /// Publishes the DKIM public key given by the provider under a selector (e.g., "google").
+    pub fn dkim(mut self, selector: &str, public_key: &str) -> Self {
+        self.dkim = Some((selector.to_string(), dkim_value(public_key)));
+        self
+    }
+
+    /// Publishes a DMARC record.
+    pub fn dmarc(mut self, dmarc: Dmarc) -> Self {
+        self.dmarc = Some(dmarc);
+        self
+    }
+
+    /// Returns the records of the preset for a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the mail is sent from and received at.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the records, or an error if the SPF record is invalid.
+    pub fn records(&self, domain_name: &str) -> Result<Vec<RecordSpec>> {
+        self.spf.validate()?;
+        let mut records = self.provider.records(domain_name);
+        records.push(RecordSpec::txt("@", &self.spf.to_string()));
+        if let Some((selector, value)) = &self.dkim {
+            records.push(RecordSpec::txt(&format!("{}._domainkey", selector), value));
+        }
+        if let Some(dmarc) = &self.dmarc {
+            records.push(RecordSpec::txt("_dmarc", &dmarc.to_string()));
+        }
+        Ok(records)
+    }
+}
+
+/// Returns the tag of a TXT value, such as "v=spf1", which tells SPF, DKIM and DMARC records apart
+/// from the other TXT records of the same name.
+fn txt_tag(value: &str) -> Option<String> {
+    let value = join_txt(value);
+    value.starts_with("v=").then(|| {
+        value
+            .split([' ', ';'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase()
+    })
+}
+
+/// Returns whether a live record is managed by the preset records: the records of the same name
+/// and type, except the TXT records of another kind than the TXT record of the preset.
+fn is_owned(record: &DomainRecord, records: &[RecordSpec]) -> bool {
+    records.iter().any(|spec| {
+        spec.has_same_name(record)
+            && (!spec.record_type().eq_ignore_ascii_case("TXT")
+                || txt_tag(&record.value) == txt_tag(spec.value()))
+    })
+}
+
+impl AliyunDns {
+    /// Computes the changes that bring the mail records of a domain to a preset.
+    ///
+    /// The MX records, the CNAME records of the preset and the SPF, DKIM and DMARC values of the
+    /// same names are replaced. The other records, such as the TXT records used to verify the
+    /// domain, are left alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the mail is sent from and received at.
+    /// * `preset` - The mail setup.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Plan` of changes, or an error if the SPF record is invalid or the records cannot be queried.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    /// use aliyun_dns::presets::{MailPreset, MailProvider};
+    /// use aliyun_dns::reconcile::Plan;
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let preset = MailPreset::new(MailProvider::Microsoft365);
+    /// let result: Result<Plan, _> = aliyun_dns.plan_mail_preset("example.com", &preset).await;
+    /// # }
+    /// ```
+    pub async fn plan_mail_preset(&self, domain_name: &str, preset: &MailPreset) -> Result<Plan> {
+        let records = preset.records(domain_name)?;
+        let live: Vec<DomainRecord> = self
+            .query_all_domain_records(domain_name)
+            .await?
+            .into_iter()
+            .filter(|record| is_owned(record, &records))
+            .collect();
+        Ok(diff(domain_name, &records, &live))
+    }
+
+    /// Brings the mail records of a domain to a preset, as planned by `plan_mail_preset`.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the mail is sent from and received at.
+    /// * `preset` - The mail setup.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `ChangeResult` of every change, in order, or an error if the SPF record is invalid or the records cannot be queried.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    /// use aliyun_dns::presets::{MailPreset, MailProvider};
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let preset = MailPreset::new(MailProvider::AliyunEnterpriseMail);
+    /// let results = aliyun_dns.apply_mail_preset("example.com", &preset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn apply_mail_preset(
+        &self,
+        domain_name: &str,
+        preset: &MailPreset,
+    ) -> Result<Vec<ChangeResult>> {
+        let plan = self.plan_mail_preset(domain_name, preset).await?;
+        Ok(self.apply_plan(&plan).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::record;
+
+    #[test]
+    fn test_spf_validate() {
+        assert!(Spf::new()
+            .ip4("192.0.2.0/24")
+            .ip6("2001:db8::1")
+            .validate()
+            .is_ok());
+        assert!(Spf::new().ip4("2001:db8::1").validate().is_err());
+        let spf = (0..11).fold(Spf::new(), |spf, n| {
+            spf.include(&format!("spf{}.example.net", n))
+        });
+        assert!(spf.validate().is_err());
+    }
+
+    #[test]
+    fn test_mail_preset() {
+        let preset = MailPreset::new(MailProvider::Microsoft365)
+            .dkim("selector1", "MIIB")
+            .dmarc(Dmarc::new(DmarcPolicy::None));
+        let records = preset.records("example.com").unwrap();
+        let values: Vec<&str> = records.iter().map(|spec| spec.value()).collect();
+        assert_eq!(
+            values,
+            [
+                "example-com.mail.protection.outlook.com",
+                "autodiscover.outlook.com",
+                "v=spf1 include:spf.protection.outlook.com -all",
+                "v=DKIM1; k=rsa; p=MIIB",
+                "v=DMARC1; p=none",
+            ]
+        );
+
+        assert!(is_owned(
+            &record("@", "MX", "mx.example.net").build(),
+            &records
+        ));
+        assert!(is_owned(
+            &record("@", "TXT", "v=spf1 mx -all").build(),
+            &records
+        ));
+        assert!(!is_owned(
+            &record("@", "TXT", "google-site-verification=abc").build(),
+            &records
+        ));
+        assert!(!is_owned(
+            &record("www", "A", "192.0.2.1").build(),
+            &records
+        ));
+    }
+
+    #[test]
+    fn test_sign_preset_value() {
+        let aliyun_dns = AliyunDns::new("testid".to_string(), "testsecret".to_string());
+        let preset = MailPreset::new(MailProvider::GoogleWorkspace);
+        let spec = preset.records("example.com").unwrap().remove(1);
+        assert_eq!(spec.value(), "v=spf1 include:_spf.google.com ~all");
+        let params = std::collections::HashMap::from([
+            ("Action", "AddDomainRecord".to_string()),
+            ("Value", spec.value().to_string()),
+        ]);
+
+        assert_eq!(
+            crate::canonical_query_string(&params),
+            "Action=AddDomainRecord&Value=v%3Dspf1%20include%3A_spf.google.com%20~all"
+        );
+        assert!(aliyun_dns
+            .string_to_sign(&params)
+            .ends_with("Value%3Dv%253Dspf1%2520include%253A_spf.google.com%2520~all"));
+        assert!(aliyun_dns.sign_request("testsecret", &params).is_ok());
+    }
+}
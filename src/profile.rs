@@ -0,0 +1,236 @@
+//! Credentials from the configuration file of the Aliyun CLI, so that tools pick up the profiles developers already configured.
+
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::OnceCell;
+
+use crate::{
+    AliyunDnsError, AssumeRoleProvider, Credentials, CredentialsFuture, CredentialsProvider,
+    EcsRamRoleProvider, OidcProvider, Result,
+};
+
+/// The environment variable holding the path of the configuration file, instead of `~/.aliyun/config.json`.
+pub const ENV_CREDENTIALS_FILE: &str = "ALIBABA_CLOUD_CREDENTIALS_FILE";
+/// The environment variable holding the name of the profile, instead of the current profile of the configuration file.
+pub const ENV_PROFILE: &str = "ALIBABA_CLOUD_PROFILE";
+
+/// A provider reading a profile of the Aliyun CLI configuration file, `~/.aliyun/config.json` unless `ALIBABA_CLOUD_CREDENTIALS_FILE` is set.
+///
+/// The profile named by `ALIBABA_CLOUD_PROFILE` is used, or else the current profile of the file.
+/// The `AK`, `StsToken`, `RamRoleArn`, `EcsRamRole` and `OIDC` modes are supported.
+/// The file is read on the first request, and the credentials of the roles are refreshed before they expire.
+///
+/// # Examples
+///
+/// ```no_run
+/// use aliyun_dns::{AliyunDns, ProfileProvider};
+///
+/// let aliyun_dns = AliyunDns::from_provider(ProfileProvider::new().profile("production"));
+/// ```
+#[derive(Debug, Default)]
+pub struct ProfileProvider {
+    path: Option<PathBuf>,
+    profile: Option<String>,
+    provider: OnceCell<Box<dyn CredentialsProvider>>,
+}
+
+impl ProfileProvider {
+    /// Creates a provider reading the selected profile of the default configuration file.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the path of the configuration file.
+    pub fn path(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the name of the profile.
+    pub fn profile(mut self, profile: &str) -> Self {
+        self.profile = Some(profile.to_string());
+        self
+    }
+
+    /// Returns the path of the configuration file: the one set, the one of `ALIBABA_CLOUD_CREDENTIALS_FILE`, or `~/.aliyun/config.json`.
+    pub(crate) fn config_path(&self) -> Result<PathBuf> {
+        if let Some(path) = &self.path {
+            return Ok(path.clone());
+        }
+        if let Some(path) = std::env::var_os(ENV_CREDENTIALS_FILE).filter(|path| !path.is_empty()) {
+            return Ok(PathBuf::from(path));
+        }
+        std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(|home| PathBuf::from(home).join(".aliyun").join("config.json"))
+            .ok_or_else(|| {
+                AliyunDnsError::Credentials("The home directory cannot be determined".to_string())
+            })
+    }
+
+    /// Reads the configuration file and creates the provider of the selected profile.
+    async fn load(&self) -> Result<Box<dyn CredentialsProvider>> {
+        let path = self.config_path()?;
+        let body = tokio::fs::read_to_string(&path).await.map_err(|error| {
+            AliyunDnsError::Credentials(format!(
+                "Failed to read the configuration file {}: {}",
+                path.display(),
+                error
+            ))
+        })?;
+        let config: ProfileConfig = serde_json::from_str(&body)
+            .map_err(|source| AliyunDnsError::Decode { source, body })?;
+
+        let name = match &self.profile {
+            Some(profile) => profile.clone(),
+            None => match std::env::var(ENV_PROFILE) {
+                Ok(profile) if !profile.is_empty() => profile,
+                _ => config.current.clone(),
+            },
+        };
+        config.provider(&name)
+    }
+}
+
+impl CredentialsProvider for ProfileProvider {
+    fn credentials(&self) -> CredentialsFuture<'_> {
+        Box::pin(async move {
+            let provider = self.provider.get_or_try_init(|| self.load()).await?;
+            provider.credentials().await
+        })
+    }
+}
+
+/// The configuration file of the Aliyun CLI.
+#[derive(Debug, Deserialize)]
+struct ProfileConfig {
+    #[serde(default)]
+    current: String,
+    #[serde(default)]
+    profiles: Vec<Profile>,
+}
+
+/// A profile of the Aliyun CLI, of which only the settings of the supported modes are read.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Profile {
+    name: String,
+    mode: String,
+    access_key_id: String,
+    access_key_secret: String,
+    sts_token: String,
+    ram_role_name: String,
+    ram_role_arn: String,
+    ram_session_name: String,
+    expired_seconds: u64,
+    oidc_provider_arn: String,
+    oidc_token_file: String,
+}
+
+impl ProfileConfig {
+    /// Creates the provider of a profile, according to its mode.
+    fn provider(&self, name: &str) -> Result<Box<dyn CredentialsProvider>> {
+        let profile = self
+            .profiles
+            .iter()
+            .find(|profile| profile.name == name)
+            .ok_or_else(|| {
+                AliyunDnsError::Credentials(format!("The profile {:?} is not configured", name))
+            })?;
+        let access_key = || Credentials::new(&profile.access_key_id, &profile.access_key_secret);
+
+        Ok(match profile.mode.as_str() {
+            "AK" => Box::new(access_key()),
+            "StsToken" => Box::new(access_key().security_token(&profile.sts_token)),
+            "RamRoleArn" => {
+                let mut provider = AssumeRoleProvider::new(access_key(), &profile.ram_role_arn);
+                if !profile.ram_session_name.is_empty() {
+                    provider = provider.role_session_name(&profile.ram_session_name);
+                }
+                if profile.expired_seconds > 0 {
+                    provider = provider.duration(Duration::from_secs(profile.expired_seconds));
+                }
+                Box::new(provider)
+            }
+            "EcsRamRole" => {
+                let provider = EcsRamRoleProvider::new();
+                Box::new(if profile.ram_role_name.is_empty() {
+                    provider
+                } else {
+                    provider.role_name(&profile.ram_role_name)
+                })
+            }
+            "OIDC" => {
+                let mut provider = OidcProvider::new(
+                    &profile.ram_role_arn,
+                    &profile.oidc_provider_arn,
+                    &profile.oidc_token_file,
+                );
+                if !profile.ram_session_name.is_empty() {
+                    provider = provider.role_session_name(&profile.ram_session_name);
+                }
+                Box::new(provider)
+            }
+            mode => {
+                return Err(AliyunDnsError::Credentials(format!(
+                    "The mode {:?} of the profile {:?} is not supported",
+                    mode, name
+                )))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_profile_config() {
+        let config: ProfileConfig = serde_json::from_str(
+            r#"{
+                "current": "default",
+                "profiles": [
+                    {
+                        "name": "default",
+                        "mode": "AK",
+                        "access_key_id": "id",
+                        "access_key_secret": "secret",
+                        "region_id": "cn-hangzhou",
+                        "output_format": "json",
+                        "language": "en"
+                    },
+                    {
+                        "name": "sts",
+                        "mode": "StsToken",
+                        "access_key_id": "STS.id",
+                        "access_key_secret": "secret",
+                        "sts_token": "token"
+                    },
+                    {
+                        "name": "signer",
+                        "mode": "RsaKeyPair"
+                    }
+                ],
+                "meta_path": ""
+            }"#,
+        )
+        .unwrap();
+
+        let credentials = config
+            .provider("default")
+            .unwrap()
+            .credentials()
+            .await
+            .unwrap();
+        assert_eq!(credentials.access_key_id(), "id");
+        assert_eq!(credentials.get_security_token(), None);
+
+        let credentials = config.provider("sts").unwrap().credentials().await.unwrap();
+        assert_eq!(credentials.get_security_token(), Some("token"));
+
+        assert!(config.provider("signer").is_err());
+        assert!(config.provider("missing").is_err());
+    }
+}
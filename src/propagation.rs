@@ -0,0 +1,187 @@
+//! Propagation checks, which wait for a record to be served by the authoritative nameservers of its zone.
+
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig, ResolverOpts};
+use hickory_resolver::proto::rr::{RData, RecordType};
+use hickory_resolver::{Name, TokioAsyncResolver};
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+use crate::{AliyunDns, AliyunDnsError, Result};
+
+/// The time between two rounds of queries to the nameservers.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Returns the error for a lookup that could not be made.
+fn propagation_error(message: impl std::fmt::Display) -> AliyunDnsError {
+    AliyunDnsError::Propagation(message.to_string())
+}
+
+/// Returns the value of a record as entered in the console, without trailing dots or quotes.
+fn record_value(data: &RData) -> String {
+    match data {
+        RData::TXT(txt) => txt
+            .txt_data()
+            .iter()
+            .map(|part| String::from_utf8_lossy(part))
+            .collect(),
+        RData::MX(mx) => mx.exchange().to_string(),
+        data => data.to_string(),
+    }
+    .trim_end_matches('.')
+    .to_string()
+}
+
+/// Returns whether a value served by a nameserver matches the expected value.
+fn matches_value(record_type: RecordType, served: &str, expected: &str) -> bool {
+    let expected = expected.trim_end_matches('.');
+    match record_type {
+        RecordType::TXT => served == expected,
+        _ => served.eq_ignore_ascii_case(expected),
+    }
+}
+
+/// Finds the authoritative nameservers of the zone of a name, and returns their addresses.
+async fn authoritative_servers(resolver: &TokioAsyncResolver, name: &Name) -> Result<Vec<IpAddr>> {
+    let mut zone = name.clone();
+    loop {
+        if let Ok(lookup) = resolver.lookup(zone.clone(), RecordType::NS).await {
+            let mut servers = Vec::new();
+            for data in lookup.iter() {
+                if let RData::NS(ns) = data {
+                    let ips = resolver
+                        .lookup_ip(ns.0.clone())
+                        .await
+                        .map_err(propagation_error)?;
+                    servers.extend(ips.iter());
+                }
+            }
+            if !servers.is_empty() {
+                return Ok(servers);
+            }
+        }
+        if zone.is_root() {
+            return Err(propagation_error(format!(
+                "No authoritative nameserver found for {}",
+                name
+            )));
+        }
+        zone = zone.base_name();
+    }
+}
+
+/// Returns a resolver querying a single nameserver directly, without recursion nor cache.
+fn direct_resolver(server: IpAddr) -> TokioAsyncResolver {
+    let config = ResolverConfig::from_parts(
+        None,
+        Vec::new(),
+        NameServerConfigGroup::from_ips_clear(&[server], 53, true),
+    );
+    let mut options = ResolverOpts::default();
+    options.recursion_desired = false;
+    options.cache_size = 0;
+    options.use_hosts_file = false;
+    TokioAsyncResolver::tokio(config, options)
+}
+
+impl AliyunDns {
+    /// Waits until a record is served by every authoritative nameserver of its zone.
+    ///
+    /// The nameservers are queried directly, bypassing caches, every few seconds. This is useful
+    /// between publishing an ACME challenge and asking the certificate authority to validate it.
+    ///
+    /// This method is available with the `propagation` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `fqdn` - The fully qualified name of the record (e.g., "_acme-challenge.example.com").
+    /// * `record_type` - The type of the record (e.g., "A", "TXT", etc.).
+    /// * `expected_value` - The value to wait for, among the values of the record.
+    /// * `timeout` - How long to wait before giving up.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is `Ok` once every nameserver serves the value, or an error if the timeout expires or the nameservers cannot be found.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    /// use std::time::Duration;
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<(), _> = aliyun_dns
+    ///     .wait_for_record("_acme-challenge.example.com", "TXT", "gfj9Xq...Rg85nM", Duration::from_secs(120))
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn wait_for_record(
+        &self,
+        fqdn: &str,
+        record_type: &str,
+        expected_value: &str,
+        timeout: Duration,
+    ) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        let name =
+            Name::from_str(&format!("{}.", fqdn.trim_end_matches('.'))).map_err(|error| {
+                AliyunDnsError::InvalidInput(format!("Invalid domain name {}: {}", fqdn, error))
+            })?;
+        let record_type = RecordType::from_str(&record_type.to_uppercase()).map_err(|error| {
+            AliyunDnsError::InvalidInput(format!("Invalid record type {}: {}", record_type, error))
+        })?;
+        let system = TokioAsyncResolver::tokio_from_system_conf().map_err(propagation_error)?;
+        let resolvers: Vec<TokioAsyncResolver> = authoritative_servers(&system, &name)
+            .await?
+            .into_iter()
+            .map(direct_resolver)
+            .collect();
+
+        loop {
+            let mut pending = 0;
+            for resolver in &resolvers {
+                let served = match resolver.lookup(name.clone(), record_type).await {
+                    Ok(lookup) => lookup.iter().any(|data| {
+                        matches_value(record_type, &record_value(data), expected_value)
+                    }),
+                    Err(_) => false,
+                };
+                if !served {
+                    pending += 1;
+                }
+            }
+            if pending == 0 {
+                return Ok(());
+            }
+            if Instant::now() + POLL_INTERVAL > deadline {
+                return Err(propagation_error(format!(
+                    "{} of {} nameservers do not serve the {} record {} with value {:?} yet",
+                    pending,
+                    resolvers.len(),
+                    record_type,
+                    fqdn,
+                    expected_value
+                )));
+            }
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_value() {
+        assert!(matches_value(
+            RecordType::CNAME,
+            "Example.net",
+            "example.net."
+        ));
+        assert!(!matches_value(RecordType::TXT, "Token", "token"));
+        assert!(matches_value(RecordType::TXT, "token", "token"));
+    }
+}
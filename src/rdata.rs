@@ -0,0 +1,273 @@
+//! Typed values of the record types whose values have several fields, such as SRV and CAA.
+
+use std::fmt;
+
+use crate::{AliyunDnsError, RecordSpec, Result};
+
+/// Returns whether a name is a valid host name: dot-separated labels of at most 63 letters, digits,
+/// hyphens and underscores, not starting nor ending with a hyphen, with an optional trailing dot.
+/// With the `idna` feature, the labels that are not ASCII are checked once converted to punycode.
+pub(crate) fn is_hostname(name: &str) -> bool {
+    #[cfg(feature = "idna")]
+    let ascii = match crate::idn::to_ascii(name) {
+        Ok(ascii) => ascii,
+        Err(_) => return false,
+    };
+    #[cfg(feature = "idna")]
+    let name = ascii.as_str();
+    let name = name.strip_suffix('.').unwrap_or(name);
+    !name.is_empty()
+        && name.len() <= 253
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+}
+
+/// The value of an SRV record: `priority weight port target`.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{RecordSpec, Srv};
+///
+/// let srv = Srv::new(10, 60, 5060, "sip.example.com").unwrap();
+/// assert_eq!(srv.to_string(), "10 60 5060 sip.example.com");
+/// let spec = RecordSpec::srv("_sip._tcp", &srv);
+/// assert!(Srv::new(10, 60, 5060, "sip example").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Srv {
+    priority: u16,
+    weight: u16,
+    port: u16,
+    target: String,
+}
+
+impl Srv {
+    /// Creates the value of an SRV record.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority` - The priority of the target, lower values first.
+    /// * `weight` - The relative weight of the targets of the same priority.
+    /// * `port` - The port of the service on the target.
+    /// * `target` - The host name of the target, or "." if the service is not available.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Srv`, or an error if the target is not a host name.
+    pub fn new(priority: u16, weight: u16, port: u16, target: &str) -> Result<Self> {
+        if target != "." && !is_hostname(target) {
+            return Err(AliyunDnsError::InvalidInput(format!(
+                "Invalid SRV target {:?}: expected a host name",
+                target
+            )));
+        }
+        Ok(Srv {
+            priority,
+            weight,
+            port,
+            target: target.trim_end_matches('.').to_string(),
+        })
+    }
+
+    /// Returns the priority of the target.
+    pub fn priority(&self) -> u16 {
+        self.priority
+    }
+
+    /// Returns the relative weight of the target.
+    pub fn weight(&self) -> u16 {
+        self.weight
+    }
+
+    /// Returns the port of the service.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns the host name of the target.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+}
+
+impl fmt::Display for Srv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let target = if self.target.is_empty() {
+            "."
+        } else {
+            &self.target
+        };
+        write!(
+            f,
+            "{} {} {} {}",
+            self.priority, self.weight, self.port, target
+        )
+    }
+}
+
+/// The property of a CAA record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaaTag {
+    /// `issue`: the certificate authority may issue certificates for the name.
+    Issue,
+    /// `issuewild`: the certificate authority may issue wildcard certificates for the name.
+    IssueWild,
+    /// `iodef`: where certificate authorities report the requests that violate the policy.
+    Iodef,
+}
+
+impl CaaTag {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CaaTag::Issue => "issue",
+            CaaTag::IssueWild => "issuewild",
+            CaaTag::Iodef => "iodef",
+        }
+    }
+}
+
+/// The value of a CAA record: `flags tag "value"`.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{Caa, RecordSpec};
+///
+/// let caa = Caa::issue("letsencrypt.org").unwrap();
+/// assert_eq!(caa.to_string(), "0 issue \"letsencrypt.org\"");
+/// let spec = RecordSpec::caa("@", &caa);
+/// assert!(Caa::iodef("security@example.com").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Caa {
+    flags: u8,
+    tag: CaaTag,
+    value: String,
+}
+
+impl Caa {
+    /// The flag telling certificate authorities to refuse issuing if they do not understand the tag.
+    pub const CRITICAL: u8 = 128;
+
+    /// Creates the value of a CAA record, with no flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The property of the record.
+    /// * `value` - The domain name of a certificate authority, with optional parameters (e.g., "letsencrypt.org; validationmethods=dns-01") or ";" to forbid issuing for `issue` and `issuewild`, or a `mailto:`, `http:` or `https:` URL for `iodef`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Caa`, or an error if the value does not fit the tag.
+    pub fn new(tag: CaaTag, value: &str) -> Result<Self> {
+        let valid = match tag {
+            CaaTag::Issue | CaaTag::IssueWild => {
+                let issuer = value.split(';').next().unwrap_or_default().trim();
+                issuer.is_empty() || is_hostname(issuer)
+            }
+            CaaTag::Iodef => ["mailto:", "http://", "https://"]
+                .iter()
+                .any(|scheme| value.starts_with(scheme)),
+        };
+        if !valid || value.contains('"') {
+            return Err(AliyunDnsError::InvalidInput(format!(
+                "Invalid CAA {} value {:?}",
+                tag.as_str(),
+                value
+            )));
+        }
+        Ok(Caa {
+            flags: 0,
+            tag,
+            value: value.to_string(),
+        })
+    }
+
+    /// Creates the value of a CAA `issue` record, allowing a certificate authority (e.g., "letsencrypt.org").
+    pub fn issue(issuer: &str) -> Result<Self> {
+        Self::new(CaaTag::Issue, issuer)
+    }
+
+    /// Creates the value of a CAA `issuewild` record, allowing a certificate authority to issue wildcard certificates.
+    pub fn issuewild(issuer: &str) -> Result<Self> {
+        Self::new(CaaTag::IssueWild, issuer)
+    }
+
+    /// Creates the value of a CAA `iodef` record, with the URL violations are reported to (e.g., "mailto:security@example.com").
+    pub fn iodef(url: &str) -> Result<Self> {
+        Self::new(CaaTag::Iodef, url)
+    }
+
+    /// Sets the critical flag, so that certificate authorities that do not understand the tag refuse to issue.
+    pub fn critical(mut self) -> Self {
+        self.flags |= Self::CRITICAL;
+        self
+    }
+
+    /// Returns the flags of the record.
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Returns the property of the record.
+    pub fn tag(&self) -> CaaTag {
+        self.tag
+    }
+
+    /// Returns the value of the property, without quotes.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for Caa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} \"{}\"", self.flags, self.tag.as_str(), self.value)
+    }
+}
+
+impl RecordSpec {
+    /// Creates an SRV record, whose host record names the service and protocol (e.g., "_sip._tcp").
+    pub fn srv(rr: &str, srv: &Srv) -> Self {
+        Self::new(rr, "SRV", &srv.to_string())
+    }
+
+    /// Creates a CAA record.
+    pub fn caa(rr: &str, caa: &Caa) -> Self {
+        Self::new(rr, "CAA", &caa.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_hostname() {
+        assert!(is_hostname("sip.example.com"));
+        assert!(is_hostname("_sip._tcp.example.com."));
+        assert!(!is_hostname(""));
+        assert!(!is_hostname("-bad.example.com"));
+        assert!(!is_hostname("bad..example.com"));
+        assert!(!is_hostname(&"a".repeat(64)));
+    }
+
+    #[test]
+    fn test_caa() {
+        assert_eq!(
+            Caa::issuewild(";").unwrap().critical().to_string(),
+            "128 issuewild \";\""
+        );
+        assert!(Caa::issue("letsencrypt.org; validationmethods=dns-01").is_ok());
+        assert!(Caa::issue("lets encrypt").is_err());
+        assert!(Caa::iodef("https://example.com/caa").is_ok());
+    }
+}
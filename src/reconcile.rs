@@ -0,0 +1,367 @@
+//! Declarative reconciliation, which brings the records of a domain to a desired set of records.
+//!
+//! `AliyunDns::plan_zone` compares the desired records with the live records of the domain and
+//! returns a `Plan` of the creates, updates and deletes needed, which `AliyunDns::apply_plan`
+//! then executes.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use aliyun_dns::{AliyunDns, RecordSpec};
+//!
+//! # async fn run() -> anyhow::Result<()> {
+//! let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+//! let desired = vec![
+//!     RecordSpec::a("www", "192.0.2.1").ttl(600),
+//!     RecordSpec::mx("@", "mail.example.com", 10),
+//! ];
+//! let plan = aliyun_dns.plan_zone("example.com", &desired).await?;
+//! print!("{}", plan);
+//! for result in aliyun_dns.apply_plan(&plan).await {
+//!     if let Err(error) = &result.result {
+//!         eprintln!("{}: {}", result.change, error);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use crate::txt::is_same_value;
+use crate::{validate_records, AliyunDns, DomainRecord, Line, RecordSpec, Result};
+
+/// A change to the records of a domain.
+#[derive(Debug, Clone)]
+pub enum Change {
+    /// Adds a record.
+    Create(RecordSpec),
+    /// Updates a live record to the value and settings of a desired record.
+    Update {
+        /// The live record.
+        record: DomainRecord,
+        /// The desired record.
+        spec: RecordSpec,
+    },
+    /// Deletes a live record that is not desired.
+    Delete(DomainRecord),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Create(spec) => write!(
+                f,
+                "create {} {} {}",
+                spec.rr(),
+                spec.record_type(),
+                spec.value()
+            ),
+            Change::Update { record, spec } => write!(
+                f,
+                "update {} {} {} -> {}",
+                record.rr,
+                record.record_type,
+                record.value,
+                spec.value()
+            ),
+            Change::Delete(record) => write!(
+                f,
+                "delete {} {} {}",
+                record.rr, record.record_type, record.value
+            ),
+        }
+    }
+}
+
+/// The changes that bring the records of a domain to a desired set of records.
+///
+/// The deletes come first, then the updates and the creates, so that a record may replace a
+/// record of a conflicting type, such as a CNAME replacing an A record.
+#[derive(Debug, Clone)]
+pub struct Plan {
+    /// The domain name the changes apply to.
+    pub domain_name: String,
+    /// The changes, in the order they are applied.
+    pub changes: Vec<Change>,
+}
+
+impl Plan {
+    /// Returns whether the records of the domain are already the desired ones.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of a change applied by `AliyunDns::apply_plan`.
+#[derive(Debug)]
+pub struct ChangeResult {
+    /// The change.
+    pub change: Change,
+    /// The ID of the record created, updated or deleted, or the error of the change.
+    pub result: Result<String>,
+}
+
+/// The name, type, line and value of a record, by which records are paired.
+pub(crate) struct RecordKey<'a> {
+    rr: &'a str,
+    record_type: &'a str,
+    line: &'a str,
+    value: &'a str,
+}
+
+impl RecordKey<'_> {
+    /// Returns whether two records have the same name and type, ignoring case, on the same line.
+    pub(crate) fn has_same_name(&self, other: &RecordKey<'_>) -> bool {
+        self.rr.eq_ignore_ascii_case(other.rr)
+            && self.record_type.eq_ignore_ascii_case(other.record_type)
+            && self.line == other.line
+    }
+
+    /// Returns whether two records of the same type have the same value.
+    fn has_same_value(&self, other: &RecordKey<'_>) -> bool {
+        is_same_value(self.record_type, self.value, other.value)
+    }
+}
+
+/// A record that can be paired with other records, either desired or live.
+pub(crate) trait Keyed {
+    /// Returns the name, type, line and value of the record.
+    fn key(&self) -> RecordKey<'_>;
+}
+
+impl Keyed for RecordSpec {
+    fn key(&self) -> RecordKey<'_> {
+        RecordKey {
+            rr: self.rr(),
+            record_type: self.record_type(),
+            line: self.options.line.as_ref().map_or("default", Line::as_str),
+            value: self.value(),
+        }
+    }
+}
+
+impl Keyed for DomainRecord {
+    fn key(&self) -> RecordKey<'_> {
+        RecordKey {
+            rr: &self.rr,
+            record_type: &self.record_type,
+            line: &self.line,
+            value: &self.value,
+        }
+    }
+}
+
+/// The records of two sets, paired by `pair_records`.
+pub(crate) struct Pairing<'a, 'b, A, B> {
+    /// The records of the same name, type, line and value in both sets.
+    pub(crate) same_value: Vec<(&'a A, &'b B)>,
+    /// The records of the same name, type and line in both sets, with different values.
+    pub(crate) changed_value: Vec<(&'a A, &'b B)>,
+    /// The records only in the first set.
+    pub(crate) only_a: Vec<&'a A>,
+    /// The records only in the second set.
+    pub(crate) only_b: Vec<&'b B>,
+}
+
+/// Pairs the records of two sets by name, type and line.
+///
+/// Records of the same value are paired first, and the other records of the same name, type and
+/// line are then paired in order, as value changes. The remaining records are left unpaired.
+pub(crate) fn pair_records<'a, 'b, A: Keyed, B: Keyed>(
+    a: &'a [A],
+    b: &'b [B],
+) -> Pairing<'a, 'b, A, B> {
+    let mut unmatched: Vec<&B> = b.iter().collect();
+    let mut pending = Vec::new();
+    let mut same_value = Vec::new();
+    for record in a {
+        let key = record.key();
+        let position = unmatched.iter().position(|other| {
+            let other = other.key();
+            key.has_same_name(&other) && key.has_same_value(&other)
+        });
+        match position {
+            Some(position) => same_value.push((record, unmatched.remove(position))),
+            None => pending.push(record),
+        }
+    }
+
+    let mut changed_value = Vec::new();
+    let mut only_a = Vec::new();
+    for record in pending {
+        let key = record.key();
+        match unmatched
+            .iter()
+            .position(|other| key.has_same_name(&other.key()))
+        {
+            Some(position) => changed_value.push((record, unmatched.remove(position))),
+            None => only_a.push(record),
+        }
+    }
+    Pairing {
+        same_value,
+        changed_value,
+        only_a,
+        only_b: unmatched,
+    }
+}
+
+/// Compares desired records with live records, pairing them by name, type and line.
+///
+/// Records of the same value are kept, or updated if their settings differ. The other desired
+/// records replace the other live records in place, and the remaining ones are created or deleted.
+pub(crate) fn diff(domain_name: &str, desired: &[RecordSpec], live: &[DomainRecord]) -> Plan {
+    let pairing = pair_records(desired, live);
+    let updates = pairing
+        .same_value
+        .into_iter()
+        .filter(|(spec, record)| !spec.is_satisfied_by(record))
+        .chain(pairing.changed_value)
+        .map(|(spec, record)| Change::Update {
+            record: record.clone(),
+            spec: spec.clone(),
+        });
+    let creates = pairing
+        .only_a
+        .into_iter()
+        .map(|spec| Change::Create(spec.clone()));
+
+    let mut changes: Vec<Change> = pairing
+        .only_b
+        .into_iter()
+        .map(|record| Change::Delete(record.clone()))
+        .collect();
+    changes.extend(updates);
+    changes.extend(creates);
+    Plan {
+        domain_name: domain_name.to_string(),
+        changes,
+    }
+}
+
+impl AliyunDns {
+    /// Computes the changes that bring the records of a domain to a desired set of records.
+    ///
+    /// Every live record of the domain that is not desired is deleted, so the desired records
+    /// should describe the whole zone.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name whose records should be reconciled.
+    /// * `desired` - The records the domain should have.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Plan` of changes, or an error if the live records cannot be queried.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordSpec};
+    /// use aliyun_dns::reconcile::Plan;
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let desired = vec![RecordSpec::a("www", "192.0.2.1")];
+    /// let result: Result<Plan, _> = aliyun_dns.plan_zone("example.com", &desired).await;
+    /// # }
+    /// ```
+    pub async fn plan_zone(&self, domain_name: &str, desired: &[RecordSpec]) -> Result<Plan> {
+        validate_records(desired)?;
+        let live = self.query_all_domain_records(domain_name).await?;
+        Ok(diff(domain_name, desired, &live))
+    }
+
+    /// Applies the changes of a plan in order, going on after a change fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `plan` - The plan, as computed by `plan_zone`.
+    ///
+    /// # Returns
+    ///
+    /// The `ChangeResult` of every change of the plan, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordSpec};
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let plan = aliyun_dns.plan_zone("example.com", &[RecordSpec::a("www", "192.0.2.1")]).await?;
+    /// let results = aliyun_dns.apply_plan(&plan).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn apply_plan(&self, plan: &Plan) -> Vec<ChangeResult> {
+        let mut results = Vec::new();
+        for change in &plan.changes {
+            let result = match change {
+                Change::Create(spec) => self
+                    .add_record(&plan.domain_name, spec)
+                    .await
+                    .map(|response| response.record_id),
+                Change::Update { record, spec } => self
+                    .update_record(&record.record_id, spec)
+                    .await
+                    .map(|response| response.record_id),
+                Change::Delete(record) => self
+                    .delete_domain_record(&record.record_id)
+                    .await
+                    .map(|response| response.record_id),
+            };
+            results.push(ChangeResult {
+                change: change.clone(),
+                result,
+            });
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::record;
+
+    #[test]
+    fn test_diff() {
+        let live = vec![
+            record("www", "A", "192.0.2.1").id("1").build(),
+            record("www", "A", "192.0.2.2").id("2").build(),
+            record("api", "A", "192.0.2.3").id("3").build(),
+            record("old", "A", "192.0.2.4").id("4").build(),
+        ];
+        let desired = vec![
+            RecordSpec::a("www", "192.0.2.2").ttl(600),
+            RecordSpec::a("www", "192.0.2.9"),
+            RecordSpec::a("api", "192.0.2.3").ttl(60),
+            RecordSpec::a("new", "192.0.2.5"),
+        ];
+        let plan = diff("example.com", &desired, &live);
+        assert_eq!(
+            plan.to_string(),
+            "delete old A 192.0.2.4\n\
+             update api A 192.0.2.3 -> 192.0.2.3\n\
+             update www A 192.0.2.1 -> 192.0.2.9\n\
+             create new A 192.0.2.5\n"
+        );
+
+        let desired: Vec<RecordSpec> = live
+            .iter()
+            .map(|record| RecordSpec::a(&record.rr, &record.value))
+            .collect();
+        assert!(diff("example.com", &desired, &live).is_empty());
+    }
+}
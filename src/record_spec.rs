@@ -0,0 +1,286 @@
+//! Record specifications, which gather the name, type, value and settings of a record to add or update.
+
+use std::collections::HashMap;
+
+use crate::{
+    split_txt, AliyunDns, DomainRecord, Lang, Line, RecordOptions, RecordResponse, RecordStatus,
+    Result,
+};
+
+/// The name, type, value and optional settings of a record, accepted by `add_record` and `update_record`.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{Line, RecordSpec};
+///
+/// let spec = RecordSpec::a("www", "192.0.2.1").ttl(600).line(Line::Unicom);
+/// let spec = RecordSpec::mx("@", "mail.example.com", 10);
+/// let spec = RecordSpec::wildcard("A", "192.0.2.1");
+/// let spec = RecordSpec::new("_sip._tcp", "SRV", "10 60 5060 sip.example.com");
+/// assert_eq!(spec.record_type(), "SRV");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecordSpec {
+    rr: String,
+    record_type: String,
+    value: String,
+    pub(crate) options: RecordOptions,
+    /// The status of the existing record the spec describes, compared by `diff_zones`.
+    pub(crate) status: Option<RecordStatus>,
+}
+
+impl RecordSpec {
+    /// The host record of the domain itself, such as `example.com`.
+    pub const APEX: &'static str = "@";
+
+    /// The host record matching every subdomain without records of its own, such as `anything.example.com`.
+    pub const WILDCARD: &'static str = "*";
+
+    /// Creates a record of any type, such as "SRV" or "CAA".
+    ///
+    /// # Arguments
+    ///
+    /// * `rr` - The subdomain of the record (e.g., "www", or "@" for the domain itself).
+    /// * `record_type` - The type of the record (e.g., "A", "CNAME", "MX", etc.).
+    /// * `value` - The value of the record (e.g., an IP address or a hostname).
+    pub fn new(rr: &str, record_type: &str, value: &str) -> Self {
+        RecordSpec {
+            rr: rr.to_string(),
+            record_type: record_type.to_string(),
+            value: value.to_string(),
+            options: RecordOptions::new(),
+            status: None,
+        }
+    }
+
+    /// Creates a record of the domain itself, on the `@` host record.
+    pub fn apex(record_type: &str, value: &str) -> Self {
+        Self::new(Self::APEX, record_type, value)
+    }
+
+    /// Creates a wildcard record, on the `*` host record, answering for every subdomain without
+    /// records of its own.
+    pub fn wildcard(record_type: &str, value: &str) -> Self {
+        Self::new(Self::WILDCARD, record_type, value)
+    }
+
+    /// Creates an A record, pointing a name to an IPv4 address.
+    pub fn a(rr: &str, ipv4: &str) -> Self {
+        Self::new(rr, "A", ipv4)
+    }
+
+    /// Creates an AAAA record, pointing a name to an IPv6 address.
+    pub fn aaaa(rr: &str, ipv6: &str) -> Self {
+        Self::new(rr, "AAAA", ipv6)
+    }
+
+    /// Creates a CNAME record, pointing a name to another hostname.
+    pub fn cname(rr: &str, target: &str) -> Self {
+        Self::new(rr, "CNAME", target)
+    }
+
+    /// Creates an MX record, routing mail to a mail server with a priority (1 to 50).
+    pub fn mx(rr: &str, mail_server: &str, priority: u32) -> Self {
+        Self::new(rr, "MX", mail_server).priority(priority)
+    }
+
+    /// Creates a TXT record. A text longer than 255 bytes, such as a DKIM key, is split into quoted
+    /// character strings with `split_txt`.
+    pub fn txt(rr: &str, text: &str) -> Self {
+        Self::new(rr, "TXT", &split_txt(text))
+    }
+
+    /// Creates an NS record, delegating a subdomain to a DNS server.
+    pub fn ns(rr: &str, name_server: &str) -> Self {
+        Self::new(rr, "NS", name_server)
+    }
+
+    /// Sets the TTL of the record, in seconds, between `Ttl::MIN` and `Ttl::MAX`.
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.options = self.options.ttl(ttl);
+        self
+    }
+
+    /// Sets the priority of an MX record (1 to 50).
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.options = self.options.priority(priority);
+        self
+    }
+
+    /// Sets the resolution line of the record (e.g., `Line::Default`, `Line::Telecom` or a line code).
+    pub fn line(mut self, line: impl Into<Line>) -> Self {
+        self.options = self.options.line(line);
+        self
+    }
+
+    /// Sets the language of the response messages, taking precedence over the language of the client.
+    pub fn lang(mut self, lang: Lang) -> Self {
+        self.options = self.options.lang(lang);
+        self
+    }
+
+    /// Sets the IP address of the end user on whose behalf the request is made, used for auditing.
+    pub fn user_client_ip(mut self, user_client_ip: &str) -> Self {
+        self.options = self.options.user_client_ip(user_client_ip);
+        self
+    }
+
+    /// Replaces the optional settings of the record.
+    pub fn options(mut self, options: RecordOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the subdomain of the record.
+    pub fn rr(&self) -> &str {
+        &self.rr
+    }
+
+    /// Returns the type of the record.
+    pub fn record_type(&self) -> &str {
+        &self.record_type
+    }
+
+    /// Returns the value of the record.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the fully qualified name of the record within a domain, such as "www.example.com".
+    pub(crate) fn fqdn(&self, domain_name: &str) -> String {
+        fqdn(&self.rr, domain_name)
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        params.insert("RR", self.rr.clone());
+        params.insert("Type", self.record_type.clone());
+        params.insert("Value", self.value.clone());
+        self.options.append_params(params);
+    }
+}
+
+/// Describes an existing record, with its TTL, line, status, and priority if it is an MX record.
+impl From<&DomainRecord> for RecordSpec {
+    fn from(record: &DomainRecord) -> Self {
+        let value = if record.record_type.eq_ignore_ascii_case("TXT") {
+            split_txt(&record.txt_value())
+        } else {
+            record.value.clone()
+        };
+        let mut spec = RecordSpec::new(&record.rr, &record.record_type, &value)
+            .ttl(record.ttl)
+            .line(record.line.as_str());
+        if let Some(priority) = record
+            .priority
+            .filter(|_| record.record_type.eq_ignore_ascii_case("MX"))
+        {
+            spec = spec.priority(priority);
+        }
+        spec.status = Some(record.status);
+        spec
+    }
+}
+
+/// Returns the fully qualified name of a subdomain, such as "www.example.com", or the domain itself for "@".
+pub(crate) fn fqdn(rr: &str, domain_name: &str) -> String {
+    if rr == "@" {
+        domain_name.to_string()
+    } else {
+        format!("{}.{}", rr, domain_name)
+    }
+}
+
+impl AliyunDns {
+    /// Adds a new domain record described by a `RecordSpec`.
+    ///
+    /// The record is checked locally before it is sent. A conflict with the existing records, such
+    /// as a CNAME record next to records of other types, is reported by the API; use
+    /// `check_cname_conflict` to report it beforehand.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the record should be added.
+    /// * `spec` - The name, type, value and optional settings of the record.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `RecordResponse` if the operation is successful, or an error if the record is invalid or the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, Line, RecordResponse, RecordSpec};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let spec = RecordSpec::a("www", "192.0.2.1").ttl(600).line(Line::Unicom);
+    /// let result: Result<RecordResponse, _> = aliyun_dns.add_record("example.com", &spec).await;
+    /// # }
+    /// ```
+    pub async fn add_record(&self, domain_name: &str, spec: &RecordSpec) -> Result<RecordResponse> {
+        spec.validate()?;
+        let action = "AddDomainRecord";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        spec.append_params(&mut params);
+
+        self.send_request(action, params).await
+    }
+
+    /// Updates a domain record with the name, type, value and settings of a `RecordSpec`.
+    ///
+    /// # Arguments
+    ///
+    /// * `record_id` - The ID of the domain record to be updated.
+    /// * `spec` - The updated name, type, value and optional settings of the record.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `RecordResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordResponse, RecordSpec};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let spec = RecordSpec::cname("www", "example.net").ttl(60);
+    /// let result: Result<RecordResponse, _> = aliyun_dns.update_record("record_id", &spec).await;
+    /// # }
+    /// ```
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        spec: &RecordSpec,
+    ) -> Result<RecordResponse> {
+        spec.validate()?;
+        let action = "UpdateDomainRecord";
+        let mut params = HashMap::new();
+        params.insert("RecordId", record_id.to_string());
+        spec.append_params(&mut params);
+
+        self.send_request(action, params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_spec_params() {
+        let spec = RecordSpec::mx("@", "mail.example.com", 10)
+            .ttl(600)
+            .line(Line::Unicom);
+        let mut params = HashMap::new();
+        spec.append_params(&mut params);
+        assert_eq!(params["RR"], "@");
+        assert_eq!(params["Type"], "MX");
+        assert_eq!(params["Value"], "mail.example.com");
+        assert_eq!(params["Priority"], "10");
+        assert_eq!(params["TTL"], "600");
+        assert_eq!(params["Line"], "unicom");
+    }
+}
@@ -0,0 +1,121 @@
+//! Host record renames, which move every record of a host record to another one.
+
+use crate::reconcile::{Change, ChangeResult};
+use crate::record_spec::fqdn;
+use crate::{AliyunDns, DomainRecord, RecordSpec, RecordStatus, Result, MAX_PAGE_SIZE};
+
+/// Returns the record to add under the new host record in place of a record.
+fn renamed_spec(record: &DomainRecord, new_rr: &str) -> RecordSpec {
+    let options = RecordSpec::from(record).options;
+    RecordSpec::new(new_rr, &record.record_type, &record.value).options(options)
+}
+
+impl AliyunDns {
+    /// Moves every record of a host record to another host record, such as to rename a service.
+    ///
+    /// The records are first added under the new host record, with their TTL, priority, line and
+    /// status, so that the name never stops resolving. If one of them cannot be added, the records
+    /// already added are deleted and the original records are left untouched. Only then are the
+    /// original records deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name of the records.
+    /// * `old_rr` - The host record to move the records from (e.g., "www").
+    /// * `new_rr` - The host record to move the records to (e.g., "web").
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `ChangeResult` of every record added and deleted, in order, or an error if the records cannot be queried or one of them cannot be added.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// for result in aliyun_dns.rename_rr("example.com", "www", "web").await? {
+    ///     println!("{}: {:?}", result.change, result.result);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rename_rr(
+        &self,
+        domain_name: &str,
+        old_rr: &str,
+        new_rr: &str,
+    ) -> Result<Vec<ChangeResult>> {
+        let response = self
+            .query_subdomain_records_paged(&fqdn(old_rr, domain_name), None, 1, MAX_PAGE_SIZE)
+            .await?;
+        let records: Vec<DomainRecord> = response
+            .domain_records
+            .records
+            .into_iter()
+            .filter(|record| record.rr.eq_ignore_ascii_case(old_rr))
+            .collect();
+
+        let mut results = Vec::new();
+        for record in &records {
+            let spec = renamed_spec(record, new_rr);
+            let mut result = self
+                .add_record(domain_name, &spec)
+                .await
+                .map(|response| response.record_id);
+            if let (Ok(record_id), RecordStatus::Disable) = (&result, &record.status) {
+                if let Err(error) = self.set_domain_record_status(record_id, false).await {
+                    let _ = self.delete_domain_record(record_id).await;
+                    result = Err(error);
+                }
+            }
+            match result {
+                Ok(record_id) => results.push(ChangeResult {
+                    change: Change::Create(spec),
+                    result: Ok(record_id),
+                }),
+                Err(error) => {
+                    for added in &results {
+                        if let Ok(record_id) = &added.result {
+                            let _ = self.delete_domain_record(record_id).await;
+                        }
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        for record in records {
+            let result = self
+                .delete_domain_record(&record.record_id)
+                .await
+                .map(|response| response.record_id);
+            results.push(ChangeResult {
+                change: Change::Delete(record),
+                result,
+            });
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::record;
+
+    #[test]
+    fn test_renamed_spec() {
+        let record = record("mail", "MX", "mx.example.com")
+            .line("telecom")
+            .priority(5)
+            .build();
+        let spec = renamed_spec(&record, "smtp");
+        assert_eq!(spec.rr(), "smtp");
+        assert_eq!(spec.record_type(), "MX");
+        assert_eq!(spec.value(), "mx.example.com");
+        assert_eq!(spec.options.ttl, Some(600));
+        assert_eq!(spec.options.priority, Some(5));
+    }
+}
@@ -0,0 +1,144 @@
+//! Automatic retries of failed requests, with exponential backoff and jitter.
+
+use std::time::Duration;
+
+use crate::AliyunDnsError;
+
+/// The action prefixes of read-only requests, which are safe to send again after an uncertain failure.
+const IDEMPOTENT_ACTION_PREFIXES: [&str; 5] = ["Describe", "Get", "List", "Query", "Preview"];
+
+/// How failed requests are retried.
+///
+/// Throttled requests (`Throttling.*` error codes) and requests that could not connect are retried for every action.
+/// Network errors, timeouts and 5xx responses are only retried for read-only actions, since a write may already have been applied.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{AliyunDns, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let aliyun_dns = AliyunDns::builder()
+///     .credentials("your_access_key_id", "your_access_key_secret")
+///     .retry_policy(RetryPolicy::new(5).base_delay(Duration::from_millis(500)))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// Makes up to 3 attempts, waiting up to 200 ms before the second one and up to 400 ms before the third.
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy making at most `max_attempts` attempts per request, the first one included.
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a policy that never retries.
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the longest wait before the first retry. The longest wait doubles with every further retry.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the longest wait before any retry.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Returns the maximum number of attempts per request.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns how long to wait after the given failed attempt, picked at random up to the backoff ("full jitter").
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        backoff.mul_f64(rand::random::<f64>())
+    }
+
+    /// Returns whether a request for the given action should be sent again after failing with the given error.
+    pub(crate) fn should_retry(&self, action: &str, attempt: u32, error: &AliyunDnsError) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        let idempotent = IDEMPOTENT_ACTION_PREFIXES
+            .iter()
+            .any(|prefix| action.starts_with(prefix));
+        match error {
+            AliyunDnsError::Api { status, .. } => {
+                error.is_throttling() || (idempotent && *status >= 500)
+            }
+            AliyunDnsError::Status { status, .. } => idempotent && *status >= 500,
+            AliyunDnsError::Http(error) => {
+                error.is_connect()
+                    || (idempotent && (error.is_timeout() || error.is_request() || error.is_body()))
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(code: &str, status: u16) -> AliyunDnsError {
+        AliyunDnsError::Api {
+            code: code.to_string(),
+            message: String::new(),
+            request_id: "request_id".to_string(),
+            status,
+        }
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy::new(3);
+        let throttled = api_error("Throttling.User", 400);
+        let unavailable = api_error("ServiceUnavailable", 503);
+        let duplicate = api_error("DomainRecordDuplicate", 400);
+
+        assert!(policy.should_retry("AddDomainRecord", 1, &throttled));
+        assert!(!policy.should_retry("AddDomainRecord", 3, &throttled));
+        assert!(policy.should_retry("DescribeDomainRecords", 2, &unavailable));
+        assert!(!policy.should_retry("AddDomainRecord", 1, &unavailable));
+        assert!(!policy.should_retry("DescribeDomainRecords", 1, &duplicate));
+    }
+
+    #[test]
+    fn test_delay() {
+        let policy = RetryPolicy::new(5)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300));
+
+        assert!(policy.delay(1) <= Duration::from_millis(100));
+        assert!(policy.delay(2) <= Duration::from_millis(200));
+        assert!(policy.delay(4) <= Duration::from_millis(300));
+    }
+}
@@ -0,0 +1,203 @@
+//! Weighted round-robin (DNS SLB) APIs, used to balance traffic between the records of a subdomain.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, Result};
+
+/// A struct representing the response for enabling or disabling weighted round-robin on a subdomain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetDnsSlbStatusResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(
+        rename = "RecordCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub record_count: u64,
+    #[serde(rename = "Open", default)]
+    pub open: bool,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for updating the weight of a record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateDnsSlbWeightResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "RecordId")]
+    pub record_id: String,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the weighted round-robin status of a single resolution line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LineAlgorithm {
+    #[serde(rename = "Line")]
+    pub line: String,
+    #[serde(rename = "Open", default)]
+    pub open: bool,
+}
+
+/// A struct containing the per-line weighted round-robin statuses returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LineAlgorithms {
+    #[serde(rename = "LineAlgorithm")]
+    pub line_algorithms: Vec<LineAlgorithm>,
+}
+
+/// A struct representing a subdomain with weighted round-robin settings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SlbSubDomain {
+    #[serde(rename = "SubDomain")]
+    pub sub_domain: String,
+    #[serde(
+        rename = "RecordCount",
+        default,
+        deserialize_with = "crate::de::number"
+    )]
+    pub record_count: u64,
+    #[serde(rename = "Open", default)]
+    pub open: bool,
+    #[serde(rename = "Type", default)]
+    pub record_type: Option<String>,
+    #[serde(rename = "LineAlgorithms", default)]
+    pub line_algorithms: Option<LineAlgorithms>,
+}
+
+/// A struct containing the weighted round-robin subdomains returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SlbSubDomains {
+    #[serde(rename = "SlbSubDomain")]
+    pub slb_sub_domains: Vec<SlbSubDomain>,
+}
+
+/// A struct representing the response for querying the weighted round-robin subdomains of a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DnsSlbSubDomainsResponse {
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
+    pub total_count: u32,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "SlbSubDomains")]
+    pub slb_sub_domains: SlbSubDomains,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+impl_response_meta!(
+    SetDnsSlbStatusResponse,
+    UpdateDnsSlbWeightResponse,
+    DnsSlbSubDomainsResponse
+);
+
+impl AliyunDns {
+    /// Enables or disables weighted round-robin on a subdomain.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_domain` - The full subdomain name (e.g., "www.example.com").
+    /// * `open` - `true` to enable weighted round-robin, `false` to disable it.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `SetDnsSlbStatusResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, SetDnsSlbStatusResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<SetDnsSlbStatusResponse, _> = aliyun_dns.set_dns_slb_status("www.example.com", true).await;
+    /// # }
+    /// ```
+    pub async fn set_dns_slb_status(
+        &self,
+        sub_domain: &str,
+        open: bool,
+    ) -> Result<SetDnsSlbStatusResponse> {
+        let action = "SetDNSSLBStatus";
+        let mut params = HashMap::new();
+        params.insert("SubDomain", sub_domain.to_string());
+        params.insert("Open", open.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Updates the weight of a record of a subdomain with weighted round-robin enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `record_id` - The ID of the domain record to be updated.
+    /// * `weight` - The new weight of the record (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `UpdateDnsSlbWeightResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, UpdateDnsSlbWeightResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<UpdateDnsSlbWeightResponse, _> = aliyun_dns.update_dns_slb_weight("record_id", 80).await;
+    /// # }
+    /// ```
+    pub async fn update_dns_slb_weight(
+        &self,
+        record_id: &str,
+        weight: u32,
+    ) -> Result<UpdateDnsSlbWeightResponse> {
+        let action = "UpdateDNSSLBWeight";
+        let mut params = HashMap::new();
+        params.insert("RecordId", record_id.to_string());
+        params.insert("Weight", weight.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of the subdomains of a domain that have weighted round-robin settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the subdomains should be queried.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of subdomains per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DnsSlbSubDomainsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DnsSlbSubDomainsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DnsSlbSubDomainsResponse, _> = aliyun_dns.query_dns_slb_subdomains("example.com", 1, 20).await;
+    /// # }
+    /// ```
+    pub async fn query_dns_slb_subdomains(
+        &self,
+        domain_name: &str,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<DnsSlbSubDomainsResponse> {
+        let action = "DescribeDNSSLBSubDomains";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+}
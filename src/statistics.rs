@@ -0,0 +1,447 @@
+//! Resolution statistics APIs, used to chart the query volume of domains.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, Direction, Result, SearchMode};
+
+/// A struct representing the query count of a single point in time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Statistic {
+    /// The point in time, in milliseconds since the Unix epoch.
+    #[serde(rename = "Timestamp", deserialize_with = "crate::de::number")]
+    pub timestamp: i64,
+    #[serde(rename = "Count", deserialize_with = "crate::de::number")]
+    pub count: u64,
+}
+
+/// A struct containing the time series returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Statistics {
+    #[serde(rename = "Statistic")]
+    pub statistics: Vec<Statistic>,
+}
+
+/// A struct representing the response for querying the resolution statistics of a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainStatisticsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Statistics")]
+    pub statistics: Statistics,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the total query count of a domain over a time range.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainStatistic {
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(rename = "Count", deserialize_with = "crate::de::number")]
+    pub count: u64,
+    #[serde(rename = "DomainType", default)]
+    pub domain_type: Option<String>,
+}
+
+/// A struct containing the per-domain query counts returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainStatistics {
+    #[serde(rename = "Statistic")]
+    pub statistics: Vec<DomainStatistic>,
+}
+
+/// A struct representing the response for querying the resolution statistics summary of the account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainStatisticsSummaryResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "Statistics")]
+    pub statistics: DomainStatistics,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the total query count of a domain in the account-wide resolve summary.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainResolveStatistic {
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    /// The query count, which this API returns as a string.
+    #[serde(rename = "Count", deserialize_with = "crate::de::number")]
+    pub count: u64,
+    /// Either `PUBLIC` for authoritative domains or `CACHE` for cache-accelerated domains.
+    #[serde(rename = "DomainType", default)]
+    pub domain_type: Option<String>,
+    #[serde(rename = "ResolveAnalysisStatus", default)]
+    pub resolve_analysis_status: Option<String>,
+}
+
+/// A struct representing the response for querying the account-wide resolve statistics summary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainResolveStatisticsSummaryResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "Statistics", default)]
+    pub statistics: Vec<DomainResolveStatistic>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the response for querying the resolution statistics of a subdomain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordStatisticsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Statistics")]
+    pub statistics: Statistics,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing the total query count of a subdomain over a time range.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecordStatistic {
+    #[serde(rename = "SubDomain")]
+    pub sub_domain: String,
+    #[serde(rename = "Count", deserialize_with = "crate::de::number")]
+    pub count: u64,
+}
+
+/// A struct containing the per-subdomain query counts returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecordStatistics {
+    #[serde(rename = "Statistic")]
+    pub statistics: Vec<RecordStatistic>,
+}
+
+/// A struct representing the response for querying the resolution statistics summary of a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordStatisticsSummaryResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalItems", deserialize_with = "crate::de::number")]
+    pub total_items: u32,
+    #[serde(rename = "TotalPages", deserialize_with = "crate::de::number")]
+    pub total_pages: u32,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "Statistics")]
+    pub statistics: RecordStatistics,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Options for querying a statistics summary, used to select the time range, filter and sort the results.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{Direction, StatisticsSummaryOptions};
+///
+/// let options = StatisticsSummaryOptions::new("2023-01-01")
+///     .end_date("2023-01-31")
+///     .direction(Direction::Desc)
+///     .page_size(100);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StatisticsSummaryOptions {
+    start_date: String,
+    end_date: Option<String>,
+    page_number: Option<u32>,
+    page_size: Option<u32>,
+    key_word: Option<String>,
+    search_mode: Option<SearchMode>,
+    threshold: Option<u64>,
+    direction: Option<Direction>,
+}
+
+impl StatisticsSummaryOptions {
+    /// Creates a set of options for the time range starting on the given day, in the `YYYY-MM-DD` format.
+    pub fn new(start_date: &str) -> Self {
+        StatisticsSummaryOptions {
+            start_date: start_date.to_string(),
+            end_date: None,
+            page_number: None,
+            page_size: None,
+            key_word: None,
+            search_mode: None,
+            threshold: None,
+            direction: None,
+        }
+    }
+
+    /// Sets the last day of the time range, in the `YYYY-MM-DD` format. Defaults to today.
+    pub fn end_date(mut self, end_date: &str) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    /// Sets the page number to fetch, starting from 1.
+    pub fn page_number(mut self, page_number: u32) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the number of entries per page (1 to 100).
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets a keyword matched against the names in the summary.
+    pub fn key_word(mut self, key_word: &str) -> Self {
+        self.key_word = Some(key_word.to_string());
+        self
+    }
+
+    /// Sets how the keyword is matched. Only `SearchMode::Like` and `SearchMode::Exact` are supported.
+    pub fn search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.search_mode = Some(search_mode);
+        self
+    }
+
+    /// Only returns the entries whose query count is at most the given threshold.
+    pub fn threshold(mut self, threshold: u64) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the direction in which the entries are sorted by query count.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    fn append_params(&self, params: &mut HashMap<&str, String>) {
+        params.insert("StartDate", self.start_date.clone());
+        if let Some(end_date) = &self.end_date {
+            params.insert("EndDate", end_date.clone());
+        }
+        if let Some(page_number) = self.page_number {
+            params.insert("PageNumber", page_number.to_string());
+        }
+        if let Some(page_size) = self.page_size {
+            params.insert("PageSize", page_size.to_string());
+        }
+        if let Some(key_word) = &self.key_word {
+            params.insert("Keyword", key_word.clone());
+        }
+        if let Some(search_mode) = self.search_mode {
+            params.insert("SearchMode", search_mode.as_str().to_string());
+        }
+        if let Some(threshold) = self.threshold {
+            params.insert("Threshold", threshold.to_string());
+        }
+        if let Some(direction) = self.direction {
+            params.insert("Direction", direction.as_str().to_string());
+        }
+    }
+}
+
+impl_response_meta!(
+    DomainStatisticsResponse,
+    DomainStatisticsSummaryResponse,
+    DomainResolveStatisticsSummaryResponse,
+    RecordStatisticsResponse,
+    RecordStatisticsSummaryResponse
+);
+
+impl_timestamps! {
+    Statistic { time => timestamp }
+}
+
+impl AliyunDns {
+    /// Queries the resolution statistics of a domain as a time series.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the statistics should be queried.
+    /// * `start_date` - The first day of the time range, in the `YYYY-MM-DD` format.
+    /// * `end_date` - The last day of the time range, in the `YYYY-MM-DD` format, or `None` for today.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainStatisticsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainStatisticsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<DomainStatisticsResponse, _> = aliyun_dns.query_domain_statistics("example.com", "2023-01-01", Some("2023-01-07")).await;
+    /// # }
+    /// ```
+    pub async fn query_domain_statistics(
+        &self,
+        domain_name: &str,
+        start_date: &str,
+        end_date: Option<&str>,
+    ) -> Result<DomainStatisticsResponse> {
+        let action = "DescribeDomainStatistics";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("StartDate", start_date.to_string());
+        if let Some(end_date) = end_date {
+            params.insert("EndDate", end_date.to_string());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Queries the total resolution count of every domain on the account over a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The time range, paging, filter and sort options applied to the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainStatisticsSummaryResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, DomainStatisticsSummaryResponse, StatisticsSummaryOptions};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = StatisticsSummaryOptions::new("2023-01-01").end_date("2023-01-31");
+    /// let result: Result<DomainStatisticsSummaryResponse, _> = aliyun_dns.query_domain_statistics_summary(&options).await;
+    /// # }
+    /// ```
+    pub async fn query_domain_statistics_summary(
+        &self,
+        options: &StatisticsSummaryOptions,
+    ) -> Result<DomainStatisticsSummaryResponse> {
+        let action = "DescribeDomainStatisticsSummary";
+        let mut params = HashMap::new();
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+
+    /// Queries the total resolve count of every domain on the account over a time range, including cache-accelerated domains.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The time range, paging, filter and sort options applied to the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DomainResolveStatisticsSummaryResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, Direction, DomainResolveStatisticsSummaryResponse, StatisticsSummaryOptions};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = StatisticsSummaryOptions::new("2023-01-01").direction(Direction::Desc).page_size(100);
+    /// let result: Result<DomainResolveStatisticsSummaryResponse, _> = aliyun_dns.query_domain_resolve_statistics_summary(&options).await;
+    /// # }
+    /// ```
+    pub async fn query_domain_resolve_statistics_summary(
+        &self,
+        options: &StatisticsSummaryOptions,
+    ) -> Result<DomainResolveStatisticsSummaryResponse> {
+        let action = "DescribeDomainResolveStatisticsSummary";
+        let mut params = HashMap::new();
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+
+    /// Queries the resolution statistics of a subdomain as a time series.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the subdomain belongs to.
+    /// * `rr` - The subdomain prefix (e.g., "www" for "www.example.com").
+    /// * `start_date` - The first day of the time range, in the `YYYY-MM-DD` format.
+    /// * `end_date` - The last day of the time range, in the `YYYY-MM-DD` format, or `None` for today.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `RecordStatisticsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordStatisticsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<RecordStatisticsResponse, _> = aliyun_dns.query_record_statistics("example.com", "www", "2023-01-01", None).await;
+    /// # }
+    /// ```
+    pub async fn query_record_statistics(
+        &self,
+        domain_name: &str,
+        rr: &str,
+        start_date: &str,
+        end_date: Option<&str>,
+    ) -> Result<RecordStatisticsResponse> {
+        let action = "DescribeRecordStatistics";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        params.insert("Rr", rr.to_string());
+        params.insert("StartDate", start_date.to_string());
+        if let Some(end_date) = end_date {
+            params.insert("EndDate", end_date.to_string());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Queries the total resolution count of every subdomain of a domain over a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name for which the statistics should be queried.
+    /// * `options` - The time range, paging, filter and sort options applied to the query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `RecordStatisticsSummaryResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordStatisticsSummaryResponse, StatisticsSummaryOptions};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let options = StatisticsSummaryOptions::new("2023-01-01").threshold(0);
+    /// let result: Result<RecordStatisticsSummaryResponse, _> = aliyun_dns.query_record_statistics_summary("example.com", &options).await;
+    /// # }
+    /// ```
+    pub async fn query_record_statistics_summary(
+        &self,
+        domain_name: &str,
+        options: &StatisticsSummaryOptions,
+    ) -> Result<RecordStatisticsSummaryResponse> {
+        let action = "DescribeRecordStatisticsSummary";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        options.append_params(&mut params);
+        self.send_request(action, params).await
+    }
+}
@@ -0,0 +1,357 @@
+//! Credentials providers backed by STS, which issues temporary credentials for RAM roles, either to other credentials or to OIDC tokens.
+
+use chrono::Utc;
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::credentials::{env_var, parse_expiration, CredentialsCache};
+use crate::{
+    endpoint, AliyunDns, AliyunDnsError, Credentials, CredentialsFuture, CredentialsProvider,
+    Result,
+};
+
+/// The public endpoint of STS.
+const STS_ENDPOINT: &str = "sts.aliyuncs.com";
+
+/// The version of the STS API.
+const STS_API_VERSION: &str = "2015-04-01";
+
+/// The name given to role sessions, unless set otherwise.
+const DEFAULT_ROLE_SESSION_NAME: &str = "aliyun-dns";
+
+/// The lifetime of the temporary credentials, unless set otherwise.
+const DEFAULT_DURATION: Duration = Duration::from_secs(3600);
+
+/// The environment variable holding the ARN of the RAM role assumed with an OIDC token.
+pub const ENV_ROLE_ARN: &str = "ALIBABA_CLOUD_ROLE_ARN";
+/// The environment variable holding the ARN of the OIDC identity provider.
+pub const ENV_OIDC_PROVIDER_ARN: &str = "ALIBABA_CLOUD_OIDC_PROVIDER_ARN";
+/// The environment variable holding the path of the OIDC token file.
+pub const ENV_OIDC_TOKEN_FILE: &str = "ALIBABA_CLOUD_OIDC_TOKEN_FILE";
+/// The environment variable holding the name of the role sessions, if set.
+pub const ENV_ROLE_SESSION_NAME: &str = "ALIBABA_CLOUD_ROLE_SESSION_NAME";
+
+/// A provider assuming a RAM role through STS, for example to manage the DNS of another account, and assuming it again before the credentials expire.
+///
+/// The `AssumeRole` requests are signed with the credentials of another provider, such as the access key of a RAM user trusted by the role.
+///
+/// # Examples
+///
+/// ```no_run
+/// use aliyun_dns::{AliyunDns, AssumeRoleProvider, Credentials};
+/// use std::time::Duration;
+///
+/// let source = Credentials::new("your_access_key_id", "your_access_key_secret");
+/// let provider = AssumeRoleProvider::new(source, "acs:ram::123456789012****:role/dns-admin")
+///     .role_session_name("ddns")
+///     .external_id("abcd1234")
+///     .duration(Duration::from_secs(900));
+/// let aliyun_dns = AliyunDns::from_provider(provider);
+/// ```
+#[derive(Debug)]
+pub struct AssumeRoleProvider {
+    source: Arc<dyn CredentialsProvider>,
+    role_arn: String,
+    role_session_name: String,
+    duration: Duration,
+    external_id: Option<String>,
+    policy: Option<String>,
+    endpoint: String,
+    client: Client,
+    cache: CredentialsCache,
+}
+
+impl AssumeRoleProvider {
+    /// Creates a provider assuming a RAM role with the credentials of another provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The provider of the credentials the role is assumed with.
+    /// * `role_arn` - The ARN of the RAM role (e.g., "acs:ram::123456789012****:role/dns-admin").
+    pub fn new(source: impl CredentialsProvider + 'static, role_arn: &str) -> Self {
+        AssumeRoleProvider {
+            source: Arc::new(source),
+            role_arn: role_arn.to_string(),
+            role_session_name: DEFAULT_ROLE_SESSION_NAME.to_string(),
+            duration: DEFAULT_DURATION,
+            external_id: None,
+            policy: None,
+            endpoint: STS_ENDPOINT.to_string(),
+            client: Client::new(),
+            cache: CredentialsCache::default(),
+        }
+    }
+
+    /// Sets the name of the role sessions, which shows up in the audit logs of the role's account.
+    pub fn role_session_name(mut self, role_session_name: &str) -> Self {
+        self.role_session_name = role_session_name.to_string();
+        self
+    }
+
+    /// Sets the lifetime of the temporary credentials, between 15 minutes and the maximum session duration of the role.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the external ID required by the trust policy of the role.
+    pub fn external_id(mut self, external_id: &str) -> Self {
+        self.external_id = Some(external_id.to_string());
+        self
+    }
+
+    /// Sets a policy restricting the permissions of the temporary credentials further than those of the role.
+    pub fn policy(mut self, policy: &str) -> Self {
+        self.policy = Some(policy.to_string());
+        self
+    }
+
+    /// Sets the STS endpoint, as a host name or a URL, such as the VPC endpoint of a region.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = endpoint.to_string();
+        self
+    }
+
+    /// Sets the HTTP client used to call STS.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Assumes the role.
+    async fn fetch(&self) -> Result<Credentials> {
+        let sts = AliyunDns {
+            credentials: self.source.clone(),
+            endpoint: endpoint::parse(&self.endpoint)?,
+            ..AliyunDns::with_client(String::new(), String::new(), self.client.clone())
+        };
+
+        let action = "AssumeRole";
+        let mut params = HashMap::new();
+        params.insert("RoleArn", self.role_arn.clone());
+        params.insert("RoleSessionName", self.role_session_name.clone());
+        params.insert("DurationSeconds", self.duration.as_secs().to_string());
+        if let Some(external_id) = &self.external_id {
+            params.insert("ExternalId", external_id.clone());
+        }
+        if let Some(policy) = &self.policy {
+            params.insert("Policy", policy.clone());
+        }
+
+        let response: AssumeRoleResponse = sts
+            .send_versioned_request(STS_API_VERSION, action, params)
+            .await?;
+        response.credentials.into_credentials()
+    }
+}
+
+impl CredentialsProvider for AssumeRoleProvider {
+    fn credentials(&self) -> CredentialsFuture<'_> {
+        Box::pin(self.cache.get_or_refresh(|| self.fetch()))
+    }
+}
+
+/// A provider exchanging an OIDC token for the STS credentials of a RAM role, as set up by RRSA (RAM Roles for Service Accounts) for the pods of ACK clusters.
+///
+/// The token file is read again on every exchange, since the projected service account token is rotated by the kubelet.
+///
+/// # Examples
+///
+/// ```no_run
+/// use aliyun_dns::{AliyunDns, OidcProvider};
+///
+/// # fn run() -> aliyun_dns::Result<()> {
+/// // Reads ALIBABA_CLOUD_ROLE_ARN, ALIBABA_CLOUD_OIDC_PROVIDER_ARN and ALIBABA_CLOUD_OIDC_TOKEN_FILE, as injected by RRSA.
+/// let aliyun_dns = AliyunDns::from_provider(OidcProvider::from_env()?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct OidcProvider {
+    role_arn: String,
+    oidc_provider_arn: String,
+    oidc_token_file: PathBuf,
+    role_session_name: String,
+    duration: Duration,
+    policy: Option<String>,
+    endpoint: String,
+    client: Client,
+    cache: CredentialsCache,
+}
+
+impl OidcProvider {
+    /// Creates a provider exchanging the OIDC token of a file for the credentials of a RAM role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role_arn` - The ARN of the RAM role (e.g., "acs:ram::123456789012****:role/external-dns").
+    /// * `oidc_provider_arn` - The ARN of the OIDC identity provider (e.g., "acs:ram::123456789012****:oidc-provider/ack-rrsa-c123").
+    /// * `oidc_token_file` - The path of the file holding the OIDC token.
+    pub fn new(role_arn: &str, oidc_provider_arn: &str, oidc_token_file: impl AsRef<Path>) -> Self {
+        OidcProvider {
+            role_arn: role_arn.to_string(),
+            oidc_provider_arn: oidc_provider_arn.to_string(),
+            oidc_token_file: oidc_token_file.as_ref().to_path_buf(),
+            role_session_name: DEFAULT_ROLE_SESSION_NAME.to_string(),
+            duration: DEFAULT_DURATION,
+            policy: None,
+            endpoint: STS_ENDPOINT.to_string(),
+            client: Client::new(),
+            cache: CredentialsCache::default(),
+        }
+    }
+
+    /// Creates a provider from the `ALIBABA_CLOUD_ROLE_ARN`, `ALIBABA_CLOUD_OIDC_PROVIDER_ARN`, `ALIBABA_CLOUD_OIDC_TOKEN_FILE` and optional `ALIBABA_CLOUD_ROLE_SESSION_NAME` environment variables.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the provider, or an error if one of the required variables is not set.
+    pub fn from_env() -> Result<Self> {
+        let provider = OidcProvider::new(
+            &env_var(ENV_ROLE_ARN)?,
+            &env_var(ENV_OIDC_PROVIDER_ARN)?,
+            env_var(ENV_OIDC_TOKEN_FILE)?,
+        );
+        Ok(match env_var(ENV_ROLE_SESSION_NAME) {
+            Ok(role_session_name) => provider.role_session_name(&role_session_name),
+            Err(_) => provider,
+        })
+    }
+
+    /// Sets the name of the role sessions, which shows up in the audit logs of the role's account.
+    pub fn role_session_name(mut self, role_session_name: &str) -> Self {
+        self.role_session_name = role_session_name.to_string();
+        self
+    }
+
+    /// Sets the lifetime of the temporary credentials, between 15 minutes and the maximum session duration of the role.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets a policy restricting the permissions of the temporary credentials further than those of the role.
+    pub fn policy(mut self, policy: &str) -> Self {
+        self.policy = Some(policy.to_string());
+        self
+    }
+
+    /// Sets the STS endpoint, as a host name or a URL, such as the VPC endpoint of a region.
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = endpoint.to_string();
+        self
+    }
+
+    /// Sets the HTTP client used to call STS.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Exchanges the current OIDC token for the credentials of the role.
+    ///
+    /// The `AssumeRoleWithOIDC` action is not signed, so the parameters are sent in a form body rather than a query string that may be logged along the way.
+    async fn fetch(&self) -> Result<Credentials> {
+        let oidc_token = tokio::fs::read_to_string(&self.oidc_token_file)
+            .await
+            .map_err(|error| {
+                AliyunDnsError::Credentials(format!(
+                    "Failed to read the OIDC token file {}: {}",
+                    self.oidc_token_file.display(),
+                    error
+                ))
+            })?;
+
+        let mut params = HashMap::new();
+        params.insert("Action", "AssumeRoleWithOIDC".to_string());
+        params.insert("Format", "JSON".to_string());
+        params.insert("Version", STS_API_VERSION.to_string());
+        params.insert(
+            "Timestamp",
+            Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        );
+        params.insert("RoleArn", self.role_arn.clone());
+        params.insert("OIDCProviderArn", self.oidc_provider_arn.clone());
+        params.insert("OIDCToken", oidc_token.trim().to_string());
+        params.insert("RoleSessionName", self.role_session_name.clone());
+        params.insert("DurationSeconds", self.duration.as_secs().to_string());
+        if let Some(policy) = &self.policy {
+            params.insert("Policy", policy.clone());
+        }
+
+        let url = endpoint::parse(&self.endpoint)?;
+        let response = self.client.post(url).form(&params).send().await?;
+        let response: AssumeRoleResponse = AliyunDns::handle_response(response).await?;
+        response.credentials.into_credentials()
+    }
+}
+
+impl CredentialsProvider for OidcProvider {
+    fn credentials(&self) -> CredentialsFuture<'_> {
+        Box::pin(self.cache.get_or_refresh(|| self.fetch()))
+    }
+}
+
+/// A response from the `AssumeRole` and `AssumeRoleWithOIDC` actions.
+#[derive(Debug, Deserialize)]
+struct AssumeRoleResponse {
+    #[serde(rename = "Credentials")]
+    credentials: StsCredentials,
+}
+
+/// Temporary credentials issued by STS.
+#[derive(Debug, Deserialize)]
+struct StsCredentials {
+    #[serde(rename = "AccessKeyId")]
+    access_key_id: String,
+    #[serde(rename = "AccessKeySecret")]
+    access_key_secret: String,
+    #[serde(rename = "SecurityToken")]
+    security_token: String,
+    #[serde(rename = "Expiration")]
+    expiration: String,
+}
+
+impl StsCredentials {
+    /// Converts the issued credentials.
+    fn into_credentials(self) -> Result<Credentials> {
+        Ok(
+            Credentials::new(&self.access_key_id, &self.access_key_secret)
+                .security_token(&self.security_token)
+                .expiration(parse_expiration(&self.expiration)?),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assume_role_response() {
+        let response: AssumeRoleResponse = serde_json::from_str(
+            r#"{
+                "RequestId": "6894B13B-6D71-4EF5-88FA-F32781734A7F",
+                "AssumedRoleUser": {
+                    "Arn": "acs:ram::123456789012****:role/dns-admin/ddns",
+                    "AssumedRoleId": "34458433936495****:ddns"
+                },
+                "Credentials": {
+                    "SecurityToken": "token",
+                    "AccessKeyId": "STS.id",
+                    "AccessKeySecret": "secret",
+                    "Expiration": "2026-10-16T09:00:00Z"
+                }
+            }"#,
+        )
+        .unwrap();
+        let credentials = response.credentials.into_credentials().unwrap();
+        assert_eq!(credentials.access_key_id(), "STS.id");
+        assert_eq!(credentials.get_security_token(), Some("token"));
+        assert!(credentials.get_expiration().is_some());
+    }
+}
@@ -0,0 +1,274 @@
+//! Resource tagging APIs, used to label domains and find domains by label.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, BasicResponse, Result};
+
+/// The only resource type that can be tagged through the Alidns API.
+const DOMAIN_RESOURCE_TYPE: &str = "DOMAIN";
+
+/// A key-value tag attached to a domain.
+#[derive(Debug, Clone)]
+pub struct Tag {
+    pub key: String,
+    pub value: String,
+}
+
+impl Tag {
+    /// Creates a new tag from its key and value.
+    pub fn new(key: &str, value: &str) -> Self {
+        Tag {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+/// A struct representing a tag attached to a resource.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TagResource {
+    #[serde(rename = "ResourceType")]
+    pub resource_type: String,
+    /// The ID of the tagged resource, which is the domain name for domains.
+    #[serde(rename = "ResourceId")]
+    pub resource_id: String,
+    #[serde(rename = "TagKey")]
+    pub tag_key: String,
+    #[serde(rename = "TagValue")]
+    pub tag_value: String,
+}
+
+/// A struct representing the response for querying the tags of resources.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagResourcesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// The token used to fetch the next page, or `None` on the last page.
+    #[serde(rename = "NextToken", default)]
+    pub next_token: Option<String>,
+    #[serde(rename = "TagResources", default)]
+    pub tag_resources: Vec<TagResource>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// Builds the indexed `ResourceId.N` parameters, which need owned keys.
+fn resource_id_params(resource_ids: &[&str]) -> Vec<(String, String)> {
+    resource_ids
+        .iter()
+        .enumerate()
+        .map(|(index, id)| (format!("ResourceId.{}", index + 1), id.to_string()))
+        .collect()
+}
+
+/// Builds the indexed `Tag.N.Key` and `Tag.N.Value` parameters, which need owned keys.
+fn tag_params(tags: &[Tag]) -> Vec<(String, String)> {
+    tags.iter()
+        .enumerate()
+        .flat_map(|(index, tag)| {
+            vec![
+                (format!("Tag.{}.Key", index + 1), tag.key.clone()),
+                (format!("Tag.{}.Value", index + 1), tag.value.clone()),
+            ]
+        })
+        .collect()
+}
+
+impl_response_meta!(TagResourcesResponse);
+
+impl AliyunDns {
+    /// Attaches tags to one or more domains.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_names` - The domain names to be tagged.
+    /// * `tags` - The tags to attach.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse, Tag};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let tags = vec![Tag::new("team", "platform"), Tag::new("cost-center", "1234")];
+    /// let result: Result<BasicResponse, _> = aliyun_dns.tag_resources(&["example.com"], &tags).await;
+    /// # }
+    /// ```
+    pub async fn tag_resources(
+        &self,
+        domain_names: &[&str],
+        tags: &[Tag],
+    ) -> Result<BasicResponse> {
+        let action = "TagResources";
+        let indexed_params: Vec<(String, String)> = resource_id_params(domain_names)
+            .into_iter()
+            .chain(tag_params(tags))
+            .collect();
+        let mut params = HashMap::new();
+        params.insert("ResourceType", DOMAIN_RESOURCE_TYPE.to_string());
+        for (key, value) in &indexed_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Removes tags from one or more domains.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_names` - The domain names to be untagged.
+    /// * `tag_keys` - The keys of the tags to remove. Ignored when `all` is `true`.
+    /// * `all` - Whether to remove every tag of the domains.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BasicResponse, _> = aliyun_dns.untag_resources(&["example.com"], &["team"], false).await;
+    /// # }
+    /// ```
+    pub async fn untag_resources(
+        &self,
+        domain_names: &[&str],
+        tag_keys: &[&str],
+        all: bool,
+    ) -> Result<BasicResponse> {
+        let action = "UntagResources";
+        let indexed_params: Vec<(String, String)> = resource_id_params(domain_names)
+            .into_iter()
+            .chain(
+                tag_keys
+                    .iter()
+                    .enumerate()
+                    .map(|(index, key)| (format!("TagKey.{}", index + 1), key.to_string())),
+            )
+            .collect();
+        let mut params = HashMap::new();
+        params.insert("ResourceType", DOMAIN_RESOURCE_TYPE.to_string());
+        params.insert("All", all.to_string());
+        for (key, value) in &indexed_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Queries the tags of domains, filtered by domain name and/or tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_names` - The domain names whose tags should be returned, or an empty slice for all domains.
+    /// * `tags` - The tags the domains must carry, or an empty slice for any tag.
+    /// * `next_token` - The token returned by the previous page, or `None` for the first page.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `TagResourcesResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, Tag, TagResourcesResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let tags = vec![Tag::new("team", "platform")];
+    /// let result: Result<TagResourcesResponse, _> = aliyun_dns.list_tag_resources(&[], &tags, None).await;
+    /// # }
+    /// ```
+    pub async fn list_tag_resources(
+        &self,
+        domain_names: &[&str],
+        tags: &[Tag],
+        next_token: Option<&str>,
+    ) -> Result<TagResourcesResponse> {
+        let action = "ListTagResources";
+        let indexed_params: Vec<(String, String)> = resource_id_params(domain_names)
+            .into_iter()
+            .chain(tag_params(tags))
+            .collect();
+        let mut params = HashMap::new();
+        params.insert("ResourceType", DOMAIN_RESOURCE_TYPE.to_string());
+        if let Some(next_token) = next_token {
+            params.insert("NextToken", next_token.to_string());
+        }
+        for (key, value) in &indexed_params {
+            params.insert(key.as_str(), value.clone());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Queries the tags of domains, filtered by domain name and/or tag, fetching every page.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_names` - The domain names whose tags should be returned, or an empty slice for all domains.
+    /// * `tags` - The tags the domains must carry, or an empty slice for any tag.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing every matching `TagResource` if the operation is successful, or an error if any page request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, Tag, TagResource};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let tags = vec![Tag::new("team", "platform")];
+    /// let result: Result<Vec<TagResource>, _> = aliyun_dns.list_all_tag_resources(&[], &tags).await;
+    /// # }
+    /// ```
+    pub async fn list_all_tag_resources(
+        &self,
+        domain_names: &[&str],
+        tags: &[Tag],
+    ) -> Result<Vec<TagResource>> {
+        let mut tag_resources = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let response = self
+                .list_tag_resources(domain_names, tags, next_token.as_deref())
+                .await?;
+            tag_resources.extend(response.tag_resources);
+            match response.next_token {
+                Some(token) if !token.is_empty() => next_token = Some(token),
+                _ => return Ok(tag_resources),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_params() {
+        let tags = vec![Tag::new("team", "platform"), Tag::new("env", "prod")];
+        let params: HashMap<String, String> = resource_id_params(&["example.com"])
+            .into_iter()
+            .chain(tag_params(&tags))
+            .collect();
+
+        assert_eq!(params.len(), 5);
+        assert_eq!(params["ResourceId.1"], "example.com");
+        assert_eq!(params["Tag.1.Key"], "team");
+        assert_eq!(params["Tag.1.Value"], "platform");
+        assert_eq!(params["Tag.2.Key"], "env");
+        assert_eq!(params["Tag.2.Value"], "prod");
+    }
+}
@@ -0,0 +1,172 @@
+//! A local HTTP server standing in for the API in tests, which answers the record queries.
+
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::{AliyunDns, DomainRecord, RequestMethod};
+
+/// The actions requested from a test server, in order.
+pub(crate) type Actions = Arc<Mutex<Vec<String>>>;
+
+/// Returns the action of a request, from the query string of its request line.
+fn action(request: &str) -> String {
+    let target = request.split_whitespace().nth(1).unwrap_or_default();
+    url::Url::parse(&format!("http://localhost{}", target))
+        .ok()
+        .and_then(|url| {
+            url.query_pairs()
+                .find(|(name, _)| name == "Action")
+                .map(|(_, action)| action.into_owned())
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the body of a `DescribeDomainRecords` response listing `records`.
+pub(crate) fn records_body(records: &[RecordFixture]) -> String {
+    let records: Vec<Value> = records.iter().map(RecordFixture::json).collect();
+    json!({
+        "TotalCount": records.len(),
+        "RequestId": "test",
+        "PageNumber": 1,
+        "PageSize": 500,
+        "DomainRecords": { "Record": records },
+    })
+    .to_string()
+}
+
+/// Starts a server answering every request with the HTTP status and body `respond` returns for
+/// its action, and returns a client sending its requests to it with the actions it receives.
+pub(crate) async fn client<F>(respond: F) -> (AliyunDns, Actions)
+where
+    F: Fn(&str) -> (u16, String) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let actions = Actions::default();
+
+    let received = actions.clone();
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut request = Vec::new();
+            let mut buffer = [0; 4096];
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                match stream.read(&mut buffer).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => request.extend_from_slice(&buffer[..read]),
+                }
+            }
+            let action = action(&String::from_utf8_lossy(&request));
+            let (status, body) = respond(&action);
+            received.lock().unwrap().push(action);
+            let response = format!(
+                "HTTP/1.1 {} OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    let aliyun_dns = AliyunDns::builder()
+        .credentials("test", "test")
+        .endpoint(&format!("http://{}", address))
+        .request_method(RequestMethod::Get)
+        .http_client(reqwest::Client::builder().no_proxy().build().unwrap())
+        .build()
+        .unwrap();
+    (aliyun_dns, actions)
+}
+
+/// Starts a server answering every request with a `DescribeDomainRecords` response listing
+/// `records`, and returns a dry-run client sending its requests to it with the actions it receives.
+pub(crate) async fn dry_run_client(records: Vec<RecordFixture>) -> (AliyunDns, Actions) {
+    let body = records_body(&records);
+    let (aliyun_dns, actions) = client(move |_| (200, body.clone())).await;
+    (aliyun_dns.with_dry_run(true), actions)
+}
+
+/// A record as the API lists it, built by `record` and adjusted by the tests that need other settings.
+#[derive(Debug, Clone)]
+pub(crate) struct RecordFixture {
+    record_id: String,
+    rr: String,
+    record_type: String,
+    value: String,
+    line: String,
+    status: String,
+    ttl: u32,
+    priority: Option<u32>,
+}
+
+/// Returns a record as the API lists it, enabled on the default line with a TTL of 600 seconds.
+pub(crate) fn record(rr: &str, record_type: &str, value: &str) -> RecordFixture {
+    RecordFixture {
+        record_id: "1".to_string(),
+        rr: rr.to_string(),
+        record_type: record_type.to_string(),
+        value: value.to_string(),
+        line: "default".to_string(),
+        status: "ENABLE".to_string(),
+        ttl: 600,
+        priority: None,
+    }
+}
+
+impl RecordFixture {
+    /// Sets the ID of the record.
+    pub(crate) fn id(mut self, record_id: &str) -> Self {
+        self.record_id = record_id.to_string();
+        self
+    }
+
+    /// Sets the line of the record, as the API names it (e.g., "telecom").
+    pub(crate) fn line(mut self, line: &str) -> Self {
+        self.line = line.to_string();
+        self
+    }
+
+    /// Sets the status of the record, as the API names it (e.g., "DISABLE").
+    pub(crate) fn status(mut self, status: &str) -> Self {
+        self.status = status.to_string();
+        self
+    }
+
+    /// Sets the TTL of the record.
+    pub(crate) fn ttl(mut self, ttl: u32) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the priority of the record.
+    pub(crate) fn priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Returns the record as the API lists it.
+    pub(crate) fn json(&self) -> Value {
+        let mut record = json!({
+            "RR": self.rr,
+            "Line": self.line,
+            "Status": self.status,
+            "Locked": false,
+            "Type": self.record_type,
+            "DomainName": "example.com",
+            "Value": self.value,
+            "RecordId": self.record_id,
+            "TTL": self.ttl,
+        });
+        if let Some(priority) = self.priority {
+            record["Priority"] = json!(priority);
+        }
+        record
+    }
+
+    /// Returns the record as the client deserializes it.
+    pub(crate) fn build(&self) -> DomainRecord {
+        serde_json::from_value(self.json()).unwrap()
+    }
+}
@@ -0,0 +1,64 @@
+//! Conversion of the timestamps the API returns, as epoch milliseconds or as formatted strings, into `DateTime<Utc>`.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// Formats the API uses for `*Time` strings, tried in order after RFC 3339.
+const TIME_FORMATS: &[&str] = &["%Y-%m-%dT%H:%MZ", "%Y-%m-%dT%H:%M:%SZ", "%Y-%m-%d %H:%M:%S"];
+
+/// A raw timestamp field that can be read as a UTC date and time.
+pub(crate) trait AsDateTime {
+    fn as_date_time(&self) -> Option<DateTime<Utc>>;
+}
+
+impl AsDateTime for i64 {
+    fn as_date_time(&self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp_millis(*self)
+    }
+}
+
+impl AsDateTime for String {
+    fn as_date_time(&self) -> Option<DateTime<Utc>> {
+        parse_time(self)
+    }
+}
+
+impl<T: AsDateTime> AsDateTime for Option<T> {
+    fn as_date_time(&self) -> Option<DateTime<Utc>> {
+        self.as_ref().and_then(AsDateTime::as_date_time)
+    }
+}
+
+/// Parses a time string such as `2015-12-12T09:23Z`, `2015-12-12T09:23:45Z` or an RFC 3339 date and time.
+pub(crate) fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Utc));
+    }
+    TIME_FORMATS.iter().find_map(|format| {
+        NaiveDateTime::parse_from_str(value, format)
+            .ok()
+            .map(|time| time.and_utc())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_time() {
+        let expected = Utc.with_ymd_and_hms(2015, 12, 12, 9, 23, 0).unwrap();
+        assert_eq!(parse_time("2015-12-12T09:23Z"), Some(expected));
+        assert_eq!(parse_time("2015-12-12T09:23:00Z"), Some(expected));
+        assert_eq!(parse_time("2015-12-12T17:23:00+08:00"), Some(expected));
+        assert_eq!(parse_time("2015-12-12 09:23:00"), Some(expected));
+        assert_eq!(parse_time("yesterday"), None);
+        assert_eq!(
+            1_449_912_180_000i64.as_date_time(),
+            Some(expected),
+            "millisecond timestamps"
+        );
+        assert_eq!(Option::<String>::None.as_date_time(), None);
+    }
+}
@@ -0,0 +1,196 @@
+//! Domain transfer APIs, used to move domains between Alibaba Cloud accounts.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{AliyunDns, BasicResponse, Result};
+
+/// Whether to query the domains transferred to or from the account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    In,
+    Out,
+}
+
+impl TransferDirection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TransferDirection::In => "IN",
+            TransferDirection::Out => "OUT",
+        }
+    }
+}
+
+/// A struct representing the response for transferring domains to another account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferDomainResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId", deserialize_with = "crate::de::number")]
+    pub task_id: i64,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+/// A struct representing a domain transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainTransfer {
+    #[serde(rename = "Id", deserialize_with = "crate::de::number")]
+    pub id: i64,
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(rename = "FromUserId", deserialize_with = "crate::de::number")]
+    pub from_user_id: i64,
+    #[serde(rename = "TargetUserId", deserialize_with = "crate::de::number")]
+    pub target_user_id: i64,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: Option<String>,
+    #[serde(
+        rename = "CreateTimestamp",
+        default,
+        deserialize_with = "crate::de::option_number"
+    )]
+    pub create_timestamp: Option<i64>,
+}
+
+/// A struct containing the domain transfers returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DomainTransfers {
+    #[serde(rename = "DomainTransfer", default)]
+    pub domain_transfers: Vec<DomainTransfer>,
+}
+
+/// A struct representing the response for querying domain transfers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferDomainsResponse {
+    #[serde(rename = "TotalCount", deserialize_with = "crate::de::number")]
+    pub total_count: u32,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "PageNumber", deserialize_with = "crate::de::number")]
+    pub page_number: u32,
+    #[serde(rename = "PageSize", deserialize_with = "crate::de::number")]
+    pub page_size: u32,
+    #[serde(rename = "DomainTransfers")]
+    pub domain_transfers: DomainTransfers,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+impl_response_meta!(TransferDomainResponse, TransferDomainsResponse);
+
+impl_timestamps! {
+    DomainTransfer { created_at => create_timestamp }
+}
+
+impl AliyunDns {
+    /// Transfers domains, along with their records, to another Alibaba Cloud account.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_names` - The domain names to be transferred.
+    /// * `target_user_id` - The ID of the account receiving the domains.
+    /// * `remark` - An optional remark for the transfer.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `TransferDomainResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, TransferDomainResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<TransferDomainResponse, _> = aliyun_dns
+    ///     .transfer_domains(&["example.com"], 1234567890, None)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn transfer_domains(
+        &self,
+        domain_names: &[&str],
+        target_user_id: i64,
+        remark: Option<&str>,
+    ) -> Result<TransferDomainResponse> {
+        let action = "TransferDomain";
+        let mut params = HashMap::new();
+        params.insert("DomainNames", domain_names.join(","));
+        params.insert("TargetUserId", target_user_id.to_string());
+        if let Some(remark) = remark {
+            params.insert("Remark", remark.to_string());
+        }
+        self.send_request(action, params).await
+    }
+
+    /// Queries a single page of the domains transferred to or from the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - Whether to query the incoming or the outgoing transfers.
+    /// * `domain_name` - Only return the transfers of this domain, or `None` for all transfers.
+    /// * `page_number` - The page number to fetch, starting from 1.
+    /// * `page_size` - The number of transfers per page (1 to 100).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `TransferDomainsResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, TransferDirection, TransferDomainsResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<TransferDomainsResponse, _> = aliyun_dns
+    ///     .query_transfer_domains(TransferDirection::In, None, 1, 20)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn query_transfer_domains(
+        &self,
+        direction: TransferDirection,
+        domain_name: Option<&str>,
+        page_number: u32,
+        page_size: u32,
+    ) -> Result<TransferDomainsResponse> {
+        let action = "DescribeTransferDomains";
+        let mut params = HashMap::new();
+        params.insert("TransferType", direction.as_str().to_string());
+        if let Some(domain_name) = domain_name {
+            params.insert("DomainName", domain_name.to_string());
+        }
+        params.insert("PageNumber", page_number.to_string());
+        params.insert("PageSize", page_size.to_string());
+        self.send_request(action, params).await
+    }
+
+    /// Retrieves a domain that was added to another account, once its ownership has been verified with a TXT record.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `BasicResponse` if the operation is successful, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, BasicResponse};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<BasicResponse, _> = aliyun_dns.retrieve_domain("example.com").await;
+    /// # }
+    /// ```
+    pub async fn retrieve_domain(&self, domain_name: &str) -> Result<BasicResponse> {
+        let action = "RetrieveDomain";
+        let mut params = HashMap::new();
+        params.insert("DomainName", domain_name.to_string());
+        self.send_request(action, params).await
+    }
+}
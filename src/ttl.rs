@@ -0,0 +1,133 @@
+//! Record TTLs, validated against the range the API accepts and the minimum TTL of the domain's plan.
+
+use std::fmt;
+
+use crate::{AliyunDns, AliyunDnsError, Result};
+
+/// The TTL of a record, in seconds, within the range the API accepts.
+///
+/// The plan of a domain may raise the minimum TTL, for example to 600 seconds on the free plan;
+/// `AliyunDns::check_ttl` also checks the TTL against the plan.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{RecordOptions, Ttl};
+///
+/// let ttl = Ttl::new(600).unwrap();
+/// let options = RecordOptions::new().ttl(ttl.seconds());
+/// assert!(Ttl::new(0).is_err());
+/// assert!(Ttl::new(600).unwrap().at_least(60).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ttl(u32);
+
+impl Ttl {
+    /// The lowest TTL accepted by the API, available on the plans with the shortest minimum TTL.
+    pub const MIN: Ttl = Ttl(1);
+
+    /// The highest TTL accepted by the API: one day.
+    pub const MAX: Ttl = Ttl(86400);
+
+    /// Creates a TTL, or returns an error if it is outside of the range the API accepts.
+    pub fn new(seconds: u32) -> Result<Self> {
+        if (Self::MIN.0..=Self::MAX.0).contains(&seconds) {
+            Ok(Ttl(seconds))
+        } else {
+            Err(AliyunDnsError::InvalidInput(format!(
+                "The TTL {} is outside of the accepted range of {} to {} seconds",
+                seconds,
+                Self::MIN,
+                Self::MAX
+            )))
+        }
+    }
+
+    /// Returns the TTL in seconds.
+    pub fn seconds(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the TTL, or an error if it is below the minimum TTL of a domain's plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_ttl` - The minimum TTL of the plan, as returned in the `min_ttl` of `get_domain_info`.
+    pub fn at_least(self, min_ttl: u32) -> Result<Self> {
+        if self.0 >= min_ttl {
+            Ok(self)
+        } else {
+            Err(AliyunDnsError::InvalidInput(format!(
+                "The TTL {} is below the minimum TTL of {} seconds of the domain's plan",
+                self.0, min_ttl
+            )))
+        }
+    }
+}
+
+impl TryFrom<u32> for Ttl {
+    type Error = AliyunDnsError;
+
+    fn try_from(seconds: u32) -> Result<Self> {
+        Ttl::new(seconds)
+    }
+}
+
+impl From<Ttl> for u32 {
+    fn from(ttl: Ttl) -> Self {
+        ttl.0
+    }
+}
+
+impl fmt::Display for Ttl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AliyunDns {
+    /// Checks a TTL against the range the API accepts and the minimum TTL of the domain's plan, fetched with `get_domain_info`.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the record belongs to.
+    /// * `seconds` - The TTL to check, in seconds.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Ttl` if it is valid for the domain, or an error if it is not or the domain information cannot be fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, Ttl};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<Ttl, _> = aliyun_dns.check_ttl("example.com", 60).await;
+    /// # }
+    /// ```
+    pub async fn check_ttl(&self, domain_name: &str, seconds: u32) -> Result<Ttl> {
+        let ttl = Ttl::new(seconds)?;
+        let domain_info = self.get_domain_info(domain_name, false).await?;
+        match domain_info.min_ttl {
+            Some(min_ttl) => ttl.at_least(min_ttl),
+            None => Ok(ttl),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ttl() {
+        assert!(Ttl::new(0).is_err());
+        assert!(Ttl::new(86401).is_err());
+        assert_eq!(Ttl::new(86400).unwrap(), Ttl::MAX);
+        assert_eq!(Ttl::try_from(600).unwrap().seconds(), 600);
+        assert!(Ttl::new(60).unwrap().at_least(600).is_err());
+        assert!(Ttl::new(600).unwrap().at_least(600).is_ok());
+    }
+}
@@ -0,0 +1,132 @@
+//! TXT values longer than 255 bytes, which DNS splits into several quoted character strings.
+
+use crate::DomainRecord;
+
+/// The longest character string of a TXT record, in bytes.
+const TXT_CHUNK_LEN: usize = 255;
+
+/// Quotes a TXT value as character strings of at most 255 bytes each, without splitting characters.
+pub(crate) fn quote_chunks(text: &str) -> String {
+    let mut chunks = Vec::new();
+    let mut chunk = String::new();
+    for c in text.chars() {
+        if chunk.len() + c.len_utf8() > TXT_CHUNK_LEN {
+            chunks.push(std::mem::take(&mut chunk));
+        }
+        chunk.push(c);
+    }
+    chunks.push(chunk);
+    chunks
+        .iter()
+        .map(|chunk| format!("\"{}\"", chunk.replace('\\', "\\\\").replace('"', "\\\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the value to send for a TXT record: the text itself if it fits in a single character
+/// string, or quoted character strings of at most 255 bytes each otherwise, such as for DKIM keys.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{join_txt, split_txt};
+///
+/// assert_eq!(split_txt("v=spf1 -all"), "v=spf1 -all");
+/// let key = "p=".to_string() + &"A".repeat(400);
+/// let value = split_txt(&key);
+/// assert!(value.starts_with("\"p=AAA") && value.contains("\" \""));
+/// assert_eq!(join_txt(&value), key);
+/// ```
+pub fn split_txt(text: &str) -> String {
+    if text.len() <= TXT_CHUNK_LEN {
+        text.to_string()
+    } else {
+        quote_chunks(text)
+    }
+}
+
+/// Reassembles a TXT value made of quoted character strings, such as `"v=DKIM1; p=MIIB" "IjAN"`,
+/// into the text they form. Values that are not quoted are returned as they are.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::join_txt;
+///
+/// assert_eq!(join_txt("\"v=DKIM1; p=MIIB\" \"IjAN\""), "v=DKIM1; p=MIIBIjAN");
+/// assert_eq!(join_txt("v=spf1 -all"), "v=spf1 -all");
+/// ```
+pub fn join_txt(value: &str) -> String {
+    let trimmed = value.trim();
+    if !trimmed.starts_with('"') {
+        return value.to_string();
+    }
+    let mut text = String::new();
+    let mut chars = trimmed.chars();
+    loop {
+        match chars.next() {
+            None => return text,
+            Some(c) if c.is_whitespace() => continue,
+            Some('"') => {}
+            Some(_) => return value.to_string(),
+        }
+        loop {
+            match chars.next() {
+                None => return value.to_string(),
+                Some('"') => break,
+                Some('\\') => match chars.next() {
+                    Some(c) => text.push(c),
+                    None => return value.to_string(),
+                },
+                Some(c) => text.push(c),
+            }
+        }
+    }
+}
+
+/// Returns whether two values of a record type are the same, comparing TXT values as the text
+/// they form rather than as split into character strings, and with the `idna` feature, host names
+/// in punycode and in Unicode as the same name.
+pub(crate) fn is_same_value(record_type: &str, a: &str, b: &str) -> bool {
+    #[cfg(feature = "idna")]
+    if let Some(same) = crate::idn::is_same_host(record_type, a, b) {
+        return same;
+    }
+    if record_type.eq_ignore_ascii_case("TXT") {
+        join_txt(a) == join_txt(b)
+    } else {
+        a == b
+    }
+}
+
+impl DomainRecord {
+    /// Returns the value of the record, with the character strings of a TXT value reassembled into
+    /// the text they form.
+    pub fn txt_value(&self) -> String {
+        if self.record_type.eq_ignore_ascii_case("TXT") {
+            join_txt(&self.value)
+        } else {
+            self.value.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_join_txt() {
+        let long = "a".repeat(300);
+        assert_eq!(
+            split_txt(&long),
+            format!("\"{}\" \"{}\"", "a".repeat(255), "a".repeat(45))
+        );
+        let text = format!("v=DKIM1; \"quoted\" \\ {}", "é".repeat(200));
+        assert_eq!(join_txt(&split_txt(&text)), text);
+        assert_eq!(join_txt("\"unterminated"), "\"unterminated");
+        assert_eq!(join_txt("\"a\" b"), "\"a\" b");
+        assert!(is_same_value("TXT", "\"ab\"", "ab"));
+        assert!(!is_same_value("A", "\"ab\"", "ab"));
+    }
+}
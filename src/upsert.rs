@@ -0,0 +1,289 @@
+//! Idempotent record operations, which create, update or delete records only when needed.
+
+use crate::reconcile::Keyed;
+use crate::record_spec::fqdn;
+use crate::txt::is_same_value;
+use crate::{AliyunDns, DomainRecord, RecordSpec, Result, MAX_PAGE_SIZE};
+
+/// The action taken by `upsert_record`, with the ID of the record.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UpsertOutcome {
+    /// No matching record existed, so a new one was added.
+    Created(String),
+    /// A record existed with another value or settings, and was updated.
+    Updated(String),
+    /// A record already existed with the value and settings, so nothing was sent.
+    Unchanged(String),
+}
+
+impl UpsertOutcome {
+    /// Returns the ID of the record that was created, updated or left unchanged.
+    pub fn record_id(&self) -> &str {
+        match self {
+            UpsertOutcome::Created(record_id)
+            | UpsertOutcome::Updated(record_id)
+            | UpsertOutcome::Unchanged(record_id) => record_id,
+        }
+    }
+
+    /// Returns whether the record was created or updated.
+    pub fn is_changed(&self) -> bool {
+        !matches!(self, UpsertOutcome::Unchanged(_))
+    }
+}
+
+impl RecordSpec {
+    /// Returns whether an existing record has the value and the settings given to this record.
+    pub(crate) fn is_satisfied_by(&self, record: &DomainRecord) -> bool {
+        is_same_value(self.record_type(), &record.value, self.value())
+            && self.options.ttl.is_none_or(|ttl| record.ttl == ttl)
+            && self
+                .options
+                .priority
+                .is_none_or(|priority| record.priority == Some(priority))
+    }
+
+    /// Returns whether an existing record has the name and type of this record, on the same line.
+    pub(crate) fn has_same_name(&self, record: &DomainRecord) -> bool {
+        self.key().has_same_name(&record.key())
+    }
+
+    /// Returns this record with the TTL and priority of an existing record where it leaves them
+    /// unset, since an update without them resets the record to the defaults of the API.
+    fn with_settings_of(&self, record: &DomainRecord) -> RecordSpec {
+        let mut spec = self.clone();
+        if spec.options.ttl.is_none() {
+            spec = spec.ttl(record.ttl);
+        }
+        if let (None, Some(priority)) = (spec.options.priority, record.priority) {
+            spec = spec.priority(priority);
+        }
+        spec
+    }
+
+    /// Picks the record to keep or update among the records of the same name and type, on the same line.
+    ///
+    /// A record with another value is only picked when it is the only one, since records sharing a
+    /// name, such as MX records or the TXT records of several services, are each a value of their own.
+    fn find_match<'a>(&self, records: &'a [DomainRecord]) -> Option<&'a DomainRecord> {
+        let candidates: Vec<&DomainRecord> = records
+            .iter()
+            .filter(|record| self.has_same_name(record))
+            .collect();
+        candidates
+            .iter()
+            .find(|record| self.is_satisfied_by(record))
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .find(|record| is_same_value(self.record_type(), &record.value, self.value()))
+            })
+            .or(match candidates.as_slice() {
+                [record] => Some(record),
+                _ => None,
+            })
+            .copied()
+    }
+}
+
+impl AliyunDns {
+    /// Makes sure a record exists with the value and settings of a `RecordSpec`.
+    ///
+    /// The records of the same name and type on the same line are queried first. A record with the
+    /// value and settings is left alone, the only record with another value or settings is updated
+    /// in place, and a new record is added if there is none or several other values.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the record belongs to.
+    /// * `spec` - The name, type, value and optional settings of the record.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `UpsertOutcome` with the action taken, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordSpec, UpsertOutcome};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let spec = RecordSpec::a("home", "192.0.2.1").ttl(600);
+    /// let result: Result<UpsertOutcome, _> = aliyun_dns.upsert_record("example.com", &spec).await;
+    /// # }
+    /// ```
+    pub async fn upsert_record(
+        &self,
+        domain_name: &str,
+        spec: &RecordSpec,
+    ) -> Result<UpsertOutcome> {
+        spec.validate()?;
+        let response = self
+            .query_subdomain_records_paged(
+                &spec.fqdn(domain_name),
+                Some(spec.record_type()),
+                1,
+                MAX_PAGE_SIZE,
+            )
+            .await?;
+        let records = response.domain_records.records;
+        match spec.find_match(&records) {
+            Some(record) if spec.is_satisfied_by(record) => {
+                Ok(UpsertOutcome::Unchanged(record.record_id.clone()))
+            }
+            Some(record) => {
+                let spec = spec.with_settings_of(record);
+                let response = self.update_record(&record.record_id, &spec).await?;
+                Ok(UpsertOutcome::Updated(response.record_id))
+            }
+            None => {
+                let response = self.add_record(domain_name, spec).await?;
+                Ok(UpsertOutcome::Created(response.record_id))
+            }
+        }
+    }
+
+    /// Deletes the records of a name and type, optionally only those with a given value.
+    ///
+    /// Unlike `delete_subdomain_records`, the records of other types are kept, and no error is
+    /// returned when no record matches, which suits the cleanup of ACME TXT records.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the records belong to.
+    /// * `rr` - The subdomain of the records (e.g., "_acme-challenge", or "@" for the domain itself).
+    /// * `record_type` - The type of the records to delete (e.g., "TXT").
+    /// * `value` - The value of the records to delete, or `None` for every value.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of records deleted, or an error if the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<u32, _> = aliyun_dns
+    ///     .delete_record_by_value("example.com", "_acme-challenge", "TXT", Some("challenge_token"))
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn delete_record_by_value(
+        &self,
+        domain_name: &str,
+        rr: &str,
+        record_type: &str,
+        value: Option<&str>,
+    ) -> Result<u32> {
+        let response = self
+            .query_subdomain_records_paged(
+                &fqdn(rr, domain_name),
+                Some(record_type),
+                1,
+                MAX_PAGE_SIZE,
+            )
+            .await?;
+        let mut deleted = 0;
+        for record in &response.domain_records.records {
+            if !is_same_record(record, rr, record_type, value) {
+                continue;
+            }
+            match self.delete_domain_record(&record.record_id).await {
+                Ok(_) => deleted += 1,
+                // The record was deleted in the meantime, which is the state asked for.
+                Err(error) if error.is_not_found() => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(deleted)
+    }
+}
+
+/// Returns whether a record has a name and type, and a value if one is given, ignoring the case
+/// of the name and type and comparing TXT values as the text they form.
+pub(crate) fn is_same_record(
+    record: &DomainRecord,
+    rr: &str,
+    record_type: &str,
+    value: Option<&str>,
+) -> bool {
+    record.rr.eq_ignore_ascii_case(rr)
+        && record.record_type.eq_ignore_ascii_case(record_type)
+        && value.is_none_or(|value| is_same_value(record_type, &record.value, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{dry_run_client, record};
+    use crate::Line;
+
+    #[test]
+    fn test_find_match() {
+        let records = vec![
+            record("home", "A", "192.0.2.1")
+                .id("1")
+                .line("telecom")
+                .build(),
+            record("home", "A", "192.0.2.2").id("2").build(),
+            record("home", "A", "192.0.2.1").id("3").ttl(60).build(),
+        ];
+
+        let spec = RecordSpec::a("home", "192.0.2.1").ttl(60);
+        let found = spec.find_match(&records).unwrap();
+        assert_eq!(found.record_id, "3");
+        assert!(spec.is_satisfied_by(found));
+
+        let spec = RecordSpec::a("home", "192.0.2.1").ttl(600);
+        let found = spec.find_match(&records).unwrap();
+        assert_eq!(found.record_id, "3");
+        assert!(!spec.is_satisfied_by(found));
+
+        let spec = RecordSpec::a("home", "192.0.2.9").line(Line::Telecom);
+        assert_eq!(spec.find_match(&records).unwrap().record_id, "1");
+
+        let spec = RecordSpec::a("home", "192.0.2.1").line(Line::Unicom);
+        assert!(spec.find_match(&records).is_none());
+
+        // Several other values on the line are kept, and a new record is added next to them.
+        let spec = RecordSpec::a("home", "192.0.2.9");
+        assert!(spec.find_match(&records).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_upsert_keeps_settings() {
+        let live = record("home", "A", "192.0.2.1").ttl(60);
+        let (aliyun_dns, _) = dry_run_client(vec![live]).await;
+
+        let spec = RecordSpec::a("home", "192.0.2.2");
+        let outcome = aliyun_dns
+            .upsert_record("example.com", &spec)
+            .await
+            .unwrap();
+        assert!(matches!(outcome, UpsertOutcome::Updated(_)));
+        let requests = aliyun_dns.dry_run_requests();
+        assert_eq!(requests[0].action, "UpdateDomainRecord");
+        assert_eq!(requests[0].params["TTL"], "60");
+    }
+
+    #[test]
+    fn test_is_same_record() {
+        let record = record("home", "A", "192.0.2.1").id("1").build();
+        assert!(is_same_record(&record, "home", "a", None));
+        assert!(is_same_record(&record, "home", "A", Some("192.0.2.1")));
+        assert!(!is_same_record(&record, "home", "A", Some("192.0.2.2")));
+        assert!(!is_same_record(&record, "home", "TXT", None));
+        assert!(!is_same_record(&record, "www", "A", None));
+        assert!(is_same_record(&record, "HOME", "A", None));
+
+        let long = "v".repeat(300);
+        let mut txt = record;
+        txt.record_type = "TXT".to_string();
+        txt.value = crate::txt::quote_chunks(&long);
+        assert!(is_same_record(&txt, "home", "TXT", Some(&long)));
+    }
+}
@@ -0,0 +1,337 @@
+//! Client-side checks of record values, which catch malformed records before the API does.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::rdata::is_hostname;
+use crate::record_spec::fqdn;
+use crate::{AliyunDns, AliyunDnsError, DomainRecord, Line, RecordSpec, Result, MAX_PAGE_SIZE};
+
+/// The types whose values are host names.
+const HOST_TYPES: &[&str] = &["CNAME", "NS", "MX", "PTR", "ALIAS"];
+
+/// Returns the error for a value that does not fit its record type.
+fn invalid_value(record_type: &str, value: &str, expected: &str) -> AliyunDnsError {
+    AliyunDnsError::InvalidInput(format!(
+        "Invalid {} record value {:?}: expected {}",
+        record_type, value, expected
+    ))
+}
+
+/// Checks that a value fits its record type: an IPv4 address for A records, an IPv6 address for
+/// AAAA records, a host name for CNAME, NS, MX and PTR records, and the fields of SRV and CAA
+/// records. The values of the other types are only checked not to be empty.
+pub(crate) fn validate_value(record_type: &str, value: &str) -> Result<()> {
+    let record_type = record_type.to_uppercase();
+    let valid = match record_type.as_str() {
+        "A" => value.parse::<Ipv4Addr>().is_ok(),
+        "AAAA" => value.parse::<Ipv6Addr>().is_ok(),
+        "SRV" => {
+            let fields: Vec<&str> = value.split_whitespace().collect();
+            fields.len() == 4
+                && fields[..3].iter().all(|field| field.parse::<u16>().is_ok())
+                && (fields[3] == "." || is_hostname(fields[3]))
+        }
+        "CAA" => {
+            let mut fields = value.splitn(3, ' ');
+            fields
+                .next()
+                .is_some_and(|flags| flags.parse::<u8>().is_ok())
+                && fields.next().is_some_and(|tag| {
+                    !tag.is_empty() && tag.chars().all(|c| c.is_ascii_alphanumeric())
+                })
+                && fields.next().is_some_and(|value| !value.is_empty())
+        }
+        record_type if HOST_TYPES.contains(&record_type) => is_hostname(value),
+        _ => !value.trim().is_empty(),
+    };
+    if valid {
+        return Ok(());
+    }
+    let expected = match record_type.as_str() {
+        "A" => "an IPv4 address",
+        "AAAA" => "an IPv6 address",
+        "SRV" => "\"priority weight port target\"",
+        "CAA" => "\"flags tag value\"",
+        record_type if HOST_TYPES.contains(&record_type) => "a host name",
+        _ => "a value",
+    };
+    Err(invalid_value(&record_type, value, expected))
+}
+
+/// The types Aliyun DNS does not accept on the apex, whose NS records it manages itself.
+const APEX_FORBIDDEN_TYPES: &[&str] = &["NS"];
+
+/// The types Aliyun DNS does not accept on wildcard host records.
+const WILDCARD_FORBIDDEN_TYPES: &[&str] = &["NS"];
+
+/// Returns the error for a host record that is not valid.
+fn invalid_rr(rr: &str, reason: &str) -> AliyunDnsError {
+    AliyunDnsError::InvalidInput(format!("Invalid host record {:?}: {}", rr, reason))
+}
+
+/// Checks that a host record is well formed and accepts records of a type.
+///
+/// The apex must be written "@" rather than left empty, and a wildcard "*" must be the leftmost
+/// label, as in "*" or "*.dev". NS records are accepted on neither.
+pub(crate) fn validate_rr(rr: &str, record_type: &str) -> Result<()> {
+    if rr.is_empty() {
+        return Err(invalid_rr(
+            rr,
+            "use \"@\" (RecordSpec::APEX) for the domain itself",
+        ));
+    }
+    if rr.starts_with('.') || rr.ends_with('.') || rr.contains("..") {
+        return Err(invalid_rr(rr, "labels cannot be empty"));
+    }
+    let labels: Vec<&str> = rr.split('.').collect();
+    if rr != RecordSpec::APEX && labels.contains(&RecordSpec::APEX) {
+        return Err(invalid_rr(rr, "\"@\" must be the whole host record"));
+    }
+    let misplaced = labels
+        .iter()
+        .enumerate()
+        .any(|(index, label)| label.contains('*') && (index > 0 || *label != RecordSpec::WILDCARD));
+    if misplaced {
+        return Err(invalid_rr(rr, "\"*\" must be the whole leftmost label"));
+    }
+
+    let record_type = record_type.to_uppercase();
+    if rr == RecordSpec::APEX && APEX_FORBIDDEN_TYPES.contains(&record_type.as_str()) {
+        return Err(invalid_rr(
+            rr,
+            &format!("{} records are not accepted on the apex", record_type),
+        ));
+    }
+    if labels[0] == RecordSpec::WILDCARD && WILDCARD_FORBIDDEN_TYPES.contains(&record_type.as_str())
+    {
+        return Err(invalid_rr(
+            rr,
+            &format!("{} records are not accepted on wildcards", record_type),
+        ));
+    }
+    Ok(())
+}
+
+/// Checks the host record and the value of a record, as `RecordSpec::validate` does.
+pub(crate) fn validate_record(rr: &str, record_type: &str, value: &str) -> Result<()> {
+    validate_rr(rr, record_type)?;
+    validate_value(record_type, value)
+}
+
+impl RecordSpec {
+    /// Checks the record before it is sent: its host record must be well formed and accept its
+    /// type, its value must fit its type, as for `validate_records`, and its TTL must be in range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aliyun_dns::RecordSpec;
+    ///
+    /// assert!(RecordSpec::a("www", "192.0.2.1").validate().is_ok());
+    /// assert!(RecordSpec::a("www", "2001:db8::1").validate().is_err());
+    /// assert!(RecordSpec::mx("@", "mail server", 10).validate().is_err());
+    /// assert!(RecordSpec::a("", "192.0.2.1").validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        validate_record(self.rr(), self.record_type(), self.value())?;
+        self.options.validate()
+    }
+}
+
+/// Returns the error for a CNAME record sharing its host record and line with a record of another type.
+fn cname_conflict(rr: &str, other_type: &str) -> AliyunDnsError {
+    AliyunDnsError::InvalidInput(format!(
+        "The CNAME record of {} cannot coexist with its {} record",
+        rr, other_type
+    ))
+}
+
+/// Returns the existing record a new record cannot coexist with: a CNAME record of the same host
+/// record and line for a record of another type, or a record of another type for a CNAME record.
+fn find_cname_conflict<'a>(
+    records: &'a [DomainRecord],
+    rr: &str,
+    record_type: &str,
+    line: &Line,
+) -> Option<&'a DomainRecord> {
+    let is_cname = record_type.eq_ignore_ascii_case("CNAME");
+    records.iter().find(|record| {
+        record.rr.eq_ignore_ascii_case(rr)
+            && record.line == line.as_str()
+            && record.record_type.eq_ignore_ascii_case("CNAME") != is_cname
+    })
+}
+
+impl AliyunDns {
+    /// Checks that a record can be added next to the existing records of its host record, which
+    /// are queried: a CNAME record cannot coexist with the records of other types on its line.
+    ///
+    /// `add_record` leaves this check to the API, so that adding a record stays a single request;
+    /// this method reports the conflict before anything is changed, at the cost of a query.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the record would be added to.
+    /// * `spec` - The record to check.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is `Ok` if the record can be added, or an `AliyunDnsError::InvalidInput` naming the conflicting type, or an error if the records cannot be queried.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, RecordSpec};
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let spec = RecordSpec::cname("www", "example.net");
+    /// aliyun_dns.check_cname_conflict("example.com", &spec).await?;
+    /// aliyun_dns.add_record("example.com", &spec).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_cname_conflict(&self, domain_name: &str, spec: &RecordSpec) -> Result<()> {
+        let (rr, record_type) = (spec.rr(), spec.record_type());
+        let response = self
+            .query_subdomain_records_paged(&fqdn(rr, domain_name), None, 1, MAX_PAGE_SIZE)
+            .await?;
+        let line = spec.options.line.clone().unwrap_or(Line::Default);
+        match find_cname_conflict(&response.domain_records.records, rr, record_type, &line) {
+            Some(record) if record_type.eq_ignore_ascii_case("CNAME") => {
+                Err(cname_conflict(rr, &record.record_type))
+            }
+            Some(_) => Err(cname_conflict(rr, record_type)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Checks a set of records before they are sent, such as the records of a zone.
+///
+/// Every record is checked with `RecordSpec::validate`: host records must be well formed, "@" for
+/// the apex and "*" only as the leftmost label, A records must hold an IPv4 address, AAAA
+/// records an IPv6 address, CNAME, NS, MX and PTR records a host name, and SRV and CAA records
+/// their fields. A CNAME record must also be the only type of its host record on its line among
+/// `specs`; the records already in the zone are checked by `AliyunDns::check_cname_conflict`.
+///
+/// # Arguments
+///
+/// * `specs` - The records to check.
+///
+/// # Returns
+///
+/// A `Result` that is `Ok` if every record is valid, or an `AliyunDnsError::InvalidInput` describing the first invalid record.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{validate_records, RecordSpec};
+///
+/// let specs = vec![
+///     RecordSpec::cname("www", "example.net"),
+///     RecordSpec::txt("www", "hello"),
+/// ];
+/// assert!(validate_records(&specs).is_err());
+/// ```
+pub fn validate_records(specs: &[RecordSpec]) -> Result<()> {
+    let line = |spec: &RecordSpec| spec.options.line.clone().unwrap_or(Line::Default);
+    for spec in specs {
+        spec.validate()?;
+        if !spec.record_type().eq_ignore_ascii_case("CNAME") {
+            continue;
+        }
+        let conflict = specs.iter().find(|other| {
+            other.rr().eq_ignore_ascii_case(spec.rr())
+                && !other.record_type().eq_ignore_ascii_case("CNAME")
+                && line(other) == line(spec)
+        });
+        if let Some(other) = conflict {
+            return Err(cname_conflict(spec.rr(), other.record_type()));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{dry_run_client, record};
+
+    #[test]
+    fn test_validate_value() {
+        assert!(validate_value("a", "192.0.2.1").is_ok());
+        assert!(validate_value("A", "192.0.2.256").is_err());
+        assert!(validate_value("AAAA", "192.0.2.1").is_err());
+        assert!(validate_value("CNAME", "example.net.").is_ok());
+        assert!(validate_value("SRV", "10 60 5060 sip.example.com").is_ok());
+        assert!(validate_value("SRV", "10 60 sip.example.com").is_err());
+        assert!(validate_value("CAA", "0 issue \"letsencrypt.org\"").is_ok());
+        assert!(validate_value("CAA", "issue letsencrypt.org").is_err());
+        assert!(validate_value("TXT", " ").is_err());
+        assert_eq!(
+            validate_value("A", "example.com").unwrap_err().to_string(),
+            "Invalid input: Invalid A record value \"example.com\": expected an IPv4 address"
+        );
+    }
+
+    #[test]
+    fn test_validate_rr() {
+        assert!(validate_rr("@", "MX").is_ok());
+        assert!(validate_rr("*", "A").is_ok());
+        assert!(validate_rr("*.dev", "CNAME").is_ok());
+        assert!(validate_rr("_acme-challenge.www", "TXT").is_ok());
+        assert!(validate_rr("", "A").is_err());
+        assert!(validate_rr("www.", "A").is_err());
+        assert!(validate_rr("www.@", "A").is_err());
+        assert!(validate_rr("dev.*", "A").is_err());
+        assert!(validate_rr("a*", "A").is_err());
+        assert!(validate_rr("@", "ns").is_err());
+        assert!(validate_rr("*.dev", "NS").is_err());
+        assert!(validate_rr("sub", "NS").is_ok());
+    }
+
+    #[test]
+    fn test_validate_records() {
+        let specs = vec![
+            RecordSpec::cname("www", "example.net"),
+            RecordSpec::a("www", "192.0.2.1").line(Line::Telecom),
+            RecordSpec::a("api", "192.0.2.1"),
+        ];
+        assert!(validate_records(&specs).is_ok());
+    }
+
+    #[test]
+    fn test_find_cname_conflict() {
+        let records = vec![
+            record("www", "A", "192.0.2.1").build(),
+            record("api", "CNAME", "example.net").build(),
+        ];
+        let conflict = find_cname_conflict(&records, "WWW", "CNAME", &Line::Default);
+        assert_eq!(conflict.unwrap().record_type, "A");
+        assert!(find_cname_conflict(&records, "www", "CNAME", &Line::Telecom).is_none());
+        assert!(find_cname_conflict(&records, "www", "AAAA", &Line::Default).is_none());
+        assert!(find_cname_conflict(&records, "api", "TXT", &Line::Default).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_check_cname_conflict() {
+        let (aliyun_dns, actions) = dry_run_client(vec![record("www", "A", "192.0.2.1")]).await;
+        let spec = RecordSpec::cname("www", "example.net");
+        assert!(aliyun_dns
+            .check_cname_conflict("example.com", &spec)
+            .await
+            .is_err());
+        let spec = RecordSpec::cname("api", "example.net");
+        assert!(aliyun_dns
+            .check_cname_conflict("example.com", &spec)
+            .await
+            .is_ok());
+
+        // Adding a record leaves the check to the API.
+        actions.lock().unwrap().clear();
+        let spec = RecordSpec::cname("www", "example.net");
+        assert!(aliyun_dns.add_record("example.com", &spec).await.is_ok());
+        assert!(actions.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,739 @@
+//! Zone files, which export the records of a domain to the BIND master file format and import them back.
+
+use std::fmt::Write;
+
+use crate::dry_run;
+use crate::txt::quote_chunks;
+use crate::{
+    join_txt, validate_records, AliyunDns, AliyunDnsError, BatchEntry, BatchResultDetail,
+    BatchTaskType, DomainRecord, RecordSpec, RecordStatus, Result,
+};
+
+/// The types whose values are host names, written as absolute names in zone files.
+const HOST_TYPES: &[&str] = &["CNAME", "NS", "MX", "PTR"];
+
+/// Returns a host name as an absolute name, with a trailing dot.
+fn absolute(host: &str) -> String {
+    if host.ends_with('.') {
+        host.to_string()
+    } else {
+        format!("{}.", host)
+    }
+}
+
+/// Returns the RDATA of a record as written in a zone file.
+fn rdata(record: &DomainRecord) -> String {
+    let record_type = record.record_type.to_uppercase();
+    match record_type.as_str() {
+        "MX" => format!(
+            "{} {}",
+            record.priority.unwrap_or_default(),
+            absolute(&record.value)
+        ),
+        "TXT" => quote_chunks(&join_txt(&record.value)),
+        "SRV" => match record.value.rsplit_once(' ') {
+            Some((fields, target)) => format!("{} {}", fields, absolute(target)),
+            None => record.value.clone(),
+        },
+        _ if HOST_TYPES.contains(&record_type.as_str()) => absolute(&record.value),
+        _ => record.value.clone(),
+    }
+}
+
+/// Renders records as a zone file of a domain.
+///
+/// Disabled records and the records of types only known to Aliyun DNS, such as URL forwarding, are
+/// kept as comments. The resolution line of the records outside of the default line is noted in a comment.
+fn render_zone(domain_name: &str, records: &[DomainRecord]) -> String {
+    let mut records: Vec<&DomainRecord> = records.iter().collect();
+    records.sort_by(|a, b| {
+        (
+            a.rr.as_str(),
+            a.record_type.as_str(),
+            a.line.as_str(),
+            a.value.as_str(),
+        )
+            .cmp(&(
+                b.rr.as_str(),
+                b.record_type.as_str(),
+                b.line.as_str(),
+                b.value.as_str(),
+            ))
+    });
+
+    let mut zone = String::new();
+    let _ = writeln!(zone, "; Zone file of {}", domain_name);
+    let _ = writeln!(zone, "$ORIGIN {}", absolute(domain_name));
+    for record in records {
+        let record_type = record.record_type.to_uppercase();
+        let mut line = format!(
+            "{}\t{}\tIN\t{}\t{}",
+            record.rr,
+            record.ttl,
+            record_type,
+            rdata(record)
+        );
+        if record.status == RecordStatus::Disable {
+            line = format!("; disabled: {}", line);
+        } else if record_type.ends_with("_URL") {
+            line = format!("; unsupported: {}", line);
+        }
+        if record.line != "default" {
+            line = format!("{} ; line={}", line, record.line);
+        }
+        let _ = writeln!(zone, "{}", line);
+    }
+    zone
+}
+
+/// The largest number of entries of a batch task.
+const MAX_BATCH_ENTRIES: usize = 1000;
+
+/// What `import_zone` and `copy_zone` do with a record when the domain already has records of the
+/// same name and type on the same line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ImportMode {
+    /// Fails before changing anything.
+    #[default]
+    Fail,
+    /// Keeps the existing records and skips the record.
+    Skip,
+    /// Deletes the existing records and adds the record, adding the existing records back if it cannot be added.
+    Overwrite,
+}
+
+/// The outcome of `import_zone` and `copy_zone`.
+#[derive(Debug, Clone, Default)]
+pub struct ZoneImport {
+    /// The number of records submitted to be added.
+    pub added: usize,
+    /// The number of records skipped because of existing records.
+    pub skipped: usize,
+    /// The number of existing records deleted to be overwritten.
+    pub deleted: usize,
+    /// The records that could not be added.
+    pub failures: Vec<BatchResultDetail>,
+}
+
+/// Returns the error for an invalid line of a zone file.
+fn zone_error(line_number: usize, message: impl std::fmt::Display) -> AliyunDnsError {
+    AliyunDnsError::InvalidInput(format!("Zone file line {}: {}", line_number, message))
+}
+
+/// Splits a line of a zone file into its content and its comment, outside of quoted strings.
+fn split_comment(line: &str) -> (&str, Option<&str>) {
+    let mut quoted = false;
+    let mut escaped = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => quoted = !quoted,
+            ';' if !quoted => return (&line[..index], Some(&line[index + 1..])),
+            _ => {}
+        }
+    }
+    (line, None)
+}
+
+/// Splits the content of an entry into tokens, keeping quoted strings with their quotes.
+fn tokenize(content: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quoted = false;
+    let mut escaped = false;
+    for c in content.chars() {
+        if escaped {
+            token.push(c);
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' => {
+                token.push(c);
+                escaped = true;
+            }
+            '"' => {
+                token.push(c);
+                quoted = !quoted;
+            }
+            '(' | ')' if !quoted => {}
+            c if c.is_whitespace() && !quoted => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+            }
+            c => token.push(c),
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// Parses a TTL in seconds, or with the units of BIND such as "1h30m".
+fn parse_ttl(token: &str) -> Option<u32> {
+    if let Ok(seconds) = token.parse() {
+        return Some(seconds);
+    }
+    let mut total: u32 = 0;
+    let mut number = String::new();
+    for c in token.to_ascii_lowercase().chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            'w' => 604800,
+            _ => return None,
+        };
+        let value: u32 = std::mem::take(&mut number).parse().ok()?;
+        total = total.checked_add(value.checked_mul(unit)?)?;
+    }
+    if number.is_empty() && total > 0 {
+        Some(total)
+    } else {
+        None
+    }
+}
+
+/// Returns a name of a zone file as an absolute name without the trailing dot.
+fn absolute_name(name: &str, origin: &str) -> String {
+    if name == "@" {
+        origin.to_string()
+    } else if let Some(name) = name.strip_suffix('.') {
+        name.to_string()
+    } else {
+        format!("{}.{}", name, origin)
+    }
+}
+
+/// Returns the character string of a quoted TXT token, without quotes nor escapes.
+fn unquote_txt(token: &str) -> String {
+    let token = token
+        .strip_prefix('"')
+        .and_then(|token| token.strip_suffix('"'))
+        .unwrap_or(token);
+    let mut text = String::new();
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(c) = chars.next() {
+                text.push(c);
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    text
+}
+
+/// Parses a zone file into the records of a domain.
+///
+/// The SOA record and the NS records of the apex, which are managed by Aliyun DNS, are skipped, as
+/// are the records kept as comments by `export_zone`. Names are resolved against `$ORIGIN`, which
+/// defaults to the domain name, and `$TTL` applies to the records without a TTL. The resolution line
+/// noted by `export_zone` in a `line=` comment is restored.
+///
+/// # Arguments
+///
+/// * `domain_name` - The domain name the zone file belongs to.
+/// * `zone_text` - The content of the zone file.
+///
+/// # Returns
+///
+/// A `Result` containing the records of the zone file, or an error if the zone file is invalid or has records outside of the domain.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::parse_zone;
+///
+/// let zone = "$TTL 600\n@ IN MX 10 mail\nwww IN A 192.0.2.1\n";
+/// let records = parse_zone("example.com", zone).unwrap();
+/// assert_eq!(records[0].value(), "mail.example.com");
+/// assert_eq!(records[1].rr(), "www");
+/// ```
+pub fn parse_zone(domain_name: &str, zone_text: &str) -> Result<Vec<RecordSpec>> {
+    let domain_name = domain_name.trim_end_matches('.');
+    let mut origin = domain_name.to_string();
+    let mut default_ttl = None;
+    let mut owner: Option<String> = None;
+    let mut records = Vec::new();
+
+    let mut lines = zone_text.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let line_number = index + 1;
+        let (content, comment) = split_comment(line);
+        let mut content = content.to_string();
+        let mut comment = comment.map(str::to_string);
+        // An entry in parentheses goes on until they are closed.
+        while content.matches('(').count() > content.matches(')').count() {
+            let (_, next) = lines
+                .next()
+                .ok_or_else(|| zone_error(line_number, "unclosed parenthesis"))?;
+            let (next_content, next_comment) = split_comment(next);
+            content.push(' ');
+            content.push_str(next_content);
+            comment = comment.or(next_comment.map(str::to_string));
+        }
+
+        let mut tokens = tokenize(&content).into_iter().peekable();
+        let Some(first) = tokens.peek().cloned() else {
+            continue;
+        };
+        match first.to_ascii_uppercase().as_str() {
+            "$ORIGIN" => {
+                let name = tokens
+                    .nth(1)
+                    .ok_or_else(|| zone_error(line_number, "$ORIGIN without a name"))?;
+                origin = absolute_name(&name, &origin);
+                continue;
+            }
+            "$TTL" => {
+                let ttl = tokens
+                    .nth(1)
+                    .and_then(|ttl| parse_ttl(&ttl))
+                    .ok_or_else(|| zone_error(line_number, "$TTL without a valid TTL"))?;
+                default_ttl = Some(ttl);
+                continue;
+            }
+            directive if directive.starts_with('$') => {
+                return Err(zone_error(
+                    line_number,
+                    format!("unsupported directive {}", first),
+                ));
+            }
+            _ => {}
+        }
+
+        // An entry starting with a blank belongs to the owner of the previous entry.
+        if !line.starts_with(char::is_whitespace) {
+            owner = tokens.next().map(|name| absolute_name(&name, &origin));
+        }
+        let name = owner
+            .clone()
+            .ok_or_else(|| zone_error(line_number, "record without an owner name"))?;
+
+        let mut ttl = default_ttl;
+        let record_type = loop {
+            let token = tokens
+                .next()
+                .ok_or_else(|| zone_error(line_number, "record without a type"))?;
+            if let Some(seconds) = parse_ttl(&token) {
+                ttl = Some(seconds);
+            } else if !matches!(token.to_ascii_uppercase().as_str(), "IN" | "CH" | "HS") {
+                break token.to_ascii_uppercase();
+            }
+        };
+        let rdata: Vec<String> = tokens.collect();
+        if rdata.is_empty() {
+            return Err(zone_error(line_number, "record without data"));
+        }
+
+        let suffix = format!(".{}", domain_name).to_ascii_lowercase();
+        let rr = if name.eq_ignore_ascii_case(domain_name) {
+            "@".to_string()
+        } else if let Some(rr) = name.to_ascii_lowercase().strip_suffix(&suffix) {
+            // Lowercasing keeps the length, so the RR keeps the case of the zone file.
+            name[..rr.len()].to_string()
+        } else {
+            return Err(zone_error(
+                line_number,
+                format!("{} is outside of {}", name, domain_name),
+            ));
+        };
+
+        let host = |token: &str| absolute_name(token, &origin);
+        let mut spec = match record_type.as_str() {
+            "SOA" => continue,
+            "NS" if rr == "@" => continue,
+            "MX" => {
+                let priority = rdata[0]
+                    .parse()
+                    .map_err(|_| zone_error(line_number, "MX record without a valid priority"))?;
+                let exchange = rdata
+                    .get(1)
+                    .ok_or_else(|| zone_error(line_number, "MX record without a mail server"))?;
+                RecordSpec::mx(&rr, &host(exchange), priority)
+            }
+            "CNAME" | "NS" | "PTR" => RecordSpec::new(&rr, &record_type, &host(&rdata[0])),
+            "SRV" if rdata.len() == 4 => {
+                let value = format!("{} {} {} {}", rdata[0], rdata[1], rdata[2], host(&rdata[3]));
+                RecordSpec::new(&rr, &record_type, &value)
+            }
+            "TXT" => {
+                let text: String = rdata.iter().map(|token| unquote_txt(token)).collect();
+                RecordSpec::txt(&rr, &text)
+            }
+            _ => RecordSpec::new(&rr, &record_type, &rdata.join(" ")),
+        };
+        if let Some(ttl) = ttl {
+            spec = spec.ttl(ttl);
+        }
+        if let Some(line) = comment
+            .as_deref()
+            .and_then(|comment| comment.trim().strip_prefix("line="))
+        {
+            spec = spec.line(line.trim());
+        }
+        records.push(spec);
+    }
+    Ok(records)
+}
+
+/// Returns the batch entry adding a record to a domain.
+fn batch_entry(domain_name: &str, spec: &RecordSpec) -> BatchEntry {
+    let mut entry = BatchEntry::new(domain_name)
+        .rr(spec.rr())
+        .record_type(spec.record_type())
+        .value(spec.value());
+    if let Some(ttl) = spec.options.ttl {
+        entry = entry.ttl(ttl);
+    }
+    if let Some(priority) = spec.options.priority {
+        entry = entry.priority(priority);
+    }
+    if let Some(line) = &spec.options.line {
+        entry = entry.line(line.clone());
+    }
+    entry
+}
+
+impl AliyunDns {
+    /// Exports all the records of a domain as a zone file in the BIND master file format.
+    ///
+    /// Disabled records and the records of types only known to Aliyun DNS, such as URL forwarding, are
+    /// kept as comments. The resolution line of the records outside of the default line is noted in a comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name whose records should be exported.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the zone file, or an error if the records cannot be queried.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::AliyunDns;
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let zone = aliyun_dns.export_zone("example.com").await?;
+    /// std::fs::write("example.com.zone", zone)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_zone(&self, domain_name: &str) -> Result<String> {
+        let records = self.query_all_domain_records(domain_name).await?;
+        Ok(render_zone(domain_name, &records))
+    }
+
+    /// Imports the records of a zone file in the BIND master file format, as parsed by `parse_zone`.
+    ///
+    /// The records are added with batch tasks of up to 1000 records, and this method waits for
+    /// them to finish.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain_name` - The domain name the records should be added to.
+    /// * `zone_text` - The content of the zone file.
+    /// * `mode` - What to do with the records of the zone file whose name and type already have records on the same line.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `ZoneImport` with the number of records added, skipped and deleted and the records that could not be added, or an error if the zone file is invalid, a conflict is found in `ImportMode::Fail`, or a request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, ImportMode};
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let zone = std::fs::read_to_string("example.com.zone")?;
+    /// let import = aliyun_dns.import_zone("example.com", &zone, ImportMode::Skip).await?;
+    /// println!("Added {} records, skipped {}", import.added, import.skipped);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn import_zone(
+        &self,
+        domain_name: &str,
+        zone_text: &str,
+        mode: ImportMode,
+    ) -> Result<ZoneImport> {
+        let specs = parse_zone(domain_name, zone_text)?;
+        validate_records(&specs)?;
+        self.import_specs(domain_name, specs, mode).await
+    }
+
+    /// Adds records with batch tasks, handling the conflicts with the existing records as `mode` says.
+    ///
+    /// In `ImportMode::Overwrite`, the existing records whose replacements cannot be added are
+    /// added back, so that an import never deletes records without replacing them. Every deleted
+    /// record is tried, and those that cannot be added back are returned in an
+    /// `AliyunDnsError::Restore` with the error that stopped the import, if any.
+    pub(crate) async fn import_specs(
+        &self,
+        domain_name: &str,
+        specs: Vec<RecordSpec>,
+        mode: ImportMode,
+    ) -> Result<ZoneImport> {
+        let existing = self.query_all_domain_records(domain_name).await?;
+        let (conflicting, specs): (Vec<RecordSpec>, Vec<RecordSpec>) = specs
+            .into_iter()
+            .partition(|spec| existing.iter().any(|record| spec.has_same_name(record)));
+
+        let mut import = ZoneImport::default();
+        let mut replaced = Vec::new();
+        let specs = match mode {
+            ImportMode::Fail if !conflicting.is_empty() => {
+                let names: Vec<String> = conflicting
+                    .iter()
+                    .map(|spec| format!("{} {}", spec.rr(), spec.record_type()))
+                    .collect();
+                return Err(AliyunDnsError::InvalidInput(format!(
+                    "Records already exist for {}",
+                    names.join(", ")
+                )));
+            }
+            ImportMode::Fail => specs,
+            ImportMode::Skip => {
+                import.skipped = conflicting.len();
+                specs
+            }
+            ImportMode::Overwrite => {
+                replaced = existing
+                    .iter()
+                    .filter(|record| conflicting.iter().any(|spec| spec.has_same_name(record)))
+                    .collect();
+                specs.into_iter().chain(conflicting).collect()
+            }
+        };
+
+        let entries: Vec<BatchEntry> = specs
+            .iter()
+            .map(|spec| batch_entry(domain_name, spec))
+            .collect();
+        let mut deleted = Vec::new();
+        let result: Result<()> = async {
+            for record in &replaced {
+                self.delete_domain_record(&record.record_id).await?;
+                deleted.push(*record);
+            }
+            for chunk in entries.chunks(MAX_BATCH_ENTRIES) {
+                let task = self
+                    .operate_batch_domain(BatchTaskType::RrAdd, chunk)
+                    .await?;
+                let result = if self.is_dry_run() {
+                    dry_run::batch_result(task.task_id, chunk.len())
+                } else {
+                    self.wait_for_batch(task.task_id).await?
+                };
+                import.added += chunk.len() - result.failures.len();
+                import.failures.extend(result.failures);
+            }
+            Ok(())
+        }
+        .await;
+
+        let mut restore_failures = Vec::new();
+        for record in deleted {
+            let restore = result.is_err()
+                || import
+                    .failures
+                    .iter()
+                    .any(|failure| is_replacement(failure, record));
+            if !restore {
+                import.deleted += 1;
+            } else if let Err(error) = self
+                .add_record(domain_name, &RecordSpec::from(record))
+                .await
+            {
+                restore_failures.push((record.clone(), error));
+            }
+        }
+        if restore_failures.is_empty() {
+            return result.map(|()| import);
+        }
+        Err(AliyunDnsError::Restore {
+            source: result.err().map(Box::new),
+            failures: restore_failures,
+        })
+    }
+}
+
+/// Returns whether a failed batch entry was to replace a record, having its name, type and line.
+fn is_replacement(failure: &BatchResultDetail, record: &DomainRecord) -> bool {
+    failure
+        .rr
+        .as_deref()
+        .is_some_and(|rr| rr.eq_ignore_ascii_case(&record.rr))
+        && failure
+            .record_type
+            .as_deref()
+            .is_some_and(|record_type| record_type.eq_ignore_ascii_case(&record.record_type))
+        && failure
+            .line
+            .as_deref()
+            .is_none_or(|line| line == record.line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{self, record};
+    use crate::Line;
+
+    #[test]
+    fn test_parse_zone() {
+        let zone = "$ORIGIN example.com.\n\
+                    $TTL 1h\n\
+                    @ IN SOA ns1.example.net. admin.example.com. (\n\
+                    \t1 ; serial\n\
+                    \t3600 600 86400 600 )\n\
+                    @ IN NS ns1.example.net.\n\
+                    @ 600 IN MX 10 mail\n\
+                    \tIN TXT \"v=spf1 \\\"a\\\" -all\" ; spf\n\
+                    www IN CNAME @\n\
+                    api 60 IN A 192.0.2.2 ; line=telecom\n\
+                    ; disabled: old\t600\tIN\tA\t192.0.2.9\n\
+                    $ORIGIN sub.example.com.\n\
+                    _sip._tcp IN SRV 10 60 5060 sip\n";
+        let records = parse_zone("example.com", zone).unwrap();
+        let summary: Vec<(&str, &str, &str, Option<u32>)> = records
+            .iter()
+            .map(|spec| {
+                (
+                    spec.rr(),
+                    spec.record_type(),
+                    spec.value(),
+                    spec.options.ttl,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("@", "MX", "mail.example.com", Some(600)),
+                ("@", "TXT", "v=spf1 \"a\" -all", Some(3600)),
+                ("www", "CNAME", "example.com", Some(3600)),
+                ("api", "A", "192.0.2.2", Some(60)),
+                (
+                    "_sip._tcp.sub",
+                    "SRV",
+                    "10 60 5060 sip.sub.example.com",
+                    Some(3600)
+                ),
+            ]
+        );
+        assert_eq!(records[0].options.priority, Some(10));
+        assert_eq!(records[3].options.line, Some(Line::Telecom));
+
+        assert!(parse_zone("example.com", "www.example.net. IN A 192.0.2.1").is_err());
+        assert!(parse_zone("example.com", "$INCLUDE other.zone").is_err());
+    }
+
+    #[test]
+    fn test_render_zone() {
+        let records = vec![
+            record("www", "A", "192.0.2.1").build(),
+            record("@", "MX", "mail.example.com").priority(10).build(),
+            record("@", "TXT", "v=spf1 \"a\" -all").build(),
+            record("_sip._tcp", "SRV", "10 60 5060 sip.example.com").build(),
+            record("old", "A", "192.0.2.9").status("DISABLE").build(),
+            record("www", "A", "192.0.2.2").line("telecom").build(),
+        ];
+        let zone = render_zone("example.com", &records);
+        assert_eq!(
+            zone,
+            "; Zone file of example.com\n\
+             $ORIGIN example.com.\n\
+             @\t600\tIN\tMX\t10 mail.example.com.\n\
+             @\t600\tIN\tTXT\t\"v=spf1 \\\"a\\\" -all\"\n\
+             _sip._tcp\t600\tIN\tSRV\t10 60 5060 sip.example.com.\n\
+             ; disabled: old\t600\tIN\tA\t192.0.2.9\n\
+             www\t600\tIN\tA\t192.0.2.1\n\
+             www\t600\tIN\tA\t192.0.2.2 ; line=telecom\n"
+        );
+
+        let parsed = parse_zone("example.com", &zone).unwrap();
+        assert_eq!(parsed.len(), 5);
+        assert_eq!(parsed[1].value(), "v=spf1 \"a\" -all");
+        assert_eq!(parsed[4].options.line, Some(Line::Telecom));
+
+        let long = "a".repeat(300);
+        assert_eq!(
+            quote_chunks(&long),
+            format!("\"{}\" \"{}\"", "a".repeat(255), "a".repeat(45))
+        );
+    }
+
+    #[test]
+    fn test_is_replacement() {
+        let failure: BatchResultDetail = serde_json::from_value(serde_json::json!({
+            "Domain": "example.com",
+            "Rr": "WWW",
+            "Type": "A",
+            "Value": "192.0.2.2",
+            "Status": false,
+        }))
+        .unwrap();
+        assert!(is_replacement(
+            &failure,
+            &record("www", "A", "192.0.2.1").build()
+        ));
+        assert!(!is_replacement(
+            &failure,
+            &record("www", "AAAA", "2001:db8::1").build()
+        ));
+        assert!(!is_replacement(
+            &failure,
+            &record("api", "A", "192.0.2.1").build()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_import_restore_failures() {
+        let live = vec![
+            record("www", "A", "192.0.2.1"),
+            record("www", "A", "192.0.2.2"),
+        ];
+        let error = r#"{"RequestId": "test", "Code": "Forbidden", "Message": "Denied"}"#;
+        let (aliyun_dns, actions) = test_server::client(move |action| match action {
+            "DescribeDomainRecords" => (200, test_server::records_body(&live)),
+            "DeleteDomainRecord" => (200, r#"{"RequestId": "test", "RecordId": "1"}"#.to_string()),
+            _ => (400, error.to_string()),
+        })
+        .await;
+
+        let result = aliyun_dns
+            .import_zone("example.com", "www IN A 192.0.2.9", ImportMode::Overwrite)
+            .await;
+        match result {
+            Err(AliyunDnsError::Restore {
+                source: Some(source),
+                failures,
+            }) => {
+                assert_eq!(source.error_code().unwrap().as_str(), "Forbidden");
+                assert_eq!(failures.len(), 2);
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+        let adds = actions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|action| *action == "AddDomainRecord")
+            .count();
+        assert_eq!(adds, 2);
+    }
+}
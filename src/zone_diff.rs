@@ -0,0 +1,234 @@
+//! Zone diffs, which compare two sets of records, such as two domains or a domain and a snapshot.
+
+use std::fmt;
+
+use crate::reconcile::pair_records;
+use crate::txt::is_same_value;
+use crate::{AliyunDns, RecordSpec, Result};
+
+/// A field of a record that differs between two zones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldChange {
+    /// The name of the field: "value", "ttl", "priority" or "status".
+    pub field: &'static str,
+    /// The field in the first zone.
+    pub from: String,
+    /// The field in the second zone.
+    pub to: String,
+}
+
+/// A record of the same name, type and line in both zones, with different fields.
+#[derive(Debug, Clone)]
+pub struct RecordChange {
+    /// The record in the first zone.
+    pub from: RecordSpec,
+    /// The record in the second zone.
+    pub to: RecordSpec,
+    /// The fields that differ.
+    pub fields: Vec<FieldChange>,
+}
+
+/// The differences between the records of two zones, as returned by `diff_zones`.
+#[derive(Debug, Clone, Default)]
+pub struct ZoneDiff {
+    /// The records only in the second zone.
+    pub added: Vec<RecordSpec>,
+    /// The records only in the first zone.
+    pub removed: Vec<RecordSpec>,
+    /// The records in both zones with different fields.
+    pub changed: Vec<RecordChange>,
+}
+
+impl ZoneDiff {
+    /// Returns whether the two zones have the same records.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for ZoneDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for spec in &self.removed {
+            writeln!(f, "- {} {} {}", spec.rr(), spec.record_type(), spec.value())?;
+        }
+        for spec in &self.added {
+            writeln!(f, "+ {} {} {}", spec.rr(), spec.record_type(), spec.value())?;
+        }
+        for change in &self.changed {
+            let fields: Vec<String> = change
+                .fields
+                .iter()
+                .map(|field| format!("{} {} -> {}", field.field, field.from, field.to))
+                .collect();
+            writeln!(
+                f,
+                "~ {} {}: {}",
+                change.from.rr(),
+                change.from.record_type(),
+                fields.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the fields that differ between two records of the same name and type.
+///
+/// A TTL, priority or status given to only one of the records is not compared.
+fn field_changes(from: &RecordSpec, to: &RecordSpec) -> Vec<FieldChange> {
+    let mut fields = Vec::new();
+    if !is_same_value(from.record_type(), from.value(), to.value()) {
+        fields.push(FieldChange {
+            field: "value",
+            from: from.value().to_string(),
+            to: to.value().to_string(),
+        });
+    }
+    let optional = [
+        ("ttl", from.options.ttl, to.options.ttl),
+        ("priority", from.options.priority, to.options.priority),
+    ];
+    for (field, from, to) in optional {
+        if let (Some(from), Some(to)) = (from, to) {
+            if from != to {
+                fields.push(FieldChange {
+                    field,
+                    from: from.to_string(),
+                    to: to.to_string(),
+                });
+            }
+        }
+    }
+    if let (Some(from), Some(to)) = (from.status, to.status) {
+        if from != to {
+            fields.push(FieldChange {
+                field: "status",
+                from: from.as_str().to_string(),
+                to: to.as_str().to_string(),
+            });
+        }
+    }
+    fields
+}
+
+/// Compares the records of two zones.
+///
+/// The records are paired by name, type and line. Records of the same value are compared field
+/// by field, and the other records of the same name, type and line are paired in order as value
+/// changes. The remaining records are added or removed.
+///
+/// # Arguments
+///
+/// * `a` - The records of the first zone.
+/// * `b` - The records of the second zone.
+///
+/// # Returns
+///
+/// The `ZoneDiff` from the first zone to the second one.
+///
+/// # Examples
+///
+/// ```
+/// use aliyun_dns::{diff_zones, parse_zone, RecordSpec};
+///
+/// let snapshot = parse_zone("example.com", "www 600 IN A 192.0.2.1").unwrap();
+/// let live = vec![RecordSpec::a("www", "192.0.2.2").ttl(600)];
+/// let diff = diff_zones(&snapshot, &live);
+/// assert_eq!(diff.to_string(), "~ www A: value 192.0.2.1 -> 192.0.2.2\n");
+/// ```
+pub fn diff_zones(a: &[RecordSpec], b: &[RecordSpec]) -> ZoneDiff {
+    let pairing = pair_records(a, b);
+    let mut diff = ZoneDiff::default();
+    for (from, to) in pairing.same_value {
+        let fields = field_changes(from, to);
+        if !fields.is_empty() {
+            diff.changed.push(RecordChange {
+                from: from.clone(),
+                to: to.clone(),
+                fields,
+            });
+        }
+    }
+    for (from, to) in pairing.changed_value {
+        diff.changed.push(RecordChange {
+            from: from.clone(),
+            to: to.clone(),
+            fields: field_changes(from, to),
+        });
+    }
+    diff.removed = pairing.only_a.into_iter().cloned().collect();
+    diff.added = pairing.only_b.into_iter().cloned().collect();
+    diff
+}
+
+impl AliyunDns {
+    /// Compares the records of two domains, such as a staging and a production zone.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first domain name.
+    /// * `b` - The second domain name.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `ZoneDiff` from the first domain to the second one, or an error if the records cannot be queried.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aliyun_dns::{AliyunDns, ZoneDiff};
+    ///
+    /// # async fn run() {
+    /// let aliyun_dns = AliyunDns::new("your_access_key_id".to_string(), "your_access_key_secret".to_string());
+    /// let result: Result<ZoneDiff, _> = aliyun_dns.diff_domains("staging.example.com", "example.com").await;
+    /// # }
+    /// ```
+    pub async fn diff_domains(&self, a: &str, b: &str) -> Result<ZoneDiff> {
+        let a = self.query_all_domain_records(a).await?;
+        let b = self.query_all_domain_records(b).await?;
+        let a: Vec<RecordSpec> = a.iter().map(RecordSpec::from).collect();
+        let b: Vec<RecordSpec> = b.iter().map(RecordSpec::from).collect();
+        Ok(diff_zones(&a, &b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::record;
+    use crate::{Line, RecordStatus};
+
+    #[test]
+    fn test_diff_zones() {
+        let a = vec![
+            RecordSpec::a("www", "192.0.2.1").ttl(600),
+            RecordSpec::a("api", "192.0.2.2").ttl(600),
+            RecordSpec::mx("@", "mail.example.com", 10),
+            RecordSpec::a("old", "192.0.2.3"),
+        ];
+        let b = vec![
+            RecordSpec::a("WWW", "192.0.2.1").ttl(60),
+            RecordSpec::a("api", "192.0.2.9"),
+            RecordSpec::mx("@", "mail.example.com", 10),
+            RecordSpec::a("old", "192.0.2.3").line(Line::Telecom),
+        ];
+        let diff = diff_zones(&a, &b);
+        assert_eq!(
+            diff.to_string(),
+            "- old A 192.0.2.3\n\
+             + old A 192.0.2.3\n\
+             ~ www A: ttl 600 -> 60\n\
+             ~ api A: value 192.0.2.2 -> 192.0.2.9\n"
+        );
+        assert!(diff_zones(&a, &a).is_empty());
+
+        let mut record = record("www", "A", "192.0.2.1").build();
+        let enabled = RecordSpec::from(&record);
+        record.status = RecordStatus::Disable;
+        let disabled = RecordSpec::from(&record);
+        assert_eq!(
+            diff_zones(&[enabled], &[disabled]).to_string(),
+            "~ www A: status Enable -> Disable\n"
+        );
+    }
+}